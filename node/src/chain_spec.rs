@@ -219,6 +219,7 @@ fn testnet_genesis(
 		transaction_payment: Default::default(),
 		treasury: Default::default(),
 		court: Default::default(),
+		higher_court: Default::default(),
 		sudo: saas3_dao_runtime::SudoConfig { key: Some(endowed_accounts[0].clone()) },
 	}
 }
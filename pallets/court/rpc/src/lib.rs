@@ -0,0 +1,109 @@
+// Copyright (C) SaaS3.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! RPC interface for the court pallet.
+
+use std::{marker::PhantomData, sync::Arc};
+
+use codec::Codec;
+use jsonrpsee::{
+	core::RpcResult,
+	proc_macros::rpc,
+	types::error::{CallError, ErrorObject},
+};
+use serde::{de::DeserializeOwned, Serialize};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::traits::Block as BlockT;
+
+pub use pallet_court_runtime_api::{CourtApi as CourtRuntimeApi, JurorStats, SubCourtIndex};
+
+/// The RPC methods to query the jurors of the court.
+#[rpc(client, server)]
+pub trait CourtApi<BlockHash, AccountId, Balance> {
+	/// The statistics of a juror added up over all sub-courts.
+	#[method(name = "court_jurorStats")]
+	fn juror_stats(&self, who: AccountId, at: Option<BlockHash>) -> RpcResult<JurorStats<Balance>>;
+
+	/// The jurors of a sub-court ranked by coherent votes, at most `limit` of them from the
+	/// `start`th.
+	#[method(name = "court_leaderboard")]
+	fn leaderboard(
+		&self,
+		sub_court: SubCourtIndex,
+		start: u32,
+		limit: u32,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<(AccountId, JurorStats<Balance>)>>;
+}
+
+/// Provides the court RPC methods, `M` is `(Block, BlockNumber)` of the runtime.
+pub struct Court<C, M> {
+	client: Arc<C>,
+	_marker: PhantomData<M>,
+}
+
+impl<C, M> Court<C, M> {
+	/// Create a new instance of the court RPC handler.
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client, _marker: Default::default() }
+	}
+}
+
+/// The error code of failed runtime calls.
+const RUNTIME_ERROR: i32 = 1;
+
+fn runtime_error(err: impl std::fmt::Debug) -> jsonrpsee::core::Error {
+	CallError::Custom(ErrorObject::owned(
+		RUNTIME_ERROR,
+		"Runtime error",
+		Some(format!("{:?}", err)),
+	))
+	.into()
+}
+
+impl<C, Block, AccountId, BlockNumber, Balance>
+	CourtApiServer<<Block as BlockT>::Hash, AccountId, Balance> for Court<C, (Block, BlockNumber)>
+where
+	Block: BlockT,
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: CourtRuntimeApi<Block, AccountId, BlockNumber, Balance>,
+	AccountId: Codec + Serialize + DeserializeOwned + Send + Sync + 'static,
+	BlockNumber: Codec + Send + Sync + 'static,
+	Balance: Codec + Serialize + Send + Sync + 'static,
+{
+	fn juror_stats(
+		&self,
+		who: AccountId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<JurorStats<Balance>> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		self.client.runtime_api().juror_stats(at, who).map_err(runtime_error)
+	}
+
+	fn leaderboard(
+		&self,
+		sub_court: SubCourtIndex,
+		start: u32,
+		limit: u32,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<(AccountId, JurorStats<Balance>)>> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		self.client
+			.runtime_api()
+			.leaderboard(at, sub_court, start, limit)
+			.map_err(runtime_error)
+	}
+}
@@ -0,0 +1,93 @@
+// Copyright (C) SaaS3.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Runtime API definition for the court pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use sp_std::vec::Vec;
+
+pub use pallet_court::{
+	InsuranceStatus, JurorStats, Justification, LawsuitFilter, ProposalIndex, SubCourtIndex,
+};
+
+sp_api::decl_runtime_apis! {
+	/// The API to query the state of the court.
+	pub trait CourtApi<AccountId, BlockNumber, Balance>
+	where
+		AccountId: Codec,
+		BlockNumber: Codec,
+		Balance: Codec,
+	{
+		/// The reputation score of a juror, decay included.
+		fn reputation(who: AccountId) -> u32;
+
+		/// The lawsuits, archived ones excluded, whose metadata matches `filter`.
+		fn lawsuits(filter: LawsuitFilter<BlockNumber>) -> Vec<ProposalIndex>;
+
+		/// The funds, outstanding debts and cap of the insurance pool covering awards.
+		fn insurance_status() -> InsuranceStatus<Balance>;
+
+		/// The statistics of a juror added up over all sub-courts.
+		fn juror_stats(who: AccountId) -> JurorStats<Balance>;
+
+		/// The jurors of a sub-court ranked by coherent votes, at most `limit` of them from the
+		/// `start`th.
+		fn leaderboard(
+			sub_court: SubCourtIndex,
+			start: u32,
+			limit: u32,
+		) -> Vec<(AccountId, JurorStats<Balance>)>;
+
+		/// The justifications of the majority of the jury for the ruling on a lawsuit, archived
+		/// lawsuits included.
+		fn reasons(lawsuit_id: ProposalIndex) -> Vec<Justification<AccountId, Vec<u8>>>;
+	}
+
+	/// The API to query the state of the higher court the appeals of the court are escalated
+	/// to, see `CourtApi`.
+	pub trait HigherCourtApi<AccountId, BlockNumber, Balance>
+	where
+		AccountId: Codec,
+		BlockNumber: Codec,
+		Balance: Codec,
+	{
+		/// The reputation score of a juror of the higher court, decay included.
+		fn reputation(who: AccountId) -> u32;
+
+		/// The lawsuits of the higher court, archived ones excluded, whose metadata matches
+		/// `filter`.
+		fn lawsuits(filter: LawsuitFilter<BlockNumber>) -> Vec<ProposalIndex>;
+
+		/// The funds, outstanding debts and cap of the insurance pool of the higher court.
+		fn insurance_status() -> InsuranceStatus<Balance>;
+
+		/// The statistics of a juror of the higher court added up over all its sub-courts.
+		fn juror_stats(who: AccountId) -> JurorStats<Balance>;
+
+		/// The jurors of a sub-court of the higher court ranked by coherent votes, at most
+		/// `limit` of them from the `start`th.
+		fn leaderboard(
+			sub_court: SubCourtIndex,
+			start: u32,
+			limit: u32,
+		) -> Vec<(AccountId, JurorStats<Balance>)>;
+
+		/// The justifications of the majority of the jury for the ruling on a case of the higher
+		/// court, archived cases included.
+		fn reasons(lawsuit_id: ProposalIndex) -> Vec<Justification<AccountId, Vec<u8>>>;
+	}
+}
@@ -41,319 +41,4996 @@
 #[cfg(test)]
 mod tests;
 
-use codec::{Decode, Encode};
+use codec::{Decode, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
 
-use sp_runtime::{traits::StaticLookup, RuntimeDebug};
-use sp_std::prelude::*;
+use sp_core::H256;
+use sp_io::hashing::blake2_256;
+use sp_runtime::{
+	traits::{
+		Dispatchable, One, SaturatedConversion, Saturating, StaticLookup, TrailingZeroInput, Zero,
+	},
+	DispatchError, DispatchResult, FixedPointNumber, FixedU128, Perbill, RuntimeDebug,
+};
+use sp_std::{marker::PhantomData, prelude::*};
 
 use frame_support::{
+	dispatch::{extract_actual_weight, GetDispatchInfo, PostDispatchInfo},
 	inherent::Vec,
-	traits::{Currency, Get, ReservableCurrency},
+	storage::{with_transaction, TransactionOutcome},
+	traits::{
+		fungibles, BalanceStatus, Contains, Currency, EnsureOrigin,
+		ExistenceRequirement::{AllowDeath, KeepAlive},
+		Get, Imbalance, QueryPreimage, Randomness, ReservableCurrency,
+	},
 	weights::Weight,
+	BoundedVec,
 };
 
 pub use pallet::*;
 
 pub type BalanceOf<T, I = ()> =
 	<<T as Config<I>>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+pub type NegativeImbalanceOf<T, I = ()> = <<T as Config<I>>::Currency as Currency<
+	<T as frame_system::Config>::AccountId,
+>>::NegativeImbalance;
+pub type LawsuitOf<T, I = ()> = Lawsuit<
+	<T as frame_system::Config>::AccountId,
+	BalanceOf<T, I>,
+	<T as frame_system::Config>::BlockNumber,
+	<T as Config<I>>::AssetId,
+	CaseTagsOf<T, I>,
+>;
+pub type CaseMetadataOf<T, I = ()> =
+	CaseMetadata<<T as frame_system::Config>::BlockNumber, CaseTagsOf<T, I>>;
+pub type CaseTagsOf<T, I = ()> =
+	CaseTags<<T as Config<I>>::MaxTags, <T as Config<I>>::MaxTagLength>;
+/// The tags of a lawsuit, at most `MaxTags` of at most `MaxTagLength` bytes each.
+pub type CaseTags<MaxTags, MaxTagLength> = BoundedVec<BoundedVec<u8, MaxTagLength>, MaxTags>;
+pub type JustificationOf<T, I = ()> =
+	Justification<<T as frame_system::Config>::AccountId, SummaryOf<T, I>>;
+/// The summary of a justification, at most `MaxSummaryLength` bytes.
+pub type SummaryOf<T, I = ()> = BoundedVec<u8, <T as Config<I>>::MaxSummaryLength>;
+pub type ArchivedLawsuitOf<T, I = ()> = ArchivedLawsuit<
+	<T as frame_system::Config>::AccountId,
+	BalanceOf<T, I>,
+	<T as frame_system::Config>::BlockNumber,
+	<T as Config<I>>::AssetId,
+>;
 type AccountIdLookupOf<T> = <<T as frame_system::Config>::Lookup as StaticLookup>::Source;
 
 /// An index of a proposal. Just a `u32`.
 pub type ProposalIndex = u32;
 
+/// An index of a sub-court. Just a `u32`, `0` is the general court.
+pub type SubCourtIndex = u32;
+
 /// A submitted lawsuit
 #[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
-pub struct Lawsuit<AccountId, Balance> {
+pub struct Lawsuit<AccountId, Balance, BlockNumber, AssetId, Tags> {
 	/// The account proposing it.
 	plaintiff: AccountId,
 	/// The (total) amount that should be paid if the proposal is accepted.
 	value: Balance,
+	/// The asset `value` is denominated in, `None` for `Currency`.
+	pub asset: Option<AssetId>,
 	/// The account to whom the payment should be made if the proposal is accepted.
 	defendent: AccountId,
-	statement: Vec<u8>,
+	/// The hash of the statement, its preimage is noted with `pallet_preimage`.
+	pub statement: H256,
 	pub voters: Vec<AccountId>,
 	votes: Vec<bool>,
 	pub approved: bool,
+	/// Whether the award of an approved lawsuit has been paid out.
+	pub paid: bool,
+	/// The jurors drawn to vote, empty if any account may vote.
+	pub jurors: Vec<AccountId>,
+	/// Whether a ruling has been made.
+	pub closed: bool,
+	/// The sub-court the lawsuit is judged in.
+	pub sub_court: SubCourtIndex,
+	/// The number of times the lawsuit has been appealed.
+	pub round: u32,
+	/// The block at which the ruling of the current round was made.
+	pub ruled_at: Option<BlockNumber>,
+	/// Whether the ruling is final and has been executed.
+	pub executed: bool,
+	/// The deposit reserved from the plaintiff until the lawsuit is archived.
+	pub deposit: Balance,
+	/// What the dispute is about.
+	pub metadata: Option<CaseMetadata<BlockNumber, Tags>>,
+	/// The mediation that took place before the lawsuit went to a jury, if any.
+	pub mediation: Option<Mediation<AccountId, Balance, BlockNumber>>,
+	/// The block until which the drawn jurors can vote before absent jurors are replaced.
+	pub voting_ends: Option<BlockNumber>,
+	/// The number of times absent jurors have been replaced in the current round.
+	pub redraws: u32,
+	/// The claim in the reference currency of `PriceProvider`, `value` is fixed from it when a
+	/// ruling is made.
+	pub claim: Option<Balance>,
+	/// The part of a paid native award kept reserved on the beneficiary until the lawsuit is
+	/// archived, so that it can be clawed back if the ruling is reversed.
+	pub holdback: Balance,
+	/// The part of a paid native award covered by the insurance pool.
+	pub insured: Balance,
+	/// The reversal of the ruling by governance, if any.
+	pub reversal: Option<Reversal<BlockNumber>>,
+	/// The counterclaim of the defendant, decided by the same jury.
+	pub counterclaim: Option<Counterclaim<AccountId, Balance>>,
+	/// Whether the lawsuit is an appeal escalated from a lower court, which executes the
+	/// ruling, so that it awards nothing by itself.
+	pub escalated: bool,
 }
 
-#[frame_support::pallet]
-pub mod pallet {
-	use super::*;
-	use frame_support::pallet_prelude::*;
-	use frame_system::pallet_prelude::*;
+/// A claim of the defendant against the plaintiff, filed in an existing lawsuit.
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct Counterclaim<AccountId, Balance> {
+	/// The amount the plaintiff should pay if the counterclaim is upheld.
+	pub value: Balance,
+	/// The counterclaim in the reference currency of `PriceProvider` if the claim of the
+	/// lawsuit is, `value` is fixed from it when the counterclaim is upheld.
+	pub claim: Option<Balance>,
+	/// The hash of the statement, its preimage is noted with `pallet_preimage`.
+	pub statement: H256,
+	pub voters: Vec<AccountId>,
+	pub votes: Vec<bool>,
+	/// Whether the counterclaim has been upheld.
+	pub approved: bool,
+}
 
-	#[pallet::pallet]
-	#[pallet::without_storage_info]
-	pub struct Pallet<T, I = ()>(PhantomData<(T, I)>);
+/// The contributions to the appeal fund of one side of a lawsuit in one round.
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct AppealFund<AccountId, Balance> {
+	/// The sum of the contributions, the fund is filled once it reaches the appeal fee.
+	pub raised: Balance,
+	/// The contributors and the amount each of them reserved.
+	pub contributions: Vec<(AccountId, Balance)>,
+	/// Whether the fund paid the appeal fee, the contributions are no longer reserved then.
+	pub paid: bool,
+}
 
-	#[pallet::config]
-	pub trait Config<I: 'static = ()>: frame_system::Config {
-		/// The staking balance.
-		type Currency: Currency<Self::AccountId> + ReservableCurrency<Self::AccountId>;
+/// The reversal of an executed ruling.
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct Reversal<BlockNumber> {
+	/// The hash of the reason for the reversal.
+	pub reason: H256,
+	/// The block at which the ruling was reversed.
+	pub reversed_at: BlockNumber,
+}
 
-		/// The overarching event type.
-		type RuntimeEvent: From<Event<Self, I>>
-			+ IsType<<Self as frame_system::Config>::RuntimeEvent>;
+/// A case against a juror for misconduct in a lawsuit, ruled on by `MisconductCourt`.
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct MisconductCase<AccountId, Balance, BlockNumber> {
+	pub reporter: AccountId,
+	pub juror: AccountId,
+	/// The lawsuit the juror was drawn into.
+	pub lawsuit_id: ProposalIndex,
+	pub sub_court: SubCourtIndex,
+	/// The bond reserved from the reporter, lost if the juror is acquitted.
+	pub bond: Balance,
+	/// Whether a new jury should rule on the lawsuit again if the juror is found guilty.
+	pub reopen: bool,
+	/// The block after which the case can be dismissed if `MisconductCourt` has not ruled on
+	/// it.
+	pub deadline: BlockNumber,
+}
 
-		/// The maximum number of approvals that can wait in the spending queue.
-		///
-		/// NOTE: This parameter is also used within the Bounties Pallet extension if enabled.
-		#[pallet::constant]
-		type MaxApprovals: Get<u32>;
+/// A jury draw committed to when it is requested and made at the next block, so the
+/// randomness it uses is not known to the account requesting it.
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct JuryDraw<AccountId> {
+	/// `0` for the jury of a round, counts the replacements of absent jurors after that.
+	pub redraw: u32,
+	/// The number of jurors to draw.
+	pub count: u32,
+	/// The absent jurors being replaced, empty for a new jury.
+	pub replacing: Vec<AccountId>,
+	/// The number of blocks the draw failed at for too few jurors.
+	pub attempts: u32,
+}
+
+/// A resolution proposed by a mediator.
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct Resolution<Balance> {
+	/// The amount the defendant pays to the plaintiff.
+	pub award: Balance,
+	/// The hash of the terms of the resolution.
+	pub terms: H256,
+}
+
+/// The mediation of a lawsuit by a single mediator.
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct Mediation<AccountId, Balance, BlockNumber> {
+	pub mediator: AccountId,
+	/// The block until which a resolution can be agreed on.
+	pub deadline: BlockNumber,
+	/// The fee reserved from the plaintiff, paid to the mediator if the parties settle.
+	pub fee: Balance,
+	/// The latest resolution proposed by the mediator.
+	pub proposal: Option<Resolution<Balance>>,
+	/// The parties that accepted the proposal.
+	pub accepted_by: Vec<AccountId>,
+	/// Whether the parties did not settle and the lawsuit went to a jury.
+	pub failed: bool,
+}
+
+/// The kind of failure of an oracle service a dispute is about.
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub enum DisputeKind {
+	/// The data delivered was wrong.
+	DataAccuracy,
+	/// The data was delivered too late.
+	Latency,
+	/// The service was not available.
+	Availability,
+	/// A payment for the service was not made or not honoured.
+	Payment,
+}
+
+/// The oracle feed or request a dispute is about.
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub enum OracleSubject {
+	Feed(u64),
+	Request(u64),
+}
+
+/// The terms of an arbitration agreement, they override the court parameters for lawsuits
+/// filed under the agreement.
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct AgreementTerms<Balance, BlockNumber> {
+	/// The sub-court that rules on lawsuits filed under the agreement.
+	pub sub_court: SubCourtIndex,
+	/// The maximum value of a lawsuit filed under the agreement, in `Currency` at the prices of
+	/// `PriceProvider` for claims denominated in an asset or in the reference currency.
+	pub max_claim: Balance,
+	/// The share of the filing fee paid by the plaintiff, the defendant pays the rest.
+	pub plaintiff_fee: Perbill,
+	/// The block after which the agreement lapses, if any.
+	pub expires_at: Option<BlockNumber>,
+}
+
+/// An arbitration agreement offered by a defendant to a plaintiff.
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct ArbitrationAgreement<Balance, BlockNumber> {
+	pub terms: AgreementTerms<Balance, BlockNumber>,
+	/// Whether the plaintiff signed the agreement, only signed agreements are live.
+	pub signed: bool,
+	/// The share of the filing fee of the next lawsuit paid by the defendant, reserved from the
+	/// defendant once the agreement is signed.
+	pub reserved: Balance,
+}
+
+/// Structured information on what a lawsuit is about.
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct CaseMetadata<BlockNumber, Tags> {
+	pub kind: DisputeKind,
+	pub subject: OracleSubject,
+	/// The first block of the disputed period.
+	pub from: BlockNumber,
+	/// The last block of the disputed period.
+	pub to: BlockNumber,
+	/// Free-form tags, see `CaseTags`.
+	pub tags: Tags,
+}
+
+/// A filter on the metadata of lawsuits, `None` fields match any lawsuit. Archived lawsuits are
+/// pruned with their metadata and never match.
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Encode, Decode, Clone, Default, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct LawsuitFilter<BlockNumber> {
+	pub kind: Option<DisputeKind>,
+	pub subject: Option<OracleSubject>,
+	/// Match lawsuits whose disputed period overlaps with this range.
+	pub during: Option<(BlockNumber, BlockNumber)>,
+	/// Match lawsuits carrying this tag.
+	pub tag: Option<Vec<u8>>,
+}
+
+impl<BlockNumber: PartialOrd> LawsuitFilter<BlockNumber> {
+	/// Whether a lawsuit with `metadata` matches the filter.
+	pub fn matches<MaxTags, MaxTagLength>(
+		&self,
+		metadata: Option<&CaseMetadata<BlockNumber, CaseTags<MaxTags, MaxTagLength>>>,
+	) -> bool {
+		let metadata = match metadata {
+			Some(metadata) => metadata,
+			// Lawsuits without metadata only match the empty filter.
+			None =>
+				return self.kind.is_none() &&
+					self.subject.is_none() &&
+					self.during.is_none() &&
+					self.tag.is_none(),
+		};
+		let during =
+			|(from, to): &(BlockNumber, BlockNumber)| *from <= metadata.to && metadata.from <= *to;
+		let tagged = |tag: &Vec<u8>| metadata.tags.iter().any(|t| t[..] == tag[..]);
+		self.kind.map_or(true, |kind| kind == metadata.kind) &&
+			self.subject.map_or(true, |subject| subject == metadata.subject) &&
+			self.during.as_ref().map_or(true, during) &&
+			self.tag.as_ref().map_or(true, tagged)
 	}
+}
 
-	/// Number of proposals that have been made.
-	#[pallet::storage]
-	#[pallet::getter(fn proposal_count)]
-	pub(crate) type ProposalCount<T, I = ()> = StorageValue<_, ProposalIndex, ValueQuery>;
+/// The summary of a lawsuit kept after the full case has been pruned.
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct ArchivedLawsuit<AccountId, Balance, BlockNumber, AssetId> {
+	pub plaintiff: AccountId,
+	pub defendent: AccountId,
+	/// Whether the lawsuit was approved.
+	pub approved: bool,
+	/// The amount claimed, denominated in `asset`.
+	pub value: Balance,
+	pub asset: Option<AssetId>,
+	/// The block at which the final ruling was made.
+	pub ruled_at: BlockNumber,
+}
 
-	/// Proposals that have been made.
-	#[pallet::storage]
-	#[pallet::getter(fn proposals)]
-	pub type Proposals<T: Config<I>, I: 'static = ()> = StorageMap<
-		_,
-		Twox64Concat,
-		ProposalIndex,
-		Lawsuit<T::AccountId, BalanceOf<T, I>>,
-		OptionQuery,
-	>;
+/// The treasury that receives the fees paid to the court and its share of slashed stake.
+pub trait CourtTreasury<AccountId, Balance> {
+	/// The account of the treasury pot.
+	fn account_id() -> AccountId;
 
-	/// Proposal indices that have been approved but not yet awarded.
-	#[pallet::storage]
-	#[pallet::getter(fn approvals)]
-	pub type Approvals<T: Config<I>, I: 'static = ()> =
-		StorageValue<_, BoundedVec<ProposalIndex, T::MaxApprovals>, ValueQuery>;
+	/// Called once `amount` paid by `source` has been moved into the pot.
+	fn on_court_income(source: &AccountId, amount: Balance);
 
-	#[pallet::genesis_config]
-	pub struct GenesisConfig;
+	/// The account of the insurance pool covering awards defendants cannot pay.
+	fn insurance_account() -> AccountId;
+}
 
-	#[cfg(feature = "std")]
-	impl Default for GenesisConfig {
-		fn default() -> Self {
-			Self
+/// The state of the insurance pool.
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct InsuranceStatus<Balance> {
+	/// The funds available to cover awards.
+	pub balance: Balance,
+	/// The debts of defendants taken over by the pool and not repaid yet.
+	pub debts: Balance,
+	/// The largest shortfall the pool covers for a single award.
+	pub cap: Balance,
+}
+
+/// The activity of a juror in a sub-court.
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Encode, Decode, Clone, Default, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct JurorStats<Balance> {
+	/// The juries the juror was drawn into, replacements included.
+	pub drawn: u32,
+	/// The votes cast in rounds that have been ruled on.
+	pub votes: u32,
+	/// The votes coherent with the final ruling of executed lawsuits.
+	pub coherent: u32,
+	/// The rewards for coherent votes, delegators' shares included.
+	pub rewards: Balance,
+	/// The amounts slashed for incoherent votes and absences, delegations included.
+	pub slashes: Balance,
+}
+
+impl<Balance: Saturating> JurorStats<Balance> {
+	/// Add up the statistics of a juror in two sub-courts.
+	pub fn saturating_add(self, other: Self) -> Self {
+		JurorStats {
+			drawn: self.drawn.saturating_add(other.drawn),
+			votes: self.votes.saturating_add(other.votes),
+			coherent: self.coherent.saturating_add(other.coherent),
+			rewards: self.rewards.saturating_add(other.rewards),
+			slashes: self.slashes.saturating_add(other.slashes),
 		}
 	}
+}
 
-	#[cfg(feature = "std")]
-	impl GenesisConfig {
-		/// Direct implementation of `GenesisBuild::assimilate_storage`.
-		#[deprecated(
-			note = "use `<GensisConfig<T, I> as GenesisBuild<T, I>>::assimilate_storage` instead"
-		)]
-		pub fn assimilate_storage<T: Config<I>, I: 'static>(
-			&self,
-			storage: &mut sp_runtime::Storage,
-		) -> Result<(), String> {
-			<Self as GenesisBuild<T, I>>::assimilate_storage(self, storage)
-		}
+/// The written reasons a juror attached to a vote.
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct Justification<AccountId, Summary> {
+	pub juror: AccountId,
+	/// The vote the justification is for.
+	pub approve: bool,
+	/// The hash of the full justification.
+	pub document: H256,
+	/// A summary of the justification.
+	pub summary: Summary,
+}
+
+/// Prices of the assets lawsuits can be denominated in, in the reference currency of claims.
+pub trait PriceProvider<AssetId> {
+	/// The price of one unit of `asset`, `None` for `Currency`, if it is known.
+	fn price(asset: Option<AssetId>) -> Option<FixedU128>;
+}
+
+impl<AssetId> PriceProvider<AssetId> for () {
+	fn price(_: Option<AssetId>) -> Option<FixedU128> {
+		None
 	}
+}
 
-	#[pallet::genesis_build]
-	impl<T: Config<I>, I: 'static> GenesisBuild<T, I> for GenesisConfig {
-		fn build(&self) {}
+/// A court other pallets can submit disputes to.
+pub trait Arbitrator<AccountId> {
+	/// File a lawsuit of `plaintiff` against `defendent` that awards nothing by itself. The
+	/// ruling is reported through `OnRulingExecuted`.
+	fn file_dispute(
+		plaintiff: &AccountId,
+		defendent: &AccountId,
+		statement: H256,
+	) -> Result<ProposalIndex, DispatchError>;
+
+	/// File a case of `reporter` against `juror` for misconduct, the arbitration agreements of
+	/// the juror do not apply. The ruling is reported through `OnRulingExecuted`.
+	fn file_misconduct(
+		reporter: &AccountId,
+		juror: &AccountId,
+		statement: H256,
+	) -> Result<ProposalIndex, DispatchError> {
+		Self::file_dispute(reporter, juror, statement)
 	}
 
-	#[pallet::event]
-	#[pallet::generate_deposit(pub(super) fn deposit_event)]
-	pub enum Event<T: Config<I>, I: 'static = ()> {
-		/// New proposal.
-		Proposed {
-			proposal_index: ProposalIndex,
-		},
-		/// We have ended a spend period and will now allocate funds.
-		Spending {
-			budget_remaining: BalanceOf<T, I>,
-		},
-		/// Some funds have been deposited.
-		Deposit {
-			value: BalanceOf<T, I>,
-		},
-		/// A new spend proposal has been approved.
-		SpendApproved {
-			proposal_index: ProposalIndex,
-			amount: BalanceOf<T, I>,
-			beneficiary: T::AccountId,
-		},
-		ProposalClosed {
-			lawsuit_id: u32,
-			approve: bool,
-		},
-		/// The inactive funds of the pallet have been updated.
-		UpdatedInactive {
-			reactivated: BalanceOf<T, I>,
-			deactivated: BalanceOf<T, I>,
-		},
-		VoteSubmitted {
-			lawsuit_id: u32,
-			voter: T::AccountId,
-			approve: bool,
-		},
+	/// The weight of filing a dispute or misconduct case, drawing its jury included.
+	fn file_weight() -> Weight {
+		Weight::zero()
 	}
+}
 
-	/// Error for the treasury pallet.
-	#[pallet::error]
-	pub enum Error<T, I = ()> {
-		/// Proposer's balance is too low.
-		InsufficientProposersBalance,
-		/// No proposal or bounty at that index.
-		InvalidIndex,
-		/// Too many approvals in the queue.
-		TooManyApprovals,
-		/// The spend origin is valid but the amount it is allowed to spend is lower than the
-		/// amount to be spent.
-		InsufficientPermission,
-		/// Proposal has not been approved.
-		ProposalNotApproved,
-		ProposalAlreadyApproved,
-		ProposalNotFound,
-		LawsuitNotFound,
-		DuplicateVote,
-		StatementOverSize,
-		VoterCountTooLow,
+/// A higher court instance the appeals of a court are escalated to.
+pub trait AppealCourt<AccountId, Balance, AssetId> {
+	/// File the appeal of `appellant` against the ruling won by `respondent` as a new case over
+	/// the claim of the lawsuit, `value` in `asset` or in the reference currency if `fiat`, in
+	/// `sub_court` if the higher court has it. The ruling on the case is reported through
+	/// `OnRulingExecuted`.
+	///
+	/// The case awards nothing by itself, and neither the limits on filing lawsuits nor the
+	/// arbitration agreements of the respondent apply. Its filing fee and case deposit are
+	/// waived if the appeal was `funded` with `fund_appeal`.
+	///
+	/// Returns `None` if appeals are ruled on by a new jury of the same court instead, as they
+	/// are while the higher court has too few jurors to draw a jury for the case.
+	#[allow(clippy::too_many_arguments)]
+	fn file_appeal(
+		appellant: &AccountId,
+		respondent: &AccountId,
+		statement: H256,
+		value: Balance,
+		asset: Option<AssetId>,
+		fiat: bool,
+		sub_court: SubCourtIndex,
+		funded: bool,
+	) -> Result<Option<ProposalIndex>, DispatchError>;
+
+	/// The weight of filing an appeal, drawing its jury included.
+	fn file_weight() -> Weight {
+		Weight::zero()
 	}
+}
 
-	#[pallet::hooks]
-	impl<T: Config<I>, I: 'static> Hooks<BlockNumberFor<T>> for Pallet<T, I> {
-		fn on_initialize(_n: T::BlockNumber) -> Weight {
-			Weight::zero()
-		}
+impl<AccountId, Balance, AssetId> AppealCourt<AccountId, Balance, AssetId> for () {
+	fn file_appeal(
+		_: &AccountId,
+		_: &AccountId,
+		_: H256,
+		_: Balance,
+		_: Option<AssetId>,
+		_: bool,
+		_: SubCourtIndex,
+		_: bool,
+	) -> Result<Option<ProposalIndex>, DispatchError> {
+		Ok(None)
 	}
+}
 
-	#[pallet::call]
-	impl<T: Config<I>, I: 'static> Pallet<T, I> {
-		/// Submit a lawsuit
-		///
-		/// ## Complexity
-		/// - O(1)
-		#[pallet::call_index(0)]
-		#[pallet::weight(Weight::zero())]
-		pub fn submit_sue(
-			origin: OriginFor<T>,
-			#[pallet::compact] value: BalanceOf<T, I>,
-			defendent: AccountIdLookupOf<T>,
-			statement: Vec<u8>,
-		) -> DispatchResult {
-			let proposer = ensure_signed(origin)?;
-			let defendent = T::Lookup::lookup(defendent)?;
-			ensure!(statement.len() < 512, Error::<T, I>::StatementOverSize);
+/// Handler for lawsuits being filed.
+///
+/// Implemented for tuples, each element is called in turn.
+pub trait OnLawsuitFiled<AccountId> {
+	/// Called once a lawsuit has been filed by `plaintiff` against `defendent`.
+	///
+	/// Returns the weight consumed.
+	fn on_lawsuit_filed(
+		lawsuit_id: ProposalIndex,
+		plaintiff: &AccountId,
+		defendent: &AccountId,
+	) -> Weight;
 
-			let c = Self::proposal_count();
-			<ProposalCount<T, I>>::put(c + 1);
-			let proposal = Lawsuit {
-				plaintiff: proposer,
-				value,
-				defendent,
-				statement: statement.clone(),
-				voters: vec![],
-				votes: vec![],
-				approved: false,
-			};
-			<Proposals<T, I>>::insert(c, proposal);
+	/// The maximum weight of `on_lawsuit_filed`, charged upfront by the calls firing the hook.
+	fn max_weight() -> Weight;
+}
 
-			Self::deposit_event(Event::Proposed { proposal_index: c });
-			Ok(())
-		}
+#[impl_trait_for_tuples::impl_for_tuples(30)]
+impl<AccountId> OnLawsuitFiled<AccountId> for Tuple {
+	fn on_lawsuit_filed(
+		lawsuit_id: ProposalIndex,
+		plaintiff: &AccountId,
+		defendent: &AccountId,
+	) -> Weight {
+		let mut weight = Weight::zero();
+		for_tuples!( #(
+			weight.saturating_accrue(Tuple::on_lawsuit_filed(lawsuit_id, plaintiff, defendent));
+		)* );
+		weight
+	}
 
-		#[pallet::call_index(1)]
-		#[pallet::weight(Weight::zero())]
-		pub fn vote_sue(origin: OriginFor<T>, lawsuit_id: u32, approve: bool) -> DispatchResult {
-			let voter = ensure_signed(origin)?;
+	fn max_weight() -> Weight {
+		let mut weight = Weight::zero();
+		for_tuples!( #( weight.saturating_accrue(Tuple::max_weight()); )* );
+		weight
+	}
+}
 
-			let mut lawsuit =
-				<Proposals<T, I>>::get(lawsuit_id).ok_or(Error::<T, I>::LawsuitNotFound)?;
-			<Proposals<T, I>>::remove(lawsuit_id);
+/// Handler for votes being cast on lawsuits.
+///
+/// Implemented for tuples, each element is called in turn.
+pub trait OnVoteCast<AccountId> {
+	/// Called once `voter` voted on a lawsuit or on its counterclaim.
+	///
+	/// Returns the weight consumed.
+	fn on_vote_cast(lawsuit_id: ProposalIndex, voter: &AccountId, approve: bool) -> Weight;
 
-			// Ensure the voter hasn't voted before
-			ensure!(!lawsuit.voters.contains(&voter), Error::<T, I>::DuplicateVote);
+	/// The maximum weight of `on_vote_cast`, charged upfront by the calls firing the hook.
+	fn max_weight() -> Weight;
+}
 
-			// Add the voter and their vote to the lawsuit
-			lawsuit.voters.push(voter.clone());
-			lawsuit.votes.push(approve);
-			<Proposals<T, I>>::insert(lawsuit_id, lawsuit);
+#[impl_trait_for_tuples::impl_for_tuples(30)]
+impl<AccountId> OnVoteCast<AccountId> for Tuple {
+	fn on_vote_cast(lawsuit_id: ProposalIndex, voter: &AccountId, approve: bool) -> Weight {
+		let mut weight = Weight::zero();
+		for_tuples!( #(
+			weight.saturating_accrue(Tuple::on_vote_cast(lawsuit_id, voter, approve));
+		)* );
+		weight
+	}
 
-			Self::deposit_event(Event::VoteSubmitted { lawsuit_id, voter, approve });
-			Ok(())
-		}
+	fn max_weight() -> Weight {
+		let mut weight = Weight::zero();
+		for_tuples!( #( weight.saturating_accrue(Tuple::max_weight()); )* );
+		weight
+	}
+}
 
-		#[pallet::call_index(2)]
-		#[pallet::weight((Weight::zero(), DispatchClass::Operational))]
-		pub fn process_sue(origin: OriginFor<T>, lawsuit_id: u32) -> DispatchResult {
-			ensure_signed(origin)?;
-			let mut proposal =
-				<Proposals<T, I>>::get(lawsuit_id).ok_or(Error::<T, I>::ProposalNotFound)?;
-			<Proposals<T, I>>::remove(lawsuit_id);
+/// Handler for rulings being made on lawsuits.
+///
+/// Implemented for tuples, each element is called in turn.
+pub trait OnRuled<AccountId> {
+	/// Called once a ruling has been made on a lawsuit, it can still be appealed.
+	///
+	/// Returns the weight consumed.
+	fn on_ruled(lawsuit_id: ProposalIndex, approved: bool) -> Weight;
 
-			// Ensure that the proposal is not already approved
-			ensure!(!proposal.approved, Error::<T, I>::ProposalAlreadyApproved);
+	/// The maximum weight of `on_ruled`, charged upfront by the calls firing the hook.
+	fn max_weight() -> Weight;
+}
 
-			// Update the tally of votes
-			let vote_count = proposal.votes.iter().filter(|v| **v).count() as u32;
-			let voter_count = proposal.votes.len();
+#[impl_trait_for_tuples::impl_for_tuples(30)]
+impl<AccountId> OnRuled<AccountId> for Tuple {
+	fn on_ruled(lawsuit_id: ProposalIndex, approved: bool) -> Weight {
+		let mut weight = Weight::zero();
+		for_tuples!( #(
+			weight.saturating_accrue(Tuple::on_ruled(lawsuit_id, approved));
+		)* );
+		weight
+	}
 
-			ensure!(voter_count > 3, Error::<T, I>::VoterCountTooLow);
+	fn max_weight() -> Weight {
+		let mut weight = Weight::zero();
+		for_tuples!( #( weight.saturating_accrue(Tuple::max_weight()); )* );
+		weight
+	}
+}
 
-			let approval_threshold = (voter_count as u32) * 3 / 4; // Simple majority
+/// Handler for rulings on lawsuits being executed.
+///
+/// Implemented for tuples, each element is called in turn.
+pub trait OnRulingExecuted<AccountId> {
+	/// Called once the final ruling on a lawsuit has been executed, mediated settlements included.
+	///
+	/// Returns the weight consumed.
+	fn on_ruling_executed(
+		lawsuit_id: ProposalIndex,
+		plaintiff: &AccountId,
+		defendent: &AccountId,
+		approved: bool,
+	) -> Weight;
 
-			if vote_count >= approval_threshold {
-				// Proposal is approved, execute the proposal
-				T::Currency::transfer(
-					&proposal.defendent,
-					&proposal.plaintiff,
-					proposal.value,
-					frame_support::traits::ExistenceRequirement::KeepAlive,
-				)?;
-				proposal.approved = true;
-				<Proposals<T, I>>::insert(lawsuit_id, &proposal);
-				Approvals::<T, I>::try_append(lawsuit_id)
-					.map_err(|_| Error::<T, I>::TooManyApprovals)?;
-				Self::deposit_event(Event::ProposalClosed { lawsuit_id, approve: true });
-			} else {
-				proposal.approved = false;
-				<Proposals<T, I>>::insert(lawsuit_id, &proposal);
-				Self::deposit_event(Event::ProposalClosed { lawsuit_id, approve: false });
-			}
+	/// Called once the executed approval of a lawsuit has been reversed by governance.
+	///
+	/// Returns the weight consumed.
+	fn on_ruling_reversed(
+		_lawsuit_id: ProposalIndex,
+		_plaintiff: &AccountId,
+		_defendent: &AccountId,
+	) -> Weight {
+		Weight::zero()
+	}
 
-			Ok(())
-		}
+	/// Called once an executed lawsuit is archived, its ruling can no longer be reversed.
+	///
+	/// Returns the weight consumed.
+	fn on_lawsuit_archived(_lawsuit_id: ProposalIndex) -> Weight {
+		Weight::zero()
+	}
 
-		/// Force a previously approved lawsuit to be removed from the approval queue.
-		///
-		/// - `lawsuit_id`: The index of a lawsuit
-		///
+	/// Called instead of `on_ruling_executed` once a lawsuit has been withdrawn by the plaintiff
+	/// before a ruling, there is neither a ruling nor a loser.
+	///
+	/// Returns the weight consumed.
+	fn on_lawsuit_withdrawn(
+		_lawsuit_id: ProposalIndex,
+		_plaintiff: &AccountId,
+		_defendent: &AccountId,
+	) -> Weight {
+		Weight::zero()
+	}
+
+	/// The maximum weight of any of the handlers, charged upfront by the calls firing them.
+	fn max_weight() -> Weight;
+}
+
+#[impl_trait_for_tuples::impl_for_tuples(30)]
+impl<AccountId> OnRulingExecuted<AccountId> for Tuple {
+	fn on_ruling_executed(
+		lawsuit_id: ProposalIndex,
+		plaintiff: &AccountId,
+		defendent: &AccountId,
+		approved: bool,
+	) -> Weight {
+		let mut weight = Weight::zero();
+		for_tuples!( #(
+			weight.saturating_accrue(Tuple::on_ruling_executed(
+				lawsuit_id, plaintiff, defendent, approved,
+			));
+		)* );
+		weight
+	}
+
+	fn on_ruling_reversed(
+		lawsuit_id: ProposalIndex,
+		plaintiff: &AccountId,
+		defendent: &AccountId,
+	) -> Weight {
+		let mut weight = Weight::zero();
+		for_tuples!( #(
+			weight.saturating_accrue(Tuple::on_ruling_reversed(lawsuit_id, plaintiff, defendent));
+		)* );
+		weight
+	}
+
+	fn on_lawsuit_archived(lawsuit_id: ProposalIndex) -> Weight {
+		let mut weight = Weight::zero();
+		for_tuples!( #( weight.saturating_accrue(Tuple::on_lawsuit_archived(lawsuit_id)); )* );
+		weight
+	}
+
+	fn on_lawsuit_withdrawn(
+		lawsuit_id: ProposalIndex,
+		plaintiff: &AccountId,
+		defendent: &AccountId,
+	) -> Weight {
+		let mut weight = Weight::zero();
+		for_tuples!( #(
+			weight.saturating_accrue(Tuple::on_lawsuit_withdrawn(lawsuit_id, plaintiff, defendent));
+		)* );
+		weight
+	}
+
+	fn max_weight() -> Weight {
+		let mut weight = Weight::zero();
+		for_tuples!( #( weight.saturating_accrue(Tuple::max_weight()); )* );
+		weight
+	}
+}
+
+/// Handler for rulings on lawsuits being appealed.
+///
+/// Implemented for tuples, each element is called in turn.
+pub trait OnAppealed<AccountId> {
+	/// Called once `appellant` appealed the ruling on a lawsuit, starting `round`.
+	///
+	/// Returns the weight consumed.
+	fn on_appealed(lawsuit_id: ProposalIndex, appellant: &AccountId, round: u32) -> Weight;
+
+	/// The maximum weight of `on_appealed`, charged upfront by the calls firing the hook.
+	fn max_weight() -> Weight;
+}
+
+#[impl_trait_for_tuples::impl_for_tuples(30)]
+impl<AccountId> OnAppealed<AccountId> for Tuple {
+	fn on_appealed(lawsuit_id: ProposalIndex, appellant: &AccountId, round: u32) -> Weight {
+		let mut weight = Weight::zero();
+		for_tuples!( #(
+			weight.saturating_accrue(Tuple::on_appealed(lawsuit_id, appellant, round));
+		)* );
+		weight
+	}
+
+	fn max_weight() -> Weight {
+		let mut weight = Weight::zero();
+		for_tuples!( #( weight.saturating_accrue(Tuple::max_weight()); )* );
+		weight
+	}
+}
+
+/// A sub-court with its own requirements on jurors.
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Encode, Decode, Clone, Default, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct SubCourt {
+	/// The reputation a juror needs to be drawn into juries of the sub-court.
+	pub min_reputation: u32,
+}
+
+/// The reputation score of a juror.
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct Reputation<BlockNumber> {
+	/// The score at `updated_at`, it decays afterwards.
+	pub score: u32,
+	/// The block at which the score was last updated.
+	pub updated_at: BlockNumber,
+}
+
+/// A juror who bonded stake to be drawn into juries.
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct Juror<Balance> {
+	/// The stake bonded by the juror.
+	pub bond: Balance,
+	/// The total stake delegated to the juror.
+	pub delegated: Balance,
+	/// The share of rewards kept by the juror before the rest is shared with delegators.
+	pub commission: Perbill,
+}
+
+impl<Balance: Saturating + Copy> Juror<Balance> {
+	/// The weight of the juror in jury selection.
+	pub fn weight(&self) -> Balance {
+		self.bond.saturating_add(self.delegated)
+	}
+}
+
+/// Stake that is waiting for the unbonding period to pass.
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct UnbondingChunk<Balance, BlockNumber> {
+	/// The amount that will be unreserved.
+	pub value: Balance,
+	/// The block from which the amount can be withdrawn.
+	pub unlock_at: BlockNumber,
+}
+
+/// Origin for the court module.
+#[derive(PartialEq, Eq, Clone, RuntimeDebug, Encode, Decode, TypeInfo, MaxEncodedLen)]
+#[scale_info(skip_type_params(I))]
+pub enum RawOrigin<AccountId, I> {
+	/// The remedy of an approved lawsuit, filed by `plaintiff`.
+	Remedy { lawsuit_id: ProposalIndex, plaintiff: AccountId },
+	/// Dummy to manage the fact we have instancing.
+	_Phantom(PhantomData<I>),
+}
+
+/// Ensure the origin is the remedy of an approved lawsuit, returning the lawsuit and its
+/// plaintiff.
+pub struct EnsureRemedy<AccountId, I: 'static = ()>(PhantomData<(AccountId, I)>);
+impl<
+		O: Into<Result<RawOrigin<AccountId, I>, O>> + From<RawOrigin<AccountId, I>>,
+		AccountId: Default,
+		I,
+	> EnsureOrigin<O> for EnsureRemedy<AccountId, I>
+{
+	type Success = (ProposalIndex, AccountId);
+	fn try_origin(o: O) -> Result<Self::Success, O> {
+		o.into().and_then(|o| match o {
+			RawOrigin::Remedy { lawsuit_id, plaintiff } => Ok((lawsuit_id, plaintiff)),
+			r => Err(O::from(r)),
+		})
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn try_successful_origin() -> Result<O, ()> {
+		Ok(O::from(RawOrigin::Remedy { lawsuit_id: 0, plaintiff: AccountId::default() }))
+	}
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+
+	/// The current storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
+	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
+	#[pallet::without_storage_info]
+	pub struct Pallet<T, I = ()>(PhantomData<(T, I)>);
+
+	#[pallet::config]
+	pub trait Config<I: 'static = ()>: frame_system::Config {
+		/// The staking balance.
+		type Currency: Currency<Self::AccountId> + ReservableCurrency<Self::AccountId>;
+
+		/// The overarching event type.
+		type RuntimeEvent: From<Event<Self, I>>
+			+ IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// The maximum number of approvals that can wait in the payout queue.
+		///
+		/// Approvals beyond this limit are deferred and enter the queue as it drains.
+		#[pallet::constant]
+		type MaxApprovals: Get<u32>;
+
+		/// The maximum number of approvals deferred while the payout queue is full.
+		///
+		/// Approvals beyond this limit are abandoned and can be queued again with `retry_award`.
+		#[pallet::constant]
+		type MaxDeferredApprovals: Get<u32>;
+
+		/// The number of times paying an award is attempted before it is abandoned.
+		#[pallet::constant]
+		type MaxPayoutAttempts: Get<u32>;
+
+		/// Something that provides randomness for drawing jurors.
+		type Randomness: Randomness<Self::Hash, Self::BlockNumber>;
+
+		/// The number of jurors drawn for each lawsuit, zero leaves voting open to any account.
+		#[pallet::constant]
+		type JurySize: Get<u32>;
+
+		/// The maximum number of jurors in the pool, bounding the cost of drawing a jury.
+		#[pallet::constant]
+		type MaxJurors: Get<u32>;
+
+		/// The number of blocks in a row a jury draw is attempted at while the sub-court has too
+		/// few jurors, before it is abandoned.
+		#[pallet::constant]
+		type MaxDrawAttempts: Get<u32>;
+
+		/// The minimum stake a juror has to bond.
+		#[pallet::constant]
+		type MinJurorBond: Get<BalanceOf<Self, I>>;
+
+		/// The number of blocks bonded and delegated stake stays reserved after it is withdrawn.
+		#[pallet::constant]
+		type UnbondingPeriod: Get<Self::BlockNumber>;
+
+		/// The share of stake slashed from a juror, and their delegators, for voting against the
+		/// ruling. The slashed funds are shared among the jurors who voted with the ruling.
+		#[pallet::constant]
+		type IncoherenceSlash: Get<Perbill>;
+
+		/// The preimage provider holding the statements of lawsuits.
+		type Preimages: QueryPreimage;
+
+		/// The origin that manages sub-courts.
+		type CourtAdminOrigin: EnsureOrigin<<Self as frame_system::Config>::RuntimeOrigin>;
+
+		/// The reputation score of a new juror.
+		#[pallet::constant]
+		type InitialReputation: Get<u32>;
+
+		/// The highest reputation score, a juror with it is drawn by stake alone.
+		#[pallet::constant]
+		type MaxReputation: Get<u32>;
+
+		/// The reputation gained for voting with the ruling.
+		#[pallet::constant]
+		type ReputationGain: Get<u32>;
+
+		/// The reputation lost for voting against the ruling or not voting when drawn.
+		#[pallet::constant]
+		type ReputationLoss: Get<u32>;
+
+		/// The share of reputation lost every `ReputationDecayPeriod`.
+		#[pallet::constant]
+		type ReputationDecay: Get<Perbill>;
+
+		/// The number of blocks after which reputation decays.
+		#[pallet::constant]
+		type ReputationDecayPeriod: Get<Self::BlockNumber>;
+
+		/// Identifier of the assets lawsuits can be denominated in.
+		type AssetId: Member + Parameter + Copy + MaxEncodedLen;
+
+		/// The assets lawsuits can be denominated in besides `Currency`. Fees and juror stake
+		/// are always paid in `Currency`.
+		type Assets: fungibles::Inspect<
+				Self::AccountId,
+				AssetId = Self::AssetId,
+				Balance = BalanceOf<Self, I>,
+			> + fungibles::Transfer<Self::AccountId>;
+
+		/// The treasury receiving court fees and its share of slashed stake.
+		type Treasury: CourtTreasury<Self::AccountId, BalanceOf<Self, I>>;
+
+		/// The fee paid by the plaintiff to file a lawsuit.
+		#[pallet::constant]
+		type FilingFee: Get<BalanceOf<Self, I>>;
+
+		/// The fee paid to appeal the first ruling, it doubles with each further round.
+		#[pallet::constant]
+		type AppealFee: Get<BalanceOf<Self, I>>;
+
+		/// The number of blocks after a ruling during which the losing party can appeal. The
+		/// ruling is executed straight away if zero.
+		#[pallet::constant]
+		type AppealPeriod: Get<Self::BlockNumber>;
+
+		/// The maximum number of times a lawsuit can be appealed.
+		#[pallet::constant]
+		type MaxAppeals: Get<u32>;
+
+		/// The share of slashed stake forwarded to the treasury. Fees are forwarded in full.
+		#[pallet::constant]
+		type TreasuryShare: Get<Perbill>;
+
+		/// The deposit reserved from the plaintiff for the storage of a lawsuit, refunded when
+		/// the lawsuit is archived.
+		#[pallet::constant]
+		type CaseDeposit: Get<BalanceOf<Self, I>>;
+
+		/// The number of blocks a lawsuit is kept in full after its ruling has been executed.
+		#[pallet::constant]
+		type RetentionPeriod: Get<Self::BlockNumber>;
+
+		/// The maximum number of open lawsuits a plaintiff can have.
+		#[pallet::constant]
+		type MaxOpenSuits: Get<u32>;
+
+		/// The maximum number of open lawsuits a plaintiff can have against one defendant.
+		#[pallet::constant]
+		type MaxOpenSuitsPerDefendant: Get<u32>;
+
+		/// The number of blocks a plaintiff has to wait to file again after a lawsuit has been
+		/// rejected.
+		#[pallet::constant]
+		type RejectionCooldown: Get<Self::BlockNumber>;
+
+		/// The maximum number of tags in the metadata of a lawsuit.
+		#[pallet::constant]
+		type MaxTags: Get<u32>;
+
+		/// The maximum length of a tag in the metadata of a lawsuit.
+		#[pallet::constant]
+		type MaxTagLength: Get<u32>;
+
+		/// The maximum number of times the statement of a lawsuit can be amended.
+		#[pallet::constant]
+		type MaxAmendments: Get<u32>;
+
+		/// The fee paid by the plaintiff to the mediator if the parties settle.
+		#[pallet::constant]
+		type MediationFee: Get<BalanceOf<Self, I>>;
+
+		/// The number of blocks the parties have to settle before the lawsuit goes to a jury.
+		#[pallet::constant]
+		type MediationPeriod: Get<Self::BlockNumber>;
+
+		/// The overarching origin type.
+		type RuntimeOrigin: From<RawOrigin<Self::AccountId, I>>;
+
+		/// The call a lawsuit can carry as remedy, dispatched with the court origin when the
+		/// lawsuit is approved.
+		type RuntimeCall: Parameter
+			+ Dispatchable<
+				RuntimeOrigin = <Self as Config<I>>::RuntimeOrigin,
+				PostInfo = PostDispatchInfo,
+			> + GetDispatchInfo;
+
+		/// The calls that can be remedies.
+		type RemedyFilter: Contains<<Self as Config<I>>::RuntimeCall>;
+
+		/// The maximum encoded length of a remedy.
+		#[pallet::constant]
+		type MaxRemedyLength: Get<u32>;
+
+		/// The maximum weight of a remedy, charged upfront by the calls executing rulings.
+		#[pallet::constant]
+		type MaxRemedyWeight: Get<Weight>;
+
+		/// The number of blocks drawn jurors have to vote before absent jurors can be replaced.
+		#[pallet::constant]
+		type VotingPeriod: Get<Self::BlockNumber>;
+
+		/// The share of stake slashed from a juror, and their delegators, for not voting by the
+		/// end of the voting period. The slashed funds go to the treasury.
+		#[pallet::constant]
+		type NoShowSlash: Get<Perbill>;
+
+		/// The maximum number of times absent jurors are replaced in a round.
+		#[pallet::constant]
+		type MaxRedraws: Get<u32>;
+
+		/// The number of voting periods in a row a juror can miss before being suspended.
+		#[pallet::constant]
+		type MaxAbsences: Get<u32>;
+
+		/// The number of blocks a suspended juror is not drawn into juries.
+		#[pallet::constant]
+		type SuspensionPeriod: Get<Self::BlockNumber>;
+
+		/// The prices used to fix the value of claims in the reference currency.
+		type Prices: PriceProvider<Self::AssetId>;
+
+		/// Handler for lawsuits being filed.
+		type OnLawsuitFiled: OnLawsuitFiled<Self::AccountId>;
+
+		/// Handler for votes being cast.
+		type OnVoteCast: OnVoteCast<Self::AccountId>;
+
+		/// Handler for rulings being made.
+		type OnRuled: OnRuled<Self::AccountId>;
+
+		/// Handler for rulings being executed.
+		type OnRulingExecuted: OnRulingExecuted<Self::AccountId>;
+
+		/// Handler for rulings being appealed.
+		type OnAppealed: OnAppealed<Self::AccountId>;
+
+		/// The share of a paid native award kept reserved on the beneficiary until the lawsuit
+		/// is archived.
+		#[pallet::constant]
+		type AwardHoldback: Get<Perbill>;
+
+		/// The largest shortfall of a native award the insurance pool covers.
+		#[pallet::constant]
+		type InsuranceCap: Get<BalanceOf<Self, I>>;
+
+		/// The court ruling on misconduct of jurors, usually a higher court instance.
+		type MisconductCourt: Arbitrator<Self::AccountId>;
+
+		/// The bond reserved from the reporter of misconduct.
+		#[pallet::constant]
+		type MisconductBond: Get<BalanceOf<Self, I>>;
+
+		/// The period `MisconductCourt` has to rule on a misconduct case before it can be
+		/// dismissed.
+		#[pallet::constant]
+		type MisconductTimeout: Get<Self::BlockNumber>;
+
+		/// The share of the stake of a juror slashed for misconduct, the rest is unbonded.
+		#[pallet::constant]
+		type MisconductSlash: Get<Perbill>;
+
+		/// The court appeals are escalated to, `()` if appeals are ruled on by a new jury of this
+		/// court.
+		type HigherCourt: AppealCourt<Self::AccountId, BalanceOf<Self, I>, Self::AssetId>;
+
+		/// The period `HigherCourt` has to rule on an escalated appeal before a new jury of this
+		/// court can rule on it instead.
+		#[pallet::constant]
+		type EscalationTimeout: Get<Self::BlockNumber>;
+
+		/// The maximum length of the summary of a justification.
+		#[pallet::constant]
+		type MaxSummaryLength: Get<u32>;
+	}
+
+	#[pallet::origin]
+	pub type Origin<T, I = ()> = RawOrigin<<T as frame_system::Config>::AccountId, I>;
+
+	/// Number of proposals that have been made.
+	#[pallet::storage]
+	#[pallet::getter(fn proposal_count)]
+	pub(crate) type ProposalCount<T, I = ()> = StorageValue<_, ProposalIndex, ValueQuery>;
+
+	/// Proposals that have been made.
+	#[pallet::storage]
+	#[pallet::getter(fn proposals)]
+	pub type Proposals<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Twox64Concat, ProposalIndex, LawsuitOf<T, I>, OptionQuery>;
+
+	/// Jurors that can be drawn into juries.
+	#[pallet::storage]
+	#[pallet::getter(fn jurors)]
+	pub type Jurors<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Twox64Concat, T::AccountId, Juror<BalanceOf<T, I>>, OptionQuery>;
+
+	/// The number of jurors in the pool, at most `MaxJurors`.
+	#[pallet::storage]
+	#[pallet::getter(fn juror_count)]
+	pub type JurorCount<T: Config<I>, I: 'static = ()> = StorageValue<_, u32, ValueQuery>;
+
+	/// Stake delegated to a juror, keyed by juror and delegator.
+	#[pallet::storage]
+	#[pallet::getter(fn delegations)]
+	pub type Delegations<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		T::AccountId,
+		Twox64Concat,
+		T::AccountId,
+		BalanceOf<T, I>,
+		ValueQuery,
+	>;
+
+	/// Number of sub-courts that have been created, the general court excluded.
+	#[pallet::storage]
+	#[pallet::getter(fn sub_court_count)]
+	pub type SubCourtCount<T, I = ()> = StorageValue<_, SubCourtIndex, ValueQuery>;
+
+	/// The requirements of each sub-court.
+	#[pallet::storage]
+	#[pallet::getter(fn sub_courts)]
+	pub type SubCourts<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Twox64Concat, SubCourtIndex, SubCourt, ValueQuery>;
+
+	/// The reputation of jurors.
+	#[pallet::storage]
+	#[pallet::getter(fn reputations)]
+	pub type Reputations<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Twox64Concat, T::AccountId, Reputation<T::BlockNumber>, OptionQuery>;
+
+	/// Bonded and delegated stake waiting to be withdrawn.
+	#[pallet::storage]
+	#[pallet::getter(fn unbonding)]
+	pub type Unbonding<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Twox64Concat,
+		T::AccountId,
+		Vec<UnbondingChunk<BalanceOf<T, I>, T::BlockNumber>>,
+		ValueQuery,
+	>;
+
+	/// Proposal indices that have been approved but not yet awarded.
+	#[pallet::storage]
+	#[pallet::getter(fn approvals)]
+	pub type Approvals<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, BoundedVec<ProposalIndex, T::MaxApprovals>, ValueQuery>;
+
+	/// Proposal indices that have been approved while the payout queue was full.
+	#[pallet::storage]
+	#[pallet::getter(fn deferred_approvals)]
+	pub type DeferredApprovals<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, BoundedVec<ProposalIndex, T::MaxDeferredApprovals>, ValueQuery>;
+
+	/// The failed attempts to pay the award of a queued lawsuit.
+	#[pallet::storage]
+	#[pallet::getter(fn payout_attempts)]
+	pub type PayoutAttempts<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Twox64Concat, ProposalIndex, u32, ValueQuery>;
+
+	/// Executed lawsuits with the block from which they can be archived, oldest first.
+	#[pallet::storage]
+	#[pallet::getter(fn archive_queue)]
+	pub type ArchiveQueue<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, Vec<(ProposalIndex, T::BlockNumber)>, ValueQuery>;
+
+	/// Summaries of archived lawsuits.
+	#[pallet::storage]
+	#[pallet::getter(fn archive)]
+	pub type Archive<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Twox64Concat, ProposalIndex, ArchivedLawsuitOf<T, I>, OptionQuery>;
+
+	/// The number of lawsuits filed by each plaintiff whose ruling has not been executed.
+	#[pallet::storage]
+	#[pallet::getter(fn open_suits)]
+	pub type OpenSuits<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Twox64Concat, T::AccountId, u32, ValueQuery>;
+
+	/// The number of open lawsuits by plaintiff and defendant.
+	#[pallet::storage]
+	#[pallet::getter(fn open_suits_against)]
+	pub type OpenSuitsAgainst<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		T::AccountId,
+		Twox64Concat,
+		T::AccountId,
+		u32,
+		ValueQuery,
+	>;
+
+	/// The block until which a plaintiff cannot file after a rejected lawsuit.
+	#[pallet::storage]
+	#[pallet::getter(fn cooldowns)]
+	pub type Cooldowns<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Twox64Concat, T::AccountId, T::BlockNumber, OptionQuery>;
+
+	/// Mediators approved by governance.
+	#[pallet::storage]
+	#[pallet::getter(fn mediators)]
+	pub type Mediators<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, Vec<T::AccountId>, ValueQuery>;
+
+	/// Accounts exempt from the limits on filing lawsuits.
+	#[pallet::storage]
+	#[pallet::getter(fn exempt)]
+	pub type Exempt<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Twox64Concat, T::AccountId, bool, ValueQuery>;
+
+	/// The number of archived approved lawsuits each account voted on.
+	#[pallet::storage]
+	#[pallet::getter(fn archived_contributions)]
+	pub type ArchivedContributions<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Twox64Concat, T::AccountId, u32, ValueQuery>;
+
+	/// The remedies dispatched if the lawsuits are approved.
+	#[pallet::storage]
+	#[pallet::getter(fn remedies)]
+	pub type Remedies<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Twox64Concat, ProposalIndex, <T as Config<I>>::RuntimeCall, OptionQuery>;
+
+	/// The number of voting periods in a row each juror missed.
+	#[pallet::storage]
+	#[pallet::getter(fn absences)]
+	pub type Absences<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Twox64Concat, T::AccountId, u32, ValueQuery>;
+
+	/// The block until which each suspended juror is not drawn into juries.
+	#[pallet::storage]
+	#[pallet::getter(fn suspensions)]
+	pub type Suspensions<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Twox64Concat, T::AccountId, T::BlockNumber, OptionQuery>;
+
+	/// The jury draws committed to for lawsuits that have not been made yet.
+	#[pallet::storage]
+	#[pallet::getter(fn pending_draws)]
+	pub type PendingDraws<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Twox64Concat, ProposalIndex, JuryDraw<T::AccountId>, OptionQuery>;
+
+	/// The lawsuits whose jury is drawn at each block.
+	#[pallet::storage]
+	pub type DrawQueue<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Twox64Concat, T::BlockNumber, Vec<ProposalIndex>, ValueQuery>;
+
+	/// The number of juries each juror sits on that have not been settled. The stake of the
+	/// juror and the delegations to the juror are locked while it is not zero.
+	#[pallet::storage]
+	#[pallet::getter(fn drawn_into)]
+	pub type DrawnInto<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Twox64Concat, T::AccountId, u32, ValueQuery>;
+
+	/// The debt of each defendant whose award was covered by the insurance pool.
+	#[pallet::storage]
+	#[pallet::getter(fn insurance_debts)]
+	pub type InsuranceDebts<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Twox64Concat, T::AccountId, BalanceOf<T, I>, ValueQuery>;
+
+	/// The sum of `InsuranceDebts`.
+	#[pallet::storage]
+	#[pallet::getter(fn total_insurance_debt)]
+	pub type TotalInsuranceDebt<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, BalanceOf<T, I>, ValueQuery>;
+
+	/// The appeal funds of each lawsuit, keyed by the round being appealed and the side backed
+	/// by the fund, `true` for the plaintiff.
+	#[pallet::storage]
+	#[pallet::getter(fn appeal_funds)]
+	pub type AppealFunds<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		ProposalIndex,
+		Twox64Concat,
+		(u32, bool),
+		AppealFund<T::AccountId, BalanceOf<T, I>>,
+		OptionQuery,
+	>;
+
+	/// The arbitration agreements, keyed by the defendant and the plaintiff.
+	#[pallet::storage]
+	#[pallet::getter(fn agreements)]
+	pub type Agreements<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		T::AccountId,
+		Twox64Concat,
+		T::AccountId,
+		ArbitrationAgreement<BalanceOf<T, I>, T::BlockNumber>,
+		OptionQuery,
+	>;
+
+	/// Every version of the statement of each open lawsuit and the block it was submitted at,
+	/// the last one is the current statement.
+	#[pallet::storage]
+	#[pallet::getter(fn statement_history)]
+	pub type StatementHistory<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Twox64Concat, ProposalIndex, Vec<(H256, T::BlockNumber)>, ValueQuery>;
+
+	/// The statistics of each juror, per sub-court.
+	#[pallet::storage]
+	#[pallet::getter(fn juror_statistics)]
+	pub type JurorStatistics<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		SubCourtIndex,
+		Twox64Concat,
+		T::AccountId,
+		JurorStats<BalanceOf<T, I>>,
+		ValueQuery,
+	>;
+
+	/// The votes of jurors in the ruled rounds of each lawsuit, checked against the final
+	/// ruling once it is executed.
+	#[pallet::storage]
+	pub type CastVotes<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Twox64Concat, ProposalIndex, Vec<(T::AccountId, bool)>, ValueQuery>;
+
+	/// The open misconduct cases, keyed by their index in `MisconductCourt`.
+	#[pallet::storage]
+	#[pallet::getter(fn misconduct_cases)]
+	pub type MisconductCases<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Twox64Concat,
+		ProposalIndex,
+		MisconductCase<T::AccountId, BalanceOf<T, I>, T::BlockNumber>,
+		OptionQuery,
+	>;
+
+	/// The number of open misconduct cases against each juror, the stake of an accused juror
+	/// cannot leave the pool until they are closed.
+	#[pallet::storage]
+	#[pallet::getter(fn accusations)]
+	pub type Accusations<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Twox64Concat, T::AccountId, u32, ValueQuery>;
+
+	/// The number of open misconduct cases that may reopen each lawsuit, the ruling on the
+	/// lawsuit cannot be executed until they are ruled on.
+	#[pallet::storage]
+	#[pallet::getter(fn pending_reopens)]
+	pub type PendingReopens<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Twox64Concat, ProposalIndex, u32, ValueQuery>;
+
+	/// The lawsuits escalated to `HigherCourt`, keyed by the index of their case there, whether
+	/// the plaintiff appealed and the block after which the appeal can be ruled on by a new jury
+	/// of this court instead.
+	#[pallet::storage]
+	#[pallet::getter(fn escalations)]
+	pub type Escalations<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Twox64Concat,
+		ProposalIndex,
+		(ProposalIndex, bool, T::BlockNumber),
+		OptionQuery,
+	>;
+
+	/// Jurors banned from the pool for misconduct.
+	#[pallet::storage]
+	#[pallet::getter(fn banned)]
+	pub type Banned<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Twox64Concat, T::AccountId, bool, ValueQuery>;
+
+	/// Accounts that can only be sued under a live arbitration agreement.
+	#[pallet::storage]
+	#[pallet::getter(fn agreement_required)]
+	pub type AgreementRequired<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Twox64Concat, T::AccountId, bool, ValueQuery>;
+
+	/// The justifications attached to the votes of the current round of a lawsuit.
+	#[pallet::storage]
+	pub type PendingJustifications<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Twox64Concat, ProposalIndex, Vec<JustificationOf<T, I>>, ValueQuery>;
+
+	/// The justifications of the jurors who voted with the majority for the last ruling of a jury
+	/// on a lawsuit, kept once the lawsuit is archived. They are cleared once the ruling is
+	/// overridden by `HigherCourt`, which keeps its own under the index of its case.
+	#[pallet::storage]
+	#[pallet::getter(fn reasons)]
+	pub type Reasons<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Twox64Concat, ProposalIndex, Vec<JustificationOf<T, I>>, ValueQuery>;
+
+	#[pallet::genesis_config]
+	pub struct GenesisConfig;
+
+	#[cfg(feature = "std")]
+	impl Default for GenesisConfig {
+		fn default() -> Self {
+			Self
+		}
+	}
+
+	#[cfg(feature = "std")]
+	impl GenesisConfig {
+		/// Direct implementation of `GenesisBuild::assimilate_storage`.
+		#[deprecated(
+			note = "use `<GensisConfig<T, I> as GenesisBuild<T, I>>::assimilate_storage` instead"
+		)]
+		pub fn assimilate_storage<T: Config<I>, I: 'static>(
+			&self,
+			storage: &mut sp_runtime::Storage,
+		) -> Result<(), String> {
+			<Self as GenesisBuild<T, I>>::assimilate_storage(self, storage)
+		}
+	}
+
+	#[pallet::genesis_build]
+	impl<T: Config<I>, I: 'static> GenesisBuild<T, I> for GenesisConfig {
+		fn build(&self) {}
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config<I>, I: 'static = ()> {
+		/// New proposal.
+		Proposed {
+			proposal_index: ProposalIndex,
+		},
+		/// We have ended a spend period and will now allocate funds.
+		Spending {
+			budget_remaining: BalanceOf<T, I>,
+		},
+		/// Some funds have been deposited.
+		Deposit {
+			value: BalanceOf<T, I>,
+		},
+		/// A new spend proposal has been approved.
+		SpendApproved {
+			proposal_index: ProposalIndex,
+			amount: BalanceOf<T, I>,
+			beneficiary: T::AccountId,
+		},
+		ProposalClosed {
+			lawsuit_id: u32,
+			approve: bool,
+		},
+		/// The inactive funds of the pallet have been updated.
+		UpdatedInactive {
+			reactivated: BalanceOf<T, I>,
+			deactivated: BalanceOf<T, I>,
+		},
+		VoteSubmitted {
+			lawsuit_id: u32,
+			voter: T::AccountId,
+			approve: bool,
+		},
+		/// The award of an approved lawsuit has been paid.
+		AwardPaid {
+			lawsuit_id: u32,
+			amount: BalanceOf<T, I>,
+			beneficiary: T::AccountId,
+		},
+		/// Paying the award of an approved lawsuit failed, it is retried up to
+		/// `MaxPayoutAttempts` times.
+		AwardFailed {
+			lawsuit_id: u32,
+			error: DispatchError,
+		},
+		/// The payout queue is full, the award waits until a slot is freed.
+		AwardDeferred {
+			lawsuit_id: u32,
+		},
+		/// The award could not be paid or queued, it can be queued again with `retry_award`.
+		AwardAbandoned {
+			lawsuit_id: u32,
+		},
+		/// Too few jurors could be drawn for a lawsuit, the draw is retried at the next block.
+		JuryDrawDelayed {
+			lawsuit_id: u32,
+		},
+		/// Too few jurors could be drawn for a lawsuit at `MaxDrawAttempts` blocks in a row, the
+		/// draw waits for `retry_draw`.
+		JuryDrawAbandoned {
+			lawsuit_id: u32,
+		},
+		/// An account bonded stake to become a juror.
+		JurorJoined {
+			juror: T::AccountId,
+			bond: BalanceOf<T, I>,
+		},
+		/// A juror left, their bond and the stake delegated to them is unbonding.
+		JurorLeft {
+			juror: T::AccountId,
+		},
+		/// A juror changed the commission kept from rewards.
+		CommissionSet {
+			juror: T::AccountId,
+			commission: Perbill,
+		},
+		/// Stake has been delegated to a juror.
+		Delegated {
+			delegator: T::AccountId,
+			juror: T::AccountId,
+			amount: BalanceOf<T, I>,
+		},
+		/// Delegated stake has started unbonding.
+		Undelegated {
+			delegator: T::AccountId,
+			juror: T::AccountId,
+			amount: BalanceOf<T, I>,
+		},
+		/// Unbonded stake has been unreserved.
+		Withdrawn {
+			who: T::AccountId,
+			amount: BalanceOf<T, I>,
+		},
+		/// Jurors have been drawn for a lawsuit.
+		JuryDrawn {
+			lawsuit_id: u32,
+			jurors: Vec<T::AccountId>,
+		},
+		/// A juror voted against the ruling, their stake and delegations have been slashed.
+		JurorSlashed {
+			lawsuit_id: u32,
+			juror: T::AccountId,
+			amount: BalanceOf<T, I>,
+		},
+		/// A juror voted with the ruling and has been rewarded, delegators included.
+		JurorRewarded {
+			lawsuit_id: u32,
+			juror: T::AccountId,
+			amount: BalanceOf<T, I>,
+		},
+		/// The reputation of a juror has been updated after a ruling.
+		ReputationUpdated {
+			juror: T::AccountId,
+			score: u32,
+		},
+		/// A sub-court has been created.
+		SubCourtCreated {
+			sub_court: SubCourtIndex,
+			min_reputation: u32,
+		},
+		/// The reputation required by a sub-court has been changed.
+		MinReputationSet {
+			sub_court: SubCourtIndex,
+			min_reputation: u32,
+		},
+		/// A fee has been paid to the treasury.
+		FeePaid {
+			lawsuit_id: u32,
+			payer: T::AccountId,
+			amount: BalanceOf<T, I>,
+		},
+		/// The losing party appealed a ruling, a new jury has to rule on the lawsuit.
+		Appealed {
+			lawsuit_id: u32,
+			appellant: T::AccountId,
+			round: u32,
+		},
+		/// The ruling on a lawsuit is final and has been executed.
+		RulingExecuted {
+			lawsuit_id: u32,
+			approve: bool,
+		},
+		/// An account has been exempted from, or made subject to, the limits on filing lawsuits.
+		ExemptionSet {
+			who: T::AccountId,
+			exempt: bool,
+		},
+		/// A mediator has been approved.
+		MediatorAdded {
+			mediator: T::AccountId,
+		},
+		/// A mediator has been removed.
+		MediatorRemoved {
+			mediator: T::AccountId,
+		},
+		/// A mediator has been assigned to a lawsuit.
+		MediationStarted {
+			lawsuit_id: u32,
+			mediator: T::AccountId,
+		},
+		/// The mediator proposed a resolution.
+		ResolutionProposed {
+			lawsuit_id: u32,
+			award: BalanceOf<T, I>,
+			terms: H256,
+		},
+		/// A party accepted the proposed resolution.
+		ResolutionAccepted {
+			lawsuit_id: u32,
+			party: T::AccountId,
+		},
+		/// Both parties accepted the resolution, the lawsuit is closed as mediated.
+		Mediated {
+			lawsuit_id: u32,
+			award: BalanceOf<T, I>,
+		},
+		/// The mediator of a settled lawsuit was paid `fee`, the fee is refunded to the
+		/// plaintiff if it cannot be paid.
+		MediatorPaid {
+			lawsuit_id: u32,
+			mediator: T::AccountId,
+			fee: BalanceOf<T, I>,
+		},
+		/// The parties did not settle, the lawsuit goes to a jury.
+		MediationFailed {
+			lawsuit_id: u32,
+		},
+		/// A lawsuit has been pruned, only its summary is kept.
+		LawsuitArchived {
+			lawsuit_id: u32,
+			deposit: BalanceOf<T, I>,
+		},
+		/// The plaintiff attached a remedy to a lawsuit.
+		RemedyAttached {
+			lawsuit_id: u32,
+		},
+		/// The remedy of an approved lawsuit has been dispatched.
+		RemedyExecuted {
+			lawsuit_id: u32,
+			result: DispatchResult,
+		},
+		/// Jurors who did not vote by the end of the voting period have been replaced, or removed
+		/// without replacements once the redraws are used up.
+		JurorsReplaced {
+			lawsuit_id: u32,
+			absent: Vec<T::AccountId>,
+			replacements: Vec<T::AccountId>,
+		},
+		/// A juror missed too many voting periods and is not drawn until `until`.
+		JurorSuspended {
+			juror: T::AccountId,
+			until: T::BlockNumber,
+		},
+		/// The value of a claim in the reference currency has been fixed at the current price.
+		ValueFixed {
+			lawsuit_id: u32,
+			claim: BalanceOf<T, I>,
+			value: BalanceOf<T, I>,
+		},
+		/// The award of a reversed ruling has been clawed back from the plaintiff, `shortfall` is
+		/// the part the plaintiff could not pay.
+		AwardClawedBack {
+			lawsuit_id: u32,
+			amount: BalanceOf<T, I>,
+			shortfall: BalanceOf<T, I>,
+		},
+		/// The unpaid award of a reversed ruling has been removed from the payout queue.
+		AwardCancelled {
+			lawsuit_id: u32,
+		},
+		/// An executed ruling has been reversed by governance.
+		RulingReversed {
+			lawsuit_id: u32,
+			reason: H256,
+		},
+		/// The defendant filed a counterclaim.
+		CounterclaimFiled {
+			lawsuit_id: u32,
+			value: BalanceOf<T, I>,
+		},
+		/// A vote has been cast on a counterclaim.
+		CounterclaimVoteSubmitted {
+			lawsuit_id: u32,
+			voter: T::AccountId,
+			approve: bool,
+		},
+		/// A counterclaim has been decided together with its lawsuit.
+		CounterclaimClosed {
+			lawsuit_id: u32,
+			approve: bool,
+		},
+		/// The insurance pool covered the part of an award the payer could not pay, and took
+		/// over the debt of the payer.
+		AwardInsured {
+			lawsuit_id: u32,
+			debtor: T::AccountId,
+			covered: BalanceOf<T, I>,
+		},
+		/// A debtor repaid part of their debt to the insurance pool.
+		InsuranceRepaid {
+			debtor: T::AccountId,
+			amount: BalanceOf<T, I>,
+		},
+		/// A contribution to the appeal fund of one side of a lawsuit has been reserved.
+		AppealFunded {
+			lawsuit_id: u32,
+			round: u32,
+			approve: bool,
+			contributor: T::AccountId,
+			amount: BalanceOf<T, I>,
+		},
+		/// An appeal fund was not filled in time, the contributions have been released.
+		AppealFundFailed {
+			lawsuit_id: u32,
+			round: u32,
+			approve: bool,
+		},
+		/// A contributor to the winning side received a share of the losing side's funds.
+		AppealFundRewarded {
+			lawsuit_id: u32,
+			contributor: T::AccountId,
+			amount: BalanceOf<T, I>,
+		},
+		/// The appeal fee paid by a contributor to the winning side has been returned from the
+		/// treasury.
+		AppealFeeRefunded {
+			lawsuit_id: u32,
+			contributor: T::AccountId,
+			amount: BalanceOf<T, I>,
+		},
+		/// A defendant offered an arbitration agreement to a plaintiff.
+		AgreementOffered {
+			defendent: T::AccountId,
+			plaintiff: T::AccountId,
+		},
+		/// The plaintiff signed an arbitration agreement, the agreement is live.
+		AgreementSigned {
+			defendent: T::AccountId,
+			plaintiff: T::AccountId,
+		},
+		/// One of the parties revoked an arbitration agreement.
+		AgreementRevoked {
+			defendent: T::AccountId,
+			plaintiff: T::AccountId,
+		},
+		/// An account opted in or out of requiring an agreement to be sued.
+		AgreementRequirementSet {
+			who: T::AccountId,
+			required: bool,
+		},
+		/// The plaintiff amended the statement of a lawsuit.
+		StatementAmended {
+			lawsuit_id: u32,
+			statement: H256,
+		},
+		/// A juror has been accused of misconduct in a lawsuit.
+		MisconductReported {
+			case_id: u32,
+			lawsuit_id: u32,
+			juror: T::AccountId,
+			reporter: T::AccountId,
+		},
+		/// The misconduct court ruled on an accused juror.
+		MisconductRuled {
+			case_id: u32,
+			juror: T::AccountId,
+			guilty: bool,
+		},
+		/// A misconduct case has been dismissed as `MisconductCourt` did not rule on it in time.
+		MisconductDismissed {
+			case_id: u32,
+			juror: T::AccountId,
+		},
+		/// A juror found guilty of misconduct has been banned from the pool.
+		JurorBanned {
+			juror: T::AccountId,
+		},
+		/// A new jury has been drawn to rule on a lawsuit again after misconduct.
+		LawsuitReopened {
+			lawsuit_id: u32,
+		},
+		/// The appeal of a lawsuit has been filed as a case in the higher court.
+		AppealEscalated {
+			lawsuit_id: u32,
+			case_id: u32,
+		},
+		/// The higher court did not rule on an escalated appeal in time, a new jury of the court
+		/// rules on it instead.
+		EscalationExpired {
+			lawsuit_id: u32,
+			case_id: u32,
+		},
+		/// The ruling of the higher court on an escalated appeal replaced the ruling on the
+		/// lawsuit.
+		RulingOverridden {
+			lawsuit_id: u32,
+			approve: bool,
+		},
+		/// A juror attached a justification to a vote.
+		VoteJustified {
+			lawsuit_id: u32,
+			juror: T::AccountId,
+			document: H256,
+		},
+		/// The plaintiff withdrew a lawsuit before it was ruled on.
+		LawsuitWithdrawn {
+			lawsuit_id: u32,
+		},
+	}
+
+	/// Error for the treasury pallet.
+	#[pallet::error]
+	pub enum Error<T, I = ()> {
+		/// Proposer's balance is too low.
+		InsufficientProposersBalance,
+		/// No proposal or bounty at that index.
+		InvalidIndex,
+		/// Too many approvals in the queue.
+		TooManyApprovals,
+		/// The spend origin is valid but the amount it is allowed to spend is lower than the
+		/// amount to be spent.
+		InsufficientPermission,
+		/// Proposal has not been approved.
+		ProposalNotApproved,
+		ProposalAlreadyApproved,
+		ProposalNotFound,
+		LawsuitNotFound,
+		DuplicateVote,
+		StatementOverSize,
+		VoterCountTooLow,
+		/// The account is already a juror.
+		AlreadyJuror,
+		/// The pool already has `MaxJurors` jurors.
+		TooManyJurors,
+		/// The jury draw of the lawsuit has not been abandoned.
+		DrawNotAbandoned,
+		/// The account is not a juror.
+		NotJuror,
+		/// The bond is lower than `MinJurorBond`.
+		BondTooLow,
+		/// There are not enough jurors to draw a jury.
+		NotEnoughJurors,
+		/// The voter has not been drawn into the jury of the lawsuit.
+		NotDrawn,
+		/// The delegation is lower than the amount to undelegate.
+		InsufficientDelegation,
+		/// There is no unbonded stake to withdraw.
+		NothingToWithdraw,
+		/// A ruling has already been made on the lawsuit.
+		LawsuitClosed,
+		/// The preimage of the statement has not been noted.
+		StatementNotNoted,
+		/// No sub-court at that index.
+		InvalidSubCourt,
+		/// No ruling has been made on the lawsuit yet.
+		NotRuled,
+		/// Only the party that lost the ruling can appeal.
+		NotLosingParty,
+		/// The appeal period of the ruling is over.
+		AppealPeriodOver,
+		/// The appeal period of the ruling is not over yet.
+		AppealPeriodNotOver,
+		/// The lawsuit cannot be appealed any further.
+		TooManyAppeals,
+		/// The ruling on the lawsuit has already been executed.
+		RulingExecuted,
+		/// The asset does not exist.
+		UnknownAsset,
+		/// The plaintiff has reached `MaxOpenSuits`.
+		TooManyOpenSuits,
+		/// The plaintiff has reached `MaxOpenSuitsPerDefendant` against the defendant.
+		TooManySuitsAgainstDefendant,
+		/// The plaintiff cannot file until the cooldown after a rejected lawsuit is over.
+		InRejectionCooldown,
+		/// The disputed period ends before it starts.
+		InvalidMetadata,
+		/// The account is already a mediator.
+		AlreadyMediator,
+		/// The account is not a mediator.
+		NotMediator,
+		/// There is no mediator that is not a party to the lawsuit.
+		NoMediator,
+		/// The lawsuit is not being mediated.
+		NotInMediation,
+		/// The lawsuit is being mediated, no jury has been drawn yet.
+		InMediation,
+		/// The mediation period is over.
+		MediationPeriodOver,
+		/// The mediation period is not over yet.
+		MediationPeriodNotOver,
+		/// The mediator has not proposed a resolution.
+		NoResolution,
+		/// Only the parties to the lawsuit can accept a resolution.
+		NotParty,
+		/// Only the plaintiff can attach a remedy.
+		NotPlaintiff,
+		/// Votes have been cast on the lawsuit.
+		VotingStarted,
+		/// The statement has been amended `MaxAmendments` times.
+		TooManyAmendments,
+		/// A party has accepted the resolution proposed by the mediator.
+		ResolutionAccepted,
+		/// The call is not allowed by `RemedyFilter`.
+		RemedyNotAllowed,
+		/// The encoded remedy is longer than `MaxRemedyLength`.
+		RemedyTooLong,
+		/// The weight of the remedy is above `MaxRemedyWeight`.
+		RemedyTooHeavy,
+		/// The voting period of the lawsuit is not over yet, or there is none.
+		VotingPeriodNotOver,
+		/// All drawn jurors have voted.
+		NoAbsentees,
+		/// There is no price for the asset of the claim.
+		NoPrice,
+		/// Only executed approvals of a lawsuit or counterclaim that have not been reversed yet
+		/// can be reversed.
+		NotReversible,
+		/// The caller is not the defendant of the lawsuit.
+		NotDefendant,
+		/// A counterclaim has already been filed.
+		CounterclaimExists,
+		/// No counterclaim has been filed.
+		NoCounterclaim,
+		/// The caller owes nothing to the insurance pool.
+		NoInsuranceDebt,
+		/// The shortfall of an award is above `InsuranceCap` or the funds of the pool.
+		InsuranceUnavailable,
+		/// The appeal fund of this side has already been filled.
+		FundFilled,
+		/// The defendant requires a live arbitration agreement with the plaintiff.
+		NoAgreement,
+		/// The terms differ from the terms of the offered agreement.
+		TermsMismatch,
+		/// The value of the lawsuit is above the maximum claim of the agreement.
+		ClaimAboveAgreement,
+		/// The agreement has expired.
+		AgreementExpired,
+		/// The account has been banned from the pool for misconduct.
+		JurorBanned,
+		/// The lawsuit may be reopened by a pending misconduct case.
+		MisconductPending,
+		/// The juror is accused in an open misconduct case.
+		JurorAccused,
+		/// There is no open misconduct case at that index.
+		NoMisconductCase,
+		/// The misconduct case can still be ruled on.
+		MisconductNotExpired,
+		/// There is no escalated appeal at that index of `HigherCourt`.
+		NoEscalation,
+		/// The higher court can still rule on the escalated appeal.
+		EscalationNotExpired,
+		/// The summary of the justification is longer than `MaxSummaryLength`.
+		SummaryTooLong,
+		/// The award is already waiting to be paid.
+		AwardQueued,
+		/// The jury of the lawsuit has not been drawn yet.
+		DrawPending,
+		/// The juror sits on a jury that has not been settled.
+		JurorDrawn,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config<I>, I: 'static> Hooks<BlockNumberFor<T>> for Pallet<T, I> {
+		/// Draw the juries committed to at the previous block.
+		fn on_initialize(n: T::BlockNumber) -> Weight {
+			let queued = DrawQueue::<T, I>::take(n);
+			let weight = Self::draw_weight(queued.len() as u32);
+			for lawsuit_id in queued {
+				Self::complete_draw(lawsuit_id, n);
+			}
+			weight
+		}
+
+		/// Pay out as many queued awards as the remaining block weight allows, then archive
+		/// lawsuits past their retention period.
+		fn on_idle(n: T::BlockNumber, remaining_weight: Weight) -> Weight {
+			let used = Self::pay_awards(remaining_weight);
+			used.saturating_add(Self::archive_lawsuits(n, remaining_weight.saturating_sub(used)))
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config<I>, I: 'static> Pallet<T, I> {
+		/// Submit a lawsuit
+		///
+		/// - `statement`: The hash of the statement. Its preimage has to be noted beforehand, the
+		///   preimage is requested until the lawsuit is closed.
+		/// - `sub_court`: The sub-court to judge the lawsuit, `0` for the general court.
+		/// - `asset`: The asset `value` is denominated in, `None` for the native currency.
+		/// - `metadata`: What the dispute is about.
+		/// - `mediate`: Whether a mediator should try to settle the dispute before a jury is
+		///   drawn. `MediationFee` is reserved from the plaintiff.
+		/// - `fiat`: Whether `value` is denominated in the reference currency of `T::Prices`. The
+		///   amount of `asset` is then fixed at the price when a ruling is made.
+		///
+		/// The plaintiff pays `FilingFee` to the treasury, and `CaseDeposit` is reserved until the
+		/// lawsuit is archived.
+		///
+		/// A defendant who requires an arbitration agreement can only be sued under a live
+		/// agreement. The sub-court and the split of the filing fee of a live agreement override
+		/// `sub_court` and the default fee, and `value` is capped by its maximum claim.
+		///
+		/// ## Complexity
+		/// - O(1)
+		#[pallet::call_index(0)]
+		#[pallet::weight(
+			T::OnLawsuitFiled::max_weight().saturating_add(Pallet::<T, I>::scan_weight())
+		)]
+		pub fn submit_sue(
+			origin: OriginFor<T>,
+			#[pallet::compact] value: BalanceOf<T, I>,
+			defendent: AccountIdLookupOf<T>,
+			statement: H256,
+			#[pallet::compact] sub_court: SubCourtIndex,
+			asset: Option<T::AssetId>,
+			metadata: Option<CaseMetadataOf<T, I>>,
+			mediate: bool,
+			fiat: bool,
+		) -> DispatchResultWithPostInfo {
+			let proposer = ensure_signed(origin)?;
+			let defendent = T::Lookup::lookup(defendent)?;
+			let terms = Self::agreement_terms(&defendent, &proposer)?;
+			let (_, weight) = Self::do_submit_sue(
+				proposer, value, defendent, statement, sub_court, asset, metadata, mediate, fiat,
+				terms, None,
+			)?;
+			Ok(Some(weight).into())
+		}
+
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::OnVoteCast::max_weight())]
+		pub fn vote_sue(
+			origin: OriginFor<T>,
+			lawsuit_id: u32,
+			approve: bool,
+		) -> DispatchResultWithPostInfo {
+			let voter = ensure_signed(origin)?;
+
+			let mut lawsuit =
+				<Proposals<T, I>>::get(lawsuit_id).ok_or(Error::<T, I>::LawsuitNotFound)?;
+			<Proposals<T, I>>::remove(lawsuit_id);
+
+			ensure!(!lawsuit.closed, Error::<T, I>::LawsuitClosed);
+			ensure!(
+				lawsuit.mediation.as_ref().map_or(true, |m| m.failed),
+				Error::<T, I>::InMediation
+			);
+			ensure!(!PendingDraws::<T, I>::contains_key(lawsuit_id), Error::<T, I>::DrawPending);
+			// Ensure the voter hasn't voted before
+			ensure!(!lawsuit.voters.contains(&voter), Error::<T, I>::DuplicateVote);
+			// Only drawn jurors may vote once a jury has been drawn
+			ensure!(
+				lawsuit.jurors.is_empty() || lawsuit.jurors.contains(&voter),
+				Error::<T, I>::NotDrawn
+			);
+
+			if !lawsuit.jurors.is_empty() {
+				Absences::<T, I>::remove(&voter);
+			}
+
+			// Add the voter and their vote to the lawsuit
+			lawsuit.voters.push(voter.clone());
+			lawsuit.votes.push(approve);
+			<Proposals<T, I>>::insert(lawsuit_id, lawsuit);
+
+			let weight = T::OnVoteCast::on_vote_cast(lawsuit_id, &voter, approve);
+			Self::deposit_event(Event::VoteSubmitted { lawsuit_id, voter, approve });
+			Ok(Some(weight).into())
+		}
+
+		#[pallet::call_index(2)]
+		#[pallet::weight((
+			T::OnRuled::max_weight()
+				.saturating_add(T::OnRulingExecuted::max_weight())
+				.saturating_add(T::MaxRemedyWeight::get()),
+			DispatchClass::Operational,
+		))]
+		pub fn process_sue(origin: OriginFor<T>, lawsuit_id: u32) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+			let proposal =
+				<Proposals<T, I>>::get(lawsuit_id).ok_or(Error::<T, I>::ProposalNotFound)?;
+
+			// Ensure that the proposal is not already approved
+			ensure!(!proposal.approved, Error::<T, I>::ProposalAlreadyApproved);
+			ensure!(!proposal.closed, Error::<T, I>::LawsuitClosed);
+			ensure!(!PendingDraws::<T, I>::contains_key(lawsuit_id), Error::<T, I>::DrawPending);
+			ensure!(proposal.votes.len() > 3, Error::<T, I>::VoterCountTooLow);
+			// A drawn jury has until the end of the voting period, unless every juror voted.
+			let now = frame_system::Pallet::<T>::block_number();
+			ensure!(
+				proposal.voting_ends.map_or(true, |end| now > end) ||
+					proposal.jurors.iter().all(|juror| proposal.voters.contains(juror)),
+				Error::<T, I>::VotingPeriodNotOver
+			);
+
+			let weight = Self::rule(lawsuit_id, proposal)?;
+			Ok(Some(weight).into())
+		}
+
+		/// Force a previously approved lawsuit to be removed from the approval queue.
+		///
+		/// The lawsuit stays approved and paid awards are not clawed back, use `reverse_ruling`
+		/// to undo a ruling. The award can be queued again with `retry_award`.
+		///
+		/// May only be called from `T::CourtAdminOrigin`.
+		///
+		/// - `lawsuit_id`: The index of a lawsuit
+		///
+		/// ## Complexity
+		/// - O(A) where `A` is the number of approvals, deferred ones included
+		///
+		/// Errors:
+		/// - `ProposalNotApproved`: The `lawsuit_id` supplied was not found in the approval queue,
+		/// i.e., the lawsuit has not been approved. This could also mean the lawsuit does not
+		/// exist altogether, thus there is no way it would have been approved in the first place.
+		#[pallet::call_index(3)]
+		#[pallet::weight((Weight::zero(), DispatchClass::Operational))]
+		pub fn remove_sue(
+			origin: OriginFor<T>,
+			#[pallet::compact] lawsuit_id: ProposalIndex,
+		) -> DispatchResult {
+			T::CourtAdminOrigin::ensure_origin(origin)?;
+			Approvals::<T, I>::try_mutate(|v| -> DispatchResult {
+				if let Some(index) = v.iter().position(|x| x == &lawsuit_id) {
+					v.remove(index);
+					Ok(())
+				} else {
+					Err(Error::<T, I>::ProposalNotApproved.into())
+				}
+			})
+			.or_else(|_| {
+				DeferredApprovals::<T, I>::try_mutate(|v| -> DispatchResult {
+					if let Some(index) = v.iter().position(|x| x == &lawsuit_id) {
+						v.remove(index);
+						Ok(())
+					} else {
+						Err(Error::<T, I>::ProposalNotApproved.into())
+					}
+				})
+			})?;
+
+			Ok(())
+		}
+
+		/// Bond stake to become a juror that can be drawn into juries, while the pool has fewer
+		/// than `MaxJurors` jurors.
+		///
+		/// - `bond`: The stake to reserve, at least `MinJurorBond`.
+		/// - `commission`: The share of rewards kept before the rest is shared with delegators.
+		#[pallet::call_index(4)]
+		#[pallet::weight(Weight::zero())]
+		pub fn join_jury(
+			origin: OriginFor<T>,
+			#[pallet::compact] bond: BalanceOf<T, I>,
+			commission: Perbill,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(!Jurors::<T, I>::contains_key(&who), Error::<T, I>::AlreadyJuror);
+			ensure!(!Self::banned(&who), Error::<T, I>::JurorBanned);
+			ensure!(bond >= T::MinJurorBond::get(), Error::<T, I>::BondTooLow);
+			ensure!(Self::juror_count() < T::MaxJurors::get(), Error::<T, I>::TooManyJurors);
+
+			T::Currency::reserve(&who, bond)?;
+			Jurors::<T, I>::insert(&who, Juror { bond, delegated: Zero::zero(), commission });
+			JurorCount::<T, I>::mutate(|n| *n = n.saturating_add(1));
+			if !Reputations::<T, I>::contains_key(&who) {
+				let updated_at = <frame_system::Pallet<T>>::block_number();
+				let score = T::InitialReputation::get();
+				Reputations::<T, I>::insert(&who, Reputation { score, updated_at });
+			}
+
+			Self::deposit_event(Event::JurorJoined { juror: who, bond });
+			Ok(())
+		}
+
+		/// Change the share of rewards kept by the juror.
+		#[pallet::call_index(5)]
+		#[pallet::weight(Weight::zero())]
+		pub fn set_commission(origin: OriginFor<T>, commission: Perbill) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Jurors::<T, I>::try_mutate(&who, |juror| -> DispatchResult {
+				let juror = juror.as_mut().ok_or(Error::<T, I>::NotJuror)?;
+				juror.commission = commission;
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::CommissionSet { juror: who, commission });
+			Ok(())
+		}
+
+		/// Stop being drawn into juries.
+		///
+		/// The bond and all the stake delegated to the juror start unbonding. A juror accused of
+		/// misconduct cannot leave until the case is closed.
+		///
 		/// ## Complexity
-		/// - O(A) where `A` is the number of approvals
+		/// - O(D) where `D` is the number of delegators of the juror
+		#[pallet::call_index(6)]
+		#[pallet::weight(Weight::zero())]
+		pub fn leave_jury(origin: OriginFor<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(Self::drawn_into(&who).is_zero(), Error::<T, I>::JurorDrawn);
+			ensure!(Self::accusations(&who).is_zero(), Error::<T, I>::JurorAccused);
+			let juror = Jurors::<T, I>::take(&who).ok_or(Error::<T, I>::NotJuror)?;
+			JurorCount::<T, I>::mutate(|n| *n = n.saturating_sub(1));
+
+			Self::unbond(&who, juror.bond);
+			for (delegator, amount) in Delegations::<T, I>::drain_prefix(&who) {
+				Self::unbond(&delegator, amount);
+			}
+
+			Self::deposit_event(Event::JurorLeft { juror: who });
+			Ok(())
+		}
+
+		/// Delegate stake to a juror, adding to the juror's weight in jury selection.
+		///
+		/// Rewards and slashes of the juror are shared with the delegator, after the commission
+		/// of the juror.
+		#[pallet::call_index(7)]
+		#[pallet::weight(Weight::zero())]
+		pub fn delegate(
+			origin: OriginFor<T>,
+			juror: AccountIdLookupOf<T>,
+			#[pallet::compact] amount: BalanceOf<T, I>,
+		) -> DispatchResult {
+			let delegator = ensure_signed(origin)?;
+			let juror = T::Lookup::lookup(juror)?;
+
+			Jurors::<T, I>::try_mutate(&juror, |profile| -> DispatchResult {
+				let profile = profile.as_mut().ok_or(Error::<T, I>::NotJuror)?;
+				T::Currency::reserve(&delegator, amount)?;
+				profile.delegated = profile.delegated.saturating_add(amount);
+				Ok(())
+			})?;
+			Delegations::<T, I>::mutate(&juror, &delegator, |v| *v = v.saturating_add(amount));
+
+			Self::deposit_event(Event::Delegated { delegator, juror, amount });
+			Ok(())
+		}
+
+		/// Withdraw delegated stake from a juror, it is unreserved after `UnbondingPeriod`. Stake
+		/// delegated to a juror accused of misconduct cannot be withdrawn until the case is closed.
+		#[pallet::call_index(8)]
+		#[pallet::weight(Weight::zero())]
+		pub fn undelegate(
+			origin: OriginFor<T>,
+			juror: AccountIdLookupOf<T>,
+			#[pallet::compact] amount: BalanceOf<T, I>,
+		) -> DispatchResult {
+			let delegator = ensure_signed(origin)?;
+			let juror = T::Lookup::lookup(juror)?;
+			ensure!(Self::drawn_into(&juror).is_zero(), Error::<T, I>::JurorDrawn);
+			ensure!(Self::accusations(&juror).is_zero(), Error::<T, I>::JurorAccused);
+
+			Delegations::<T, I>::try_mutate_exists(&juror, &delegator, |v| -> DispatchResult {
+				let delegated = v.as_mut().ok_or(Error::<T, I>::InsufficientDelegation)?;
+				ensure!(*delegated >= amount, Error::<T, I>::InsufficientDelegation);
+				*delegated = delegated.saturating_sub(amount);
+				if delegated.is_zero() {
+					*v = None;
+				}
+				Ok(())
+			})?;
+			Jurors::<T, I>::mutate(&juror, |profile| {
+				if let Some(profile) = profile {
+					profile.delegated = profile.delegated.saturating_sub(amount);
+				}
+			});
+			Self::unbond(&delegator, amount);
+
+			Self::deposit_event(Event::Undelegated { delegator, juror, amount });
+			Ok(())
+		}
+
+		/// Unreserve all the stake whose unbonding period has passed.
+		#[pallet::call_index(9)]
+		#[pallet::weight(Weight::zero())]
+		pub fn withdraw_unbonded(origin: OriginFor<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let now = <frame_system::Pallet<T>>::block_number();
+
+			let mut amount = BalanceOf::<T, I>::zero();
+			Unbonding::<T, I>::mutate_exists(&who, |chunks| {
+				let mut remaining = chunks.take().unwrap_or_default();
+				remaining.retain(|chunk| {
+					if chunk.unlock_at <= now {
+						amount = amount.saturating_add(chunk.value);
+						false
+					} else {
+						true
+					}
+				});
+				if !remaining.is_empty() {
+					*chunks = Some(remaining);
+				}
+			});
+			ensure!(!amount.is_zero(), Error::<T, I>::NothingToWithdraw);
+
+			T::Currency::unreserve(&who, amount);
+			Self::deposit_event(Event::Withdrawn { who, amount });
+			Ok(())
+		}
+
+		/// Create a sub-court whose jurors need at least `min_reputation`.
+		///
+		/// May only be called from `T::CourtAdminOrigin`.
+		#[pallet::call_index(10)]
+		#[pallet::weight(Weight::zero())]
+		pub fn create_sub_court(
+			origin: OriginFor<T>,
+			#[pallet::compact] min_reputation: u32,
+		) -> DispatchResult {
+			T::CourtAdminOrigin::ensure_origin(origin)?;
+
+			let sub_court = Self::sub_court_count() + 1;
+			<SubCourtCount<T, I>>::put(sub_court);
+			SubCourts::<T, I>::insert(sub_court, SubCourt { min_reputation });
+
+			Self::deposit_event(Event::SubCourtCreated { sub_court, min_reputation });
+			Ok(())
+		}
+
+		/// Change the reputation jurors need to be drawn into juries of a sub-court.
+		///
+		/// May only be called from `T::CourtAdminOrigin`.
+		#[pallet::call_index(11)]
+		#[pallet::weight(Weight::zero())]
+		pub fn set_min_reputation(
+			origin: OriginFor<T>,
+			#[pallet::compact] sub_court: SubCourtIndex,
+			#[pallet::compact] min_reputation: u32,
+		) -> DispatchResult {
+			T::CourtAdminOrigin::ensure_origin(origin)?;
+			ensure!(sub_court <= Self::sub_court_count(), Error::<T, I>::InvalidSubCourt);
+
+			SubCourts::<T, I>::mutate(sub_court, |c| c.min_reputation = min_reputation);
+
+			Self::deposit_event(Event::MinReputationSet { sub_court, min_reputation });
+			Ok(())
+		}
+
+		/// Appeal the ruling on a lawsuit within `AppealPeriod` of the ruling.
+		///
+		/// Only the losing party can appeal. The appellant pays `AppealFee`, doubled for every
+		/// previous appeal, to the treasury and a new jury is drawn to rule on the lawsuit. The
+		/// appeal can also be crowdfunded with `fund_appeal`.
+		///
+		/// With a `HigherCourt`, the appeal is filed there by the losing party instead, and the
+		/// ruling of the higher court overrides the ruling on the lawsuit. If the higher court
+		/// does not rule within `EscalationTimeout`, see `expire_escalation`.
+		///
+		/// - `lawsuit_id`: The index of a lawsuit
+		#[pallet::call_index(12)]
+		#[pallet::weight(
+			T::OnAppealed::max_weight()
+				.saturating_add(Pallet::<T, I>::scan_weight())
+				.saturating_add(T::HigherCourt::file_weight())
+		)]
+		pub fn appeal(
+			origin: OriginFor<T>,
+			#[pallet::compact] lawsuit_id: ProposalIndex,
+		) -> DispatchResultWithPostInfo {
+			let appellant = ensure_signed(origin)?;
+			let lawsuit =
+				<Proposals<T, I>>::get(lawsuit_id).ok_or(Error::<T, I>::LawsuitNotFound)?;
+			Self::ensure_appealable(&lawsuit)?;
+			let loser = if lawsuit.approved { &lawsuit.defendent } else { &lawsuit.plaintiff };
+			ensure!(&appellant == loser, Error::<T, I>::NotLosingParty);
+
+			Self::pay_fee(lawsuit_id, &appellant, Self::appeal_fee(lawsuit.round))?;
+			let weight = Self::start_appeal(lawsuit_id, lawsuit, appellant, false)?;
+			Ok(Some(weight).into())
+		}
+
+		/// Execute the ruling on a lawsuit once its appeal period is over.
+		///
+		/// - `lawsuit_id`: The index of a lawsuit
+		#[pallet::call_index(13)]
+		#[pallet::weight(
+			T::OnRulingExecuted::max_weight().saturating_add(T::MaxRemedyWeight::get())
+		)]
+		pub fn execute_ruling(
+			origin: OriginFor<T>,
+			#[pallet::compact] lawsuit_id: ProposalIndex,
+		) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+			let lawsuit =
+				<Proposals<T, I>>::get(lawsuit_id).ok_or(Error::<T, I>::LawsuitNotFound)?;
+			ensure!(!lawsuit.executed, Error::<T, I>::RulingExecuted);
+			let ruled_at = lawsuit.ruled_at.ok_or(Error::<T, I>::NotRuled)?;
+			ensure!(
+				frame_system::Pallet::<T>::block_number() >
+					ruled_at.saturating_add(T::AppealPeriod::get()),
+				Error::<T, I>::AppealPeriodNotOver
+			);
+			ensure!(Self::pending_reopens(lawsuit_id).is_zero(), Error::<T, I>::MisconductPending);
+
+			Ok(Some(Self::execute(lawsuit_id, lawsuit)).into())
+		}
+
+		/// Exempt an account from the limits on filing lawsuits, or make it subject to them
+		/// again.
+		///
+		/// May only be called from `T::CourtAdminOrigin`.
+		#[pallet::call_index(14)]
+		#[pallet::weight(Weight::zero())]
+		pub fn set_exemption(
+			origin: OriginFor<T>,
+			who: AccountIdLookupOf<T>,
+			exempt: bool,
+		) -> DispatchResult {
+			T::CourtAdminOrigin::ensure_origin(origin)?;
+			let who = T::Lookup::lookup(who)?;
+
+			if exempt {
+				Exempt::<T, I>::insert(&who, true);
+			} else {
+				Exempt::<T, I>::remove(&who);
+			}
+
+			Self::deposit_event(Event::ExemptionSet { who, exempt });
+			Ok(())
+		}
+
+		/// Approve a mediator.
+		///
+		/// May only be called from `T::CourtAdminOrigin`.
+		#[pallet::call_index(15)]
+		#[pallet::weight(Weight::zero())]
+		pub fn add_mediator(origin: OriginFor<T>, who: AccountIdLookupOf<T>) -> DispatchResult {
+			T::CourtAdminOrigin::ensure_origin(origin)?;
+			let mediator = T::Lookup::lookup(who)?;
+			Mediators::<T, I>::try_mutate(|mediators| -> DispatchResult {
+				ensure!(!mediators.contains(&mediator), Error::<T, I>::AlreadyMediator);
+				mediators.push(mediator.clone());
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::MediatorAdded { mediator });
+			Ok(())
+		}
+
+		/// Remove a mediator. Ongoing mediations are not affected.
+		///
+		/// May only be called from `T::CourtAdminOrigin`.
+		#[pallet::call_index(16)]
+		#[pallet::weight(Weight::zero())]
+		pub fn remove_mediator(origin: OriginFor<T>, who: AccountIdLookupOf<T>) -> DispatchResult {
+			T::CourtAdminOrigin::ensure_origin(origin)?;
+			let mediator = T::Lookup::lookup(who)?;
+			Mediators::<T, I>::try_mutate(|mediators| -> DispatchResult {
+				let index = mediators
+					.iter()
+					.position(|m| m == &mediator)
+					.ok_or(Error::<T, I>::NotMediator)?;
+				mediators.remove(index);
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::MediatorRemoved { mediator });
+			Ok(())
+		}
+
+		/// Propose a resolution for a lawsuit in mediation, replacing any previous proposal.
+		///
+		/// May only be called by the mediator of the lawsuit, within the mediation period.
+		///
+		/// - `award`: The amount the defendant pays to the plaintiff.
+		/// - `terms`: The hash of the terms of the resolution.
+		#[pallet::call_index(17)]
+		#[pallet::weight(Weight::zero())]
+		pub fn propose_resolution(
+			origin: OriginFor<T>,
+			#[pallet::compact] lawsuit_id: ProposalIndex,
+			#[pallet::compact] award: BalanceOf<T, I>,
+			terms: H256,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let mut lawsuit =
+				<Proposals<T, I>>::get(lawsuit_id).ok_or(Error::<T, I>::LawsuitNotFound)?;
+			let mediation = Self::ongoing_mediation(&mut lawsuit)?;
+			ensure!(who == mediation.mediator, Error::<T, I>::NotMediator);
+			ensure!(
+				frame_system::Pallet::<T>::block_number() <= mediation.deadline,
+				Error::<T, I>::MediationPeriodOver
+			);
+
+			mediation.proposal = Some(Resolution { award, terms });
+			mediation.accepted_by = vec![];
+			<Proposals<T, I>>::insert(lawsuit_id, lawsuit);
+
+			Self::deposit_event(Event::ResolutionProposed { lawsuit_id, award, terms });
+			Ok(())
+		}
+
+		/// Accept the resolution proposed by the mediator, within the mediation period.
+		///
+		/// Once both parties accepted, the lawsuit is closed as mediated: the award is queued for
+		/// payout and the mediator is paid the mediation fee.
+		#[pallet::call_index(18)]
+		#[pallet::weight(
+			T::OnRulingExecuted::max_weight().saturating_add(T::MaxRemedyWeight::get())
+		)]
+		pub fn accept_resolution(
+			origin: OriginFor<T>,
+			#[pallet::compact] lawsuit_id: ProposalIndex,
+		) -> DispatchResultWithPostInfo {
+			let party = ensure_signed(origin)?;
+			let mut lawsuit =
+				<Proposals<T, I>>::get(lawsuit_id).ok_or(Error::<T, I>::LawsuitNotFound)?;
+			ensure!(
+				party == lawsuit.plaintiff || party == lawsuit.defendent,
+				Error::<T, I>::NotParty
+			);
+			let (plaintiff, defendent) = (lawsuit.plaintiff.clone(), lawsuit.defendent.clone());
+			let mediation = Self::ongoing_mediation(&mut lawsuit)?;
+			ensure!(
+				frame_system::Pallet::<T>::block_number() <= mediation.deadline,
+				Error::<T, I>::MediationPeriodOver
+			);
+			let award = mediation.proposal.as_ref().ok_or(Error::<T, I>::NoResolution)?.award;
+			if !mediation.accepted_by.contains(&party) {
+				mediation.accepted_by.push(party.clone());
+			}
+			let settled = mediation.accepted_by.contains(&plaintiff) &&
+				mediation.accepted_by.contains(&defendent);
+			let (mediator, fee) = (mediation.mediator.clone(), mediation.fee);
+			Self::deposit_event(Event::ResolutionAccepted { lawsuit_id, party });
+			if !settled {
+				<Proposals<T, I>>::insert(lawsuit_id, lawsuit);
+				return Ok(Some(Weight::zero()).into())
+			}
+
+			let repatriated =
+				T::Currency::repatriate_reserved(&plaintiff, &mediator, fee, BalanceStatus::Free);
+			let paid = match repatriated {
+				Ok(unpaid) => fee.saturating_sub(unpaid),
+				Err(_) => {
+					T::Currency::unreserve(&plaintiff, fee);
+					Zero::zero()
+				},
+			};
+			Self::deposit_event(Event::MediatorPaid { lawsuit_id, mediator, fee: paid });
+			lawsuit.value = award;
+			lawsuit.approved = !award.is_zero();
+			lawsuit.closed = true;
+			lawsuit.ruled_at = Some(frame_system::Pallet::<T>::block_number());
+			Self::deposit_event(Event::Mediated { lawsuit_id, award });
+			Ok(Some(Self::execute(lawsuit_id, lawsuit)).into())
+		}
+
+		/// End the mediation of a lawsuit without a settlement and draw a jury.
+		///
+		/// Either party can end the mediation at any time, anyone else once the mediation period
+		/// is over. The mediation fee is refunded, the last proposed resolution is kept with the
+		/// lawsuit.
+		#[pallet::call_index(19)]
+		#[pallet::weight(Pallet::<T, I>::scan_weight())]
+		pub fn end_mediation(
+			origin: OriginFor<T>,
+			#[pallet::compact] lawsuit_id: ProposalIndex,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let mut lawsuit =
+				<Proposals<T, I>>::get(lawsuit_id).ok_or(Error::<T, I>::LawsuitNotFound)?;
+			let plaintiff = lawsuit.plaintiff.clone();
+			let is_party = who == plaintiff || who == lawsuit.defendent;
+			Self::schedule_draw(
+				lawsuit_id,
+				lawsuit.sub_court,
+				&[&lawsuit.plaintiff, &lawsuit.defendent],
+				JuryDraw { redraw: 0, count: T::JurySize::get(), replacing: vec![], attempts: 0 },
+			)?;
+			let mediation = Self::ongoing_mediation(&mut lawsuit)?;
+			ensure!(
+				is_party || frame_system::Pallet::<T>::block_number() > mediation.deadline,
+				Error::<T, I>::MediationPeriodNotOver
+			);
+
+			mediation.failed = true;
+			T::Currency::unreserve(&plaintiff, mediation.fee);
+			<Proposals<T, I>>::insert(lawsuit_id, lawsuit);
+
+			Self::deposit_event(Event::MediationFailed { lawsuit_id });
+			Ok(())
+		}
+
+		/// Attach a remedy to a lawsuit, replacing any previous one, before votes are cast and
+		/// outside of mediation.
+		///
+		/// The remedy is dispatched with the `Remedy` origin when the ruling approving the
+		/// lawsuit is executed, its weight is charged by the call executing the ruling.
+		///
+		/// - `remedy`: A call allowed by `RemedyFilter`, at most `MaxRemedyLength` long and
+		///   `MaxRemedyWeight` heavy.
+		#[pallet::call_index(20)]
+		#[pallet::weight(Weight::zero())]
+		pub fn attach_remedy(
+			origin: OriginFor<T>,
+			#[pallet::compact] lawsuit_id: ProposalIndex,
+			remedy: Box<<T as Config<I>>::RuntimeCall>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let lawsuit =
+				<Proposals<T, I>>::get(lawsuit_id).ok_or(Error::<T, I>::LawsuitNotFound)?;
+			ensure!(who == lawsuit.plaintiff, Error::<T, I>::NotPlaintiff);
+			ensure!(!lawsuit.closed, Error::<T, I>::LawsuitClosed);
+			ensure!(
+				lawsuit.mediation.as_ref().map_or(true, |m| m.failed),
+				Error::<T, I>::InMediation
+			);
+			ensure!(
+				lawsuit.round.is_zero() && lawsuit.voters.is_empty(),
+				Error::<T, I>::VotingStarted
+			);
+			ensure!(T::RemedyFilter::contains(&remedy), Error::<T, I>::RemedyNotAllowed);
+			ensure!(
+				remedy.encoded_size() as u32 <= T::MaxRemedyLength::get(),
+				Error::<T, I>::RemedyTooLong
+			);
+			ensure!(
+				remedy.get_dispatch_info().weight.all_lte(T::MaxRemedyWeight::get()),
+				Error::<T, I>::RemedyTooHeavy
+			);
+
+			Remedies::<T, I>::insert(lawsuit_id, *remedy);
+
+			Self::deposit_event(Event::RemedyAttached { lawsuit_id });
+			Ok(())
+		}
+
+		/// Replace the jurors who did not vote by the end of the voting period.
 		///
-		/// Errors:
-		/// - `ProposalNotApproved`: The `lawsuit_id` supplied was not found in the approval queue,
-		/// i.e., the lawsuit has not been approved. This could also mean the lawsuit does not
-		/// exist altogether, thus there is no way it would have been approved in the first place.
-		#[pallet::call_index(3)]
+		/// The absent jurors are slashed `NoShowSlash` and lose reputation. Up to `MaxRedraws`
+		/// times per round, as many replacements are drawn and the voting period starts over.
+		/// Once the redraws are used up, the absent jurors are released and the lawsuit is ruled
+		/// on with the votes present, it is rejected if no juror voted.
+		///
+		/// May be called by any signed origin. Fails with `NotEnoughJurors` while the sub-court
+		/// has too few jurors to replace the absent ones, the voting period is left as it is.
+		#[pallet::call_index(21)]
+		#[pallet::weight(
+			Pallet::<T, I>::scan_weight()
+				.saturating_add(T::OnRuled::max_weight())
+				.saturating_add(T::OnRulingExecuted::max_weight())
+				.saturating_add(T::MaxRemedyWeight::get())
+		)]
+		pub fn replace_absentees(
+			origin: OriginFor<T>,
+			#[pallet::compact] lawsuit_id: ProposalIndex,
+		) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+			let mut lawsuit =
+				<Proposals<T, I>>::get(lawsuit_id).ok_or(Error::<T, I>::LawsuitNotFound)?;
+			ensure!(!lawsuit.closed, Error::<T, I>::LawsuitClosed);
+			ensure!(!PendingDraws::<T, I>::contains_key(lawsuit_id), Error::<T, I>::DrawPending);
+			let now = frame_system::Pallet::<T>::block_number();
+			ensure!(
+				lawsuit.voting_ends.map_or(false, |end| now > end),
+				Error::<T, I>::VotingPeriodNotOver
+			);
+			let (absent, present): (Vec<_>, Vec<_>) =
+				lawsuit.jurors.iter().cloned().partition(|j| !lawsuit.voters.contains(j));
+			ensure!(!absent.is_empty(), Error::<T, I>::NoAbsentees);
+
+			for juror in absent.iter() {
+				Self::penalize_absentee(lawsuit_id, lawsuit.sub_court, juror, now);
+			}
+			if lawsuit.redraws < T::MaxRedraws::get() {
+				// The replacements are drawn at the next block, see `JurorsReplaced`.
+				let mut exclude = vec![&lawsuit.plaintiff, &lawsuit.defendent];
+				exclude.extend(lawsuit.jurors.iter());
+				let draw = JuryDraw {
+					redraw: lawsuit.redraws + 1,
+					count: absent.len() as u32,
+					replacing: absent.clone(),
+					attempts: 0,
+				};
+				Self::schedule_draw(lawsuit_id, lawsuit.sub_court, &exclude, draw)?;
+				Self::release_jurors(&absent);
+				lawsuit.redraws += 1;
+				lawsuit.jurors = present;
+				<Proposals<T, I>>::insert(lawsuit_id, lawsuit);
+				Ok(Some(Self::scan_weight()).into())
+			} else {
+				Self::release_jurors(&absent);
+				lawsuit.jurors = present;
+				Self::deposit_event(Event::JurorsReplaced {
+					lawsuit_id,
+					absent,
+					replacements: vec![],
+				});
+				let weight = Self::rule(lawsuit_id, lawsuit)?;
+				Ok(Some(weight).into())
+			}
+		}
+
+		/// Reverse the executed approval of a lawsuit, or of its counterclaim, until it is
+		/// archived. Both the lawsuit and the counterclaim are rejected.
+		///
+		/// A paid award, netted with any upheld counterclaim, is clawed back from the beneficiary,
+		/// starting with the holdback, and returned to the payer, the share covered by the
+		/// insurance pool to the pool. An unpaid award is removed from
+		/// the payout queue. The votes on each upheld claim are settled again against the
+		/// reversal: jurors who voted for the approval lose reputation and are slashed, the others
+		/// gain reputation and share the slashes. The voters no longer count the
+		/// lawsuit as a contribution. A dispatched remedy is not undone.
+		///
+		/// May only be called from `T::CourtAdminOrigin`.
+		///
+		/// - `reason`: The hash of the reason for the reversal.
+		#[pallet::call_index(22)]
 		#[pallet::weight((Weight::zero(), DispatchClass::Operational))]
-		pub fn remove_sue(
+		pub fn reverse_ruling(
 			origin: OriginFor<T>,
 			#[pallet::compact] lawsuit_id: ProposalIndex,
+			reason: H256,
 		) -> DispatchResult {
-			ensure_signed_or_root(origin)?;
-			Approvals::<T, I>::try_mutate(|v| -> DispatchResult {
-				if let Some(index) = v.iter().position(|x| x == &lawsuit_id) {
-					v.remove(index);
+			T::CourtAdminOrigin::ensure_origin(origin)?;
+			let mut lawsuit =
+				<Proposals<T, I>>::get(lawsuit_id).ok_or(Error::<T, I>::LawsuitNotFound)?;
+			let upheld = lawsuit.counterclaim.as_ref().map_or(false, |c| c.approved);
+			ensure!(
+				lawsuit.executed && (lawsuit.approved || upheld) && lawsuit.reversal.is_none(),
+				Error::<T, I>::NotReversible
+			);
+
+			match Self::net_award(&lawsuit) {
+				Some((_, _, award)) if lawsuit.paid => {
+					let amount = Self::claw_back(&lawsuit);
+					lawsuit.holdback = Zero::zero();
+					Self::deposit_event(Event::AwardClawedBack {
+						lawsuit_id,
+						amount,
+						shortfall: award.saturating_sub(amount),
+					});
+				},
+				Some(_) => {
+					Approvals::<T, I>::mutate(|v| v.retain(|x| x != &lawsuit_id));
+					DeferredApprovals::<T, I>::mutate(|v| v.retain(|x| x != &lawsuit_id));
+					Self::deposit_event(Event::AwardCancelled { lawsuit_id });
+				},
+				None => {},
+			}
+			if lawsuit.approved {
+				Self::settle_votes(
+					lawsuit_id,
+					lawsuit.sub_court,
+					&lawsuit.voters,
+					&lawsuit.votes,
+					false,
+				);
+			}
+			lawsuit.approved = false;
+			if let Some(counterclaim) = lawsuit.counterclaim.as_mut().filter(|c| c.approved) {
+				let (voters, votes) = (&counterclaim.voters, &counterclaim.votes);
+				Self::settle_votes(lawsuit_id, lawsuit.sub_court, voters, votes, false);
+				counterclaim.approved = false;
+			}
+			let reversed_at = frame_system::Pallet::<T>::block_number();
+			lawsuit.reversal = Some(Reversal { reason, reversed_at });
+			T::OnRulingExecuted::on_ruling_reversed(
+				lawsuit_id,
+				&lawsuit.plaintiff,
+				&lawsuit.defendent,
+			);
+			<Proposals<T, I>>::insert(lawsuit_id, lawsuit);
+
+			Self::deposit_event(Event::RulingReversed { lawsuit_id, reason });
+			Ok(())
+		}
+
+		/// File a counterclaim against the plaintiff before votes are cast on the lawsuit.
+		///
+		/// The jury of the lawsuit votes on the counterclaim in the same voting period and both
+		/// are decided together, the awards are netted so that only the difference is paid.
+		///
+		/// May only be called by the defendant.
+		///
+		/// - `value`: The amount claimed, in the same unit as the claim of the lawsuit.
+		/// - `statement`: The hash of the statement, whose preimage must have been noted.
+		#[pallet::call_index(23)]
+		#[pallet::weight(Weight::zero())]
+		pub fn file_counterclaim(
+			origin: OriginFor<T>,
+			#[pallet::compact] lawsuit_id: ProposalIndex,
+			#[pallet::compact] value: BalanceOf<T, I>,
+			statement: H256,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let mut lawsuit =
+				<Proposals<T, I>>::get(lawsuit_id).ok_or(Error::<T, I>::LawsuitNotFound)?;
+			ensure!(who == lawsuit.defendent, Error::<T, I>::NotDefendant);
+			ensure!(!lawsuit.closed, Error::<T, I>::LawsuitClosed);
+			ensure!(
+				lawsuit.round.is_zero() && lawsuit.voters.is_empty(),
+				Error::<T, I>::VotingStarted
+			);
+			ensure!(lawsuit.counterclaim.is_none(), Error::<T, I>::CounterclaimExists);
+			ensure!(T::Preimages::len(&statement).is_some(), Error::<T, I>::StatementNotNoted);
+
+			T::Preimages::request(&statement);
+			let (fixed, claim) =
+				if lawsuit.claim.is_some() { (Zero::zero(), Some(value)) } else { (value, None) };
+			lawsuit.counterclaim = Some(Counterclaim {
+				value: fixed,
+				claim,
+				statement,
+				voters: vec![],
+				votes: vec![],
+				approved: false,
+			});
+			<Proposals<T, I>>::insert(lawsuit_id, lawsuit);
+
+			Self::deposit_event(Event::CounterclaimFiled { lawsuit_id, value });
+			Ok(())
+		}
+
+		/// Vote on the counterclaim of a lawsuit, the same accounts as for the lawsuit may vote.
+		#[pallet::call_index(24)]
+		#[pallet::weight(T::OnVoteCast::max_weight())]
+		pub fn vote_counterclaim(
+			origin: OriginFor<T>,
+			#[pallet::compact] lawsuit_id: ProposalIndex,
+			approve: bool,
+		) -> DispatchResultWithPostInfo {
+			let voter = ensure_signed(origin)?;
+			let mut lawsuit =
+				<Proposals<T, I>>::get(lawsuit_id).ok_or(Error::<T, I>::LawsuitNotFound)?;
+			ensure!(!lawsuit.closed, Error::<T, I>::LawsuitClosed);
+			ensure!(
+				lawsuit.mediation.as_ref().map_or(true, |m| m.failed),
+				Error::<T, I>::InMediation
+			);
+			// Anyone could vote while the jury is yet to be drawn.
+			ensure!(!PendingDraws::<T, I>::contains_key(lawsuit_id), Error::<T, I>::DrawPending);
+			ensure!(
+				lawsuit.jurors.is_empty() || lawsuit.jurors.contains(&voter),
+				Error::<T, I>::NotDrawn
+			);
+			if !lawsuit.jurors.is_empty() {
+				Absences::<T, I>::remove(&voter);
+			}
+			let counterclaim =
+				lawsuit.counterclaim.as_mut().ok_or(Error::<T, I>::NoCounterclaim)?;
+			ensure!(!counterclaim.voters.contains(&voter), Error::<T, I>::DuplicateVote);
+
+			counterclaim.voters.push(voter.clone());
+			counterclaim.votes.push(approve);
+			<Proposals<T, I>>::insert(lawsuit_id, lawsuit);
+
+			let weight = T::OnVoteCast::on_vote_cast(lawsuit_id, &voter, approve);
+			Self::deposit_event(Event::CounterclaimVoteSubmitted { lawsuit_id, voter, approve });
+			Ok(Some(weight).into())
+		}
+
+		/// Repay the debt taken over by the insurance pool, at most the debt is repaid.
+		#[pallet::call_index(25)]
+		#[pallet::weight(Weight::zero())]
+		pub fn repay_insurance(
+			origin: OriginFor<T>,
+			#[pallet::compact] amount: BalanceOf<T, I>,
+		) -> DispatchResult {
+			let debtor = ensure_signed(origin)?;
+			let debt = Self::insurance_debts(&debtor);
+			ensure!(!debt.is_zero(), Error::<T, I>::NoInsuranceDebt);
+
+			let amount = amount.min(debt);
+			T::Currency::transfer(&debtor, &T::Treasury::insurance_account(), amount, KeepAlive)?;
+			Self::reduce_insurance_debt(&debtor, amount);
+
+			Self::deposit_event(Event::InsuranceRepaid { debtor, amount });
+			Ok(())
+		}
+
+		/// Contribute to the fund of one side for appealing the ruling on a lawsuit, within
+		/// `AppealPeriod` of the ruling.
+		///
+		/// Each side's fund has to raise the appeal fee of the round, the contribution is
+		/// reserved and capped at what is left to raise. As soon as the losing side's fund is
+		/// filled, its contributors pay the appeal fee to the treasury and the lawsuit is
+		/// appealed, a fund not filled by then is released. Once the ruling is executed, the
+		/// contributors to the winning side get back their reserved stake, or the appeal fee they
+		/// paid from the treasury, plus a share of the reserved funds of the losing side in
+		/// proportion to their contributions.
+		///
+		/// - `lawsuit_id`: The index of a lawsuit
+		/// - `approve`: Whether to back the plaintiff rather than the defendant
+		/// - `amount`: The amount to contribute
+		#[pallet::call_index(26)]
+		#[pallet::weight(
+			T::OnAppealed::max_weight()
+				.saturating_add(Pallet::<T, I>::scan_weight())
+				.saturating_add(T::HigherCourt::file_weight())
+		)]
+		pub fn fund_appeal(
+			origin: OriginFor<T>,
+			#[pallet::compact] lawsuit_id: ProposalIndex,
+			approve: bool,
+			#[pallet::compact] amount: BalanceOf<T, I>,
+		) -> DispatchResultWithPostInfo {
+			let contributor = ensure_signed(origin)?;
+			let lawsuit =
+				<Proposals<T, I>>::get(lawsuit_id).ok_or(Error::<T, I>::LawsuitNotFound)?;
+			Self::ensure_appealable(&lawsuit)?;
+
+			let round = lawsuit.round;
+			let mut fund = Self::appeal_funds(lawsuit_id, (round, approve)).unwrap_or(AppealFund {
+				raised: Zero::zero(),
+				contributions: vec![],
+				paid: false,
+			});
+			let left = Self::appeal_fee(round).saturating_sub(fund.raised);
+			ensure!(!left.is_zero(), Error::<T, I>::FundFilled);
+			let amount = amount.min(left);
+			T::Currency::reserve(&contributor, amount)?;
+			fund.raised = fund.raised.saturating_add(amount);
+			match fund.contributions.iter_mut().find(|(who, _)| who == &contributor) {
+				Some((_, contributed)) => *contributed = contributed.saturating_add(amount),
+				None => fund.contributions.push((contributor.clone(), amount)),
+			}
+			let appealed = amount == left && approve != lawsuit.approved;
+			Self::deposit_event(Event::AppealFunded {
+				lawsuit_id,
+				round,
+				approve,
+				contributor: contributor.clone(),
+				amount,
+			});
+			if appealed {
+				// The contributors pay the appeal fee together.
+				for (who, contributed) in fund.contributions.iter() {
+					T::Currency::unreserve(who, *contributed);
+					Self::pay_fee(lawsuit_id, who, *contributed)?;
+				}
+				fund.paid = true;
+			}
+			AppealFunds::<T, I>::insert(lawsuit_id, (round, approve), fund);
+
+			if appealed {
+				let weight = Self::start_appeal(lawsuit_id, lawsuit, contributor, true)?;
+				return Ok(Some(weight).into())
+			}
+			Ok(Some(Weight::zero()).into())
+		}
+
+		/// Offer an arbitration agreement to a plaintiff, replacing any previous agreement
+		/// between them. The agreement is live once the plaintiff signs it.
+		///
+		/// - `plaintiff`: The account allowed to sue under the agreement
+		/// - `terms`: The terms of the agreement
+		#[pallet::call_index(27)]
+		#[pallet::weight(Weight::zero())]
+		pub fn offer_agreement(
+			origin: OriginFor<T>,
+			plaintiff: AccountIdLookupOf<T>,
+			terms: AgreementTerms<BalanceOf<T, I>, T::BlockNumber>,
+		) -> DispatchResult {
+			let defendent = ensure_signed(origin)?;
+			let plaintiff = T::Lookup::lookup(plaintiff)?;
+			ensure!(terms.sub_court <= Self::sub_court_count(), Error::<T, I>::InvalidSubCourt);
+			let now = frame_system::Pallet::<T>::block_number();
+			ensure!(
+				terms.expires_at.map_or(true, |expires_at| expires_at > now),
+				Error::<T, I>::AgreementExpired
+			);
+
+			if let Some(agreement) = Self::agreements(&defendent, &plaintiff) {
+				T::Currency::unreserve(&defendent, agreement.reserved);
+			}
+			Agreements::<T, I>::insert(
+				&defendent,
+				&plaintiff,
+				ArbitrationAgreement { terms, signed: false, reserved: Zero::zero() },
+			);
+			Self::deposit_event(Event::AgreementOffered { defendent, plaintiff });
+			Ok(())
+		}
+
+		/// Sign an arbitration agreement offered by a defendant. `terms` must match the
+		/// offered terms.
+		///
+		/// The defendant's share of the filing fee is reserved from the defendant, so that it is
+		/// paid when a lawsuit is filed under the agreement.
+		///
+		/// - `defendent`: The account that offered the agreement
+		/// - `terms`: The terms of the agreement
+		#[pallet::call_index(28)]
+		#[pallet::weight(Weight::zero())]
+		pub fn sign_agreement(
+			origin: OriginFor<T>,
+			defendent: AccountIdLookupOf<T>,
+			terms: AgreementTerms<BalanceOf<T, I>, T::BlockNumber>,
+		) -> DispatchResult {
+			let plaintiff = ensure_signed(origin)?;
+			let defendent = T::Lookup::lookup(defendent)?;
+			Agreements::<T, I>::try_mutate(
+				&defendent,
+				&plaintiff,
+				|agreement| -> DispatchResult {
+					let agreement = agreement.as_mut().ok_or(Error::<T, I>::NoAgreement)?;
+					ensure!(agreement.terms == terms, Error::<T, I>::TermsMismatch);
+					let now = frame_system::Pallet::<T>::block_number();
+					ensure!(
+						terms.expires_at.map_or(true, |expires_at| expires_at > now),
+						Error::<T, I>::AgreementExpired
+					);
+					let share = T::FilingFee::get()
+						.saturating_sub(terms.plaintiff_fee * T::FilingFee::get())
+						.saturating_sub(agreement.reserved);
+					T::Currency::reserve(&defendent, share)?;
+					agreement.reserved = agreement.reserved.saturating_add(share);
+					agreement.signed = true;
 					Ok(())
+				},
+			)?;
+
+			Self::deposit_event(Event::AgreementSigned { defendent, plaintiff });
+			Ok(())
+		}
+
+		/// Revoke an arbitration agreement, either party can revoke it. The share of the fee
+		/// reserved from the defendant is released.
+		///
+		/// - `counterparty`: The other party to the agreement
+		#[pallet::call_index(29)]
+		#[pallet::weight(Weight::zero())]
+		pub fn revoke_agreement(
+			origin: OriginFor<T>,
+			counterparty: AccountIdLookupOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let counterparty = T::Lookup::lookup(counterparty)?;
+			let (defendent, plaintiff) = if Agreements::<T, I>::contains_key(&who, &counterparty) {
+				(who, counterparty)
+			} else {
+				(counterparty, who)
+			};
+			let agreement = Agreements::<T, I>::take(&defendent, &plaintiff)
+				.ok_or(Error::<T, I>::NoAgreement)?;
+			T::Currency::unreserve(&defendent, agreement.reserved);
+
+			Self::deposit_event(Event::AgreementRevoked { defendent, plaintiff });
+			Ok(())
+		}
+
+		/// Only accept lawsuits from plaintiffs with a live arbitration agreement, or accept
+		/// lawsuits from anyone again.
+		///
+		/// - `required`: Whether an agreement is required
+		#[pallet::call_index(30)]
+		#[pallet::weight(Weight::zero())]
+		pub fn set_agreement_required(origin: OriginFor<T>, required: bool) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			if required {
+				AgreementRequired::<T, I>::insert(&who, true);
+			} else {
+				AgreementRequired::<T, I>::remove(&who);
+			}
+
+			Self::deposit_event(Event::AgreementRequirementSet { who, required });
+			Ok(())
+		}
+
+		/// Amend the statement of a lawsuit before voting starts, at most `MaxAmendments` times
+		/// and not once a party has accepted a resolution in mediation. The earlier versions stay
+		/// requested until the lawsuit is closed, see `StatementHistory`.
+		///
+		/// May only be called by the plaintiff.
+		///
+		/// - `statement`: The hash of the new statement, whose preimage must have been noted.
+		#[pallet::call_index(31)]
+		#[pallet::weight(Weight::zero())]
+		pub fn amend_statement(
+			origin: OriginFor<T>,
+			#[pallet::compact] lawsuit_id: ProposalIndex,
+			statement: H256,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let mut lawsuit =
+				<Proposals<T, I>>::get(lawsuit_id).ok_or(Error::<T, I>::LawsuitNotFound)?;
+			ensure!(who == lawsuit.plaintiff, Error::<T, I>::NotPlaintiff);
+			ensure!(!lawsuit.closed, Error::<T, I>::LawsuitClosed);
+			ensure!(
+				lawsuit.round.is_zero() &&
+					lawsuit.voters.is_empty() &&
+					lawsuit.counterclaim.as_ref().map_or(true, |c| c.voters.is_empty()),
+				Error::<T, I>::VotingStarted
+			);
+			ensure!(
+				lawsuit.mediation.as_ref().map_or(true, |m| m.accepted_by.is_empty()),
+				Error::<T, I>::ResolutionAccepted
+			);
+			// the first version is the statement the lawsuit was filed with
+			let amendments = StatementHistory::<T, I>::decode_len(lawsuit_id).unwrap_or(1);
+			ensure!(
+				amendments.saturating_sub(1) < T::MaxAmendments::get() as usize,
+				Error::<T, I>::TooManyAmendments
+			);
+			ensure!(T::Preimages::len(&statement).is_some(), Error::<T, I>::StatementNotNoted);
+
+			T::Preimages::request(&statement);
+			let now = frame_system::Pallet::<T>::block_number();
+			StatementHistory::<T, I>::append(lawsuit_id, (statement, now));
+			lawsuit.statement = statement;
+			<Proposals<T, I>>::insert(lawsuit_id, lawsuit);
+
+			Self::deposit_event(Event::StatementAmended { lawsuit_id, statement });
+			Ok(())
+		}
+
+		/// Accuse a juror drawn into a lawsuit of misconduct, the case is filed in
+		/// `MisconductCourt` with the reporter as plaintiff and the juror as defendant.
+		///
+		/// Fails with `NotEnoughJurors` while `MisconductCourt` has too few jurors other than the
+		/// reporter and the juror to draw a jury, the report can be made once it has enough.
+		///
+		/// `MisconductBond` is reserved from the reporter and returned if the juror is found
+		/// guilty, the juror is then slashed `MisconductSlash` of their stake and banned from the
+		/// pool. An acquittal forfeits the bond to the treasury. The stake of the juror cannot
+		/// leave the pool until the case is closed, see `dismiss_misconduct` if it is not ruled
+		/// on within `MisconductTimeout`.
+		///
+		/// - `juror`: The juror drawn into the current jury of the lawsuit, or who voted in it
+		/// - `statement`: The hash of the statement, whose preimage must have been noted.
+		/// - `reopen`: Whether a new jury should rule on the lawsuit again if the juror is found
+		///   guilty. The ruling on the lawsuit cannot be executed until then.
+		#[pallet::call_index(32)]
+		#[pallet::weight(T::MisconductCourt::file_weight())]
+		pub fn report_misconduct(
+			origin: OriginFor<T>,
+			#[pallet::compact] lawsuit_id: ProposalIndex,
+			juror: AccountIdLookupOf<T>,
+			statement: H256,
+			reopen: bool,
+		) -> DispatchResult {
+			let reporter = ensure_signed(origin)?;
+			let juror = T::Lookup::lookup(juror)?;
+			let lawsuit =
+				<Proposals<T, I>>::get(lawsuit_id).ok_or(Error::<T, I>::LawsuitNotFound)?;
+			ensure!(
+				lawsuit.jurors.contains(&juror) || lawsuit.voters.contains(&juror),
+				Error::<T, I>::NotDrawn
+			);
+			ensure!(!reopen || !lawsuit.executed, Error::<T, I>::RulingExecuted);
+
+			let bond = T::MisconductBond::get();
+			T::Currency::reserve(&reporter, bond)?;
+			let case_id = T::MisconductCourt::file_misconduct(&reporter, &juror, statement)?;
+			let case = MisconductCase {
+				reporter: reporter.clone(),
+				juror: juror.clone(),
+				lawsuit_id,
+				sub_court: lawsuit.sub_court,
+				bond,
+				reopen,
+				deadline: frame_system::Pallet::<T>::block_number()
+					.saturating_add(T::MisconductTimeout::get()),
+			};
+			MisconductCases::<T, I>::insert(case_id, case);
+			Accusations::<T, I>::mutate(&juror, |n| *n = n.saturating_add(1));
+			if reopen {
+				PendingReopens::<T, I>::mutate(lawsuit_id, |n| *n = n.saturating_add(1));
+			}
+
+			Self::deposit_event(Event::MisconductReported { case_id, lawsuit_id, juror, reporter });
+			Ok(())
+		}
+
+		/// Vote on a lawsuit like `vote_sue`, attaching a written justification of the vote.
+		///
+		/// The justifications of the jurors who vote with the majority are kept as the reasons
+		/// for the ruling, see `Reasons`.
+		///
+		/// - `document`: The hash of the full justification.
+		/// - `summary`: A summary of the justification, at most `MaxSummaryLength` bytes.
+		#[pallet::call_index(33)]
+		#[pallet::weight(T::OnVoteCast::max_weight())]
+		pub fn vote_with_justification(
+			origin: OriginFor<T>,
+			lawsuit_id: u32,
+			approve: bool,
+			document: H256,
+			summary: Vec<u8>,
+		) -> DispatchResultWithPostInfo {
+			let juror = ensure_signed(origin.clone())?;
+			let summary =
+				SummaryOf::<T, I>::try_from(summary).map_err(|_| Error::<T, I>::SummaryTooLong)?;
+			let post_info = Self::vote_sue(origin, lawsuit_id, approve)?;
+
+			let justification = Justification { juror: juror.clone(), approve, document, summary };
+			PendingJustifications::<T, I>::append(lawsuit_id, justification);
+			Self::deposit_event(Event::VoteJustified { lawsuit_id, juror, document });
+			Ok(post_info)
+		}
+
+		/// Queue the unpaid award of an executed lawsuit again after it has been abandoned or
+		/// removed from the payout queue.
+		///
+		/// - `lawsuit_id`: The index of a lawsuit
+		#[pallet::call_index(34)]
+		#[pallet::weight(Weight::zero())]
+		pub fn retry_award(
+			origin: OriginFor<T>,
+			#[pallet::compact] lawsuit_id: ProposalIndex,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+			let lawsuit =
+				<Proposals<T, I>>::get(lawsuit_id).ok_or(Error::<T, I>::LawsuitNotFound)?;
+			ensure!(lawsuit.executed, Error::<T, I>::NotRuled);
+			ensure!(
+				!lawsuit.paid && Self::net_award(&lawsuit).is_some(),
+				Error::<T, I>::ProposalNotApproved
+			);
+			ensure!(
+				!Approvals::<T, I>::get().contains(&lawsuit_id) &&
+					!DeferredApprovals::<T, I>::get().contains(&lawsuit_id),
+				Error::<T, I>::AwardQueued
+			);
+
+			Approvals::<T, I>::try_append(lawsuit_id)
+				.map_err(|_| Error::<T, I>::TooManyApprovals)?;
+			PayoutAttempts::<T, I>::remove(lawsuit_id);
+			Ok(())
+		}
+
+		/// Withdraw a lawsuit that has not been ruled on.
+		///
+		/// The lawsuit is executed as rejected: the jury is released without rewards or slashes,
+		/// an ongoing mediation is ended and its fee refunded, and the lawsuit no longer counts
+		/// towards the open suits of the plaintiff. The filing fee is not refunded. Handlers are
+		/// notified with `on_lawsuit_withdrawn` rather than of a rejection.
+		///
+		/// - `lawsuit_id`: The index of a lawsuit
+		///
+		/// Errors:
+		/// - `NotPlaintiff`: Only the plaintiff can withdraw a lawsuit.
+		/// - `CounterclaimExists`: The counterclaim of the defendant has to be ruled on.
+		#[pallet::call_index(35)]
+		#[pallet::weight(
+			T::OnRulingExecuted::max_weight().saturating_add(T::MaxRemedyWeight::get())
+		)]
+		pub fn withdraw_sue(
+			origin: OriginFor<T>,
+			#[pallet::compact] lawsuit_id: ProposalIndex,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			let mut lawsuit =
+				<Proposals<T, I>>::get(lawsuit_id).ok_or(Error::<T, I>::LawsuitNotFound)?;
+			ensure!(who == lawsuit.plaintiff, Error::<T, I>::NotPlaintiff);
+			ensure!(!lawsuit.closed, Error::<T, I>::LawsuitClosed);
+			ensure!(lawsuit.counterclaim.is_none(), Error::<T, I>::CounterclaimExists);
+
+			if let Ok(mediation) = Self::ongoing_mediation(&mut lawsuit) {
+				T::Currency::unreserve(&who, mediation.fee);
+				mediation.failed = true;
+			}
+			PendingDraws::<T, I>::remove(lawsuit_id);
+			PendingJustifications::<T, I>::remove(lawsuit_id);
+			CastVotes::<T, I>::remove(lawsuit_id);
+			Self::release_jurors(&lawsuit.jurors);
+			lawsuit.approved = false;
+			lawsuit.closed = true;
+			lawsuit.ruled_at = Some(frame_system::Pallet::<T>::block_number());
+
+			Self::deposit_event(Event::LawsuitWithdrawn { lawsuit_id });
+			Ok(Some(Self::do_execute(lawsuit_id, lawsuit, true)).into())
+		}
+
+		/// Dismiss a misconduct case `MisconductCourt` has not ruled on within
+		/// `MisconductTimeout`.
+		///
+		/// The bond is returned to the reporter, the stake of the juror is free to leave the pool
+		/// and the ruling on the lawsuit can be executed if no other case may reopen it. A later
+		/// ruling on the case is ignored.
+		///
+		/// - `case_id`: The index of the case in `MisconductCourt`
+		#[pallet::call_index(36)]
+		#[pallet::weight(Weight::zero())]
+		pub fn dismiss_misconduct(
+			origin: OriginFor<T>,
+			#[pallet::compact] case_id: ProposalIndex,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+			let case =
+				MisconductCases::<T, I>::get(case_id).ok_or(Error::<T, I>::NoMisconductCase)?;
+			ensure!(
+				frame_system::Pallet::<T>::block_number() > case.deadline,
+				Error::<T, I>::MisconductNotExpired
+			);
+
+			MisconductCases::<T, I>::remove(case_id);
+			Self::close_misconduct(&case);
+			T::Currency::unreserve(&case.reporter, case.bond);
+
+			Self::deposit_event(Event::MisconductDismissed { case_id, juror: case.juror });
+			Ok(())
+		}
+
+		/// Have a new jury of the court rule on an escalated appeal `HigherCourt` has not ruled
+		/// on within `EscalationTimeout`. A later ruling of the higher court is ignored.
+		///
+		/// - `case_id`: The index of the case in `HigherCourt`
+		#[pallet::call_index(37)]
+		#[pallet::weight(Pallet::<T, I>::scan_weight())]
+		pub fn expire_escalation(
+			origin: OriginFor<T>,
+			#[pallet::compact] case_id: ProposalIndex,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+			let (lawsuit_id, _, deadline) =
+				Self::escalations(case_id).ok_or(Error::<T, I>::NoEscalation)?;
+			ensure!(
+				frame_system::Pallet::<T>::block_number() > deadline,
+				Error::<T, I>::EscalationNotExpired
+			);
+			let mut lawsuit =
+				<Proposals<T, I>>::get(lawsuit_id).ok_or(Error::<T, I>::LawsuitNotFound)?;
+			Self::schedule_draw(
+				lawsuit_id,
+				lawsuit.sub_court,
+				&[&lawsuit.plaintiff, &lawsuit.defendent],
+				JuryDraw { redraw: 0, count: T::JurySize::get(), replacing: vec![], attempts: 0 },
+			)?;
+
+			Escalations::<T, I>::remove(case_id);
+			// The counterclaim is ruled on again too, as in an appeal that was not escalated.
+			Self::reset_round(&mut lawsuit);
+			<Proposals<T, I>>::insert(lawsuit_id, lawsuit);
+
+			Self::deposit_event(Event::EscalationExpired { lawsuit_id, case_id });
+			Ok(())
+		}
+
+		/// Retry the abandoned jury draw of a lawsuit at the next block, for another
+		/// `MaxDrawAttempts` blocks.
+		///
+		/// May be called by any signed origin once the sub-court has enough jurors again.
+		///
+		/// - `lawsuit_id`: The index of a lawsuit
+		#[pallet::call_index(38)]
+		#[pallet::weight(Pallet::<T, I>::scan_weight())]
+		pub fn retry_draw(
+			origin: OriginFor<T>,
+			#[pallet::compact] lawsuit_id: ProposalIndex,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+			let mut draw =
+				PendingDraws::<T, I>::get(lawsuit_id).ok_or(Error::<T, I>::DrawNotAbandoned)?;
+			ensure!(draw.attempts >= T::MaxDrawAttempts::get(), Error::<T, I>::DrawNotAbandoned);
+			let lawsuit =
+				<Proposals<T, I>>::get(lawsuit_id).ok_or(Error::<T, I>::LawsuitNotFound)?;
+
+			draw.attempts = 0;
+			Self::schedule_draw(
+				lawsuit_id,
+				lawsuit.sub_court,
+				&[&lawsuit.plaintiff, &lawsuit.defendent],
+				draw,
+			)
+		}
+	}
+}
+
+impl<T: Config<I>, I: 'static> Pallet<T, I> {
+	/// The terms of the agreement under which `proposer` sues `defendent`, if any. Fails if the
+	/// defendant requires an agreement and there is no live one.
+	fn agreement_terms(
+		defendent: &T::AccountId,
+		proposer: &T::AccountId,
+	) -> Result<Option<AgreementTerms<BalanceOf<T, I>, T::BlockNumber>>, DispatchError> {
+		let terms = Self::live_agreement(defendent, proposer);
+		ensure!(
+			terms.is_some() || !Self::agreement_required(defendent),
+			Error::<T, I>::NoAgreement
+		);
+		Ok(terms)
+	}
+
+	/// File a lawsuit for `proposer` under the agreement `terms`, see `submit_sue`. `escalated`
+	/// is whether the appeal was funded for an appeal escalated from a lower court, see
+	/// `AppealCourt::file_appeal`. Returns the index of the lawsuit and the weight of the
+	/// `OnLawsuitFiled` hook.
+	#[allow(clippy::too_many_arguments)]
+	fn do_submit_sue(
+		proposer: T::AccountId,
+		value: BalanceOf<T, I>,
+		defendent: T::AccountId,
+		statement: H256,
+		sub_court: SubCourtIndex,
+		asset: Option<T::AssetId>,
+		metadata: Option<CaseMetadataOf<T, I>>,
+		mediate: bool,
+		fiat: bool,
+		terms: Option<AgreementTerms<BalanceOf<T, I>, T::BlockNumber>>,
+		escalated: Option<bool>,
+	) -> Result<(ProposalIndex, Weight), DispatchError> {
+		ensure!(T::Preimages::len(&statement).is_some(), Error::<T, I>::StatementNotNoted);
+		// The terms of the agreement override the court parameters
+		let sub_court = terms.as_ref().map_or(sub_court, |terms| terms.sub_court);
+		let plaintiff_fee = terms.as_ref().map_or(Perbill::one(), |terms| terms.plaintiff_fee);
+		if let Some(terms) = &terms {
+			let value = Self::native_value(value, asset, fiat).ok_or(Error::<T, I>::NoPrice)?;
+			ensure!(value <= terms.max_claim, Error::<T, I>::ClaimAboveAgreement);
+		}
+		ensure!(sub_court <= Self::sub_court_count(), Error::<T, I>::InvalidSubCourt);
+		if let Some(asset) = asset {
+			ensure!(T::Assets::asset_exists(asset), Error::<T, I>::UnknownAsset);
+		}
+		if let Some(metadata) = &metadata {
+			Self::ensure_valid_metadata(metadata)?;
+		}
+		ensure!(!fiat || T::Prices::price(asset).is_some(), Error::<T, I>::NoPrice);
+		if !Self::exempt(&proposer) && escalated.is_none() {
+			Self::ensure_can_file(&proposer, &defendent)?;
+		}
+		// The fund of a crowdfunded appeal covered its fees already.
+		let waived = escalated == Some(true);
+
+		let c = Self::proposal_count();
+		let mediation = if mediate {
+			let mediator = Self::assign_mediator(c, &[&proposer, &defendent])?;
+			let fee = T::MediationFee::get();
+			T::Currency::reserve(&proposer, fee)?;
+			let deadline =
+				frame_system::Pallet::<T>::block_number().saturating_add(T::MediationPeriod::get());
+			let mediation = Mediation {
+				mediator,
+				deadline,
+				fee,
+				proposal: None,
+				accepted_by: vec![],
+				failed: false,
+			};
+			Some(mediation)
+		} else {
+			Self::schedule_draw(
+				c,
+				sub_court,
+				&[&proposer, &defendent],
+				JuryDraw { redraw: 0, count: T::JurySize::get(), replacing: vec![], attempts: 0 },
+			)?;
+			None
+		};
+		let deposit = if waived { Zero::zero() } else { T::CaseDeposit::get() };
+		T::Currency::reserve(&proposer, deposit)?;
+		let filing_fee = if waived { Zero::zero() } else { T::FilingFee::get() };
+		let fee = plaintiff_fee * filing_fee;
+		Self::pay_fee(c, &proposer, fee)?;
+		Self::pay_defendant_fee(c, &proposer, &defendent, filing_fee.saturating_sub(fee))?;
+		<ProposalCount<T, I>>::put(c + 1);
+		let (value, claim) = if fiat { (Zero::zero(), Some(value)) } else { (value, None) };
+		let proposal = Lawsuit {
+			plaintiff: proposer,
+			value,
+			asset,
+			defendent,
+			statement,
+			voters: vec![],
+			votes: vec![],
+			approved: false,
+			paid: false,
+			jurors: vec![],
+			closed: false,
+			sub_court,
+			round: 0,
+			ruled_at: None,
+			executed: false,
+			deposit,
+			metadata,
+			mediation: mediation.clone(),
+			voting_ends: None,
+			redraws: 0,
+			claim,
+			holdback: Zero::zero(),
+			insured: Zero::zero(),
+			reversal: None,
+			counterclaim: None,
+			escalated: escalated.is_some(),
+		};
+		<Proposals<T, I>>::insert(c, &proposal);
+		OpenSuits::<T, I>::mutate(&proposal.plaintiff, |n| *n = n.saturating_add(1));
+		OpenSuitsAgainst::<T, I>::mutate(&proposal.plaintiff, &proposal.defendent, |n| {
+			*n = n.saturating_add(1)
+		});
+		T::Preimages::request(&statement);
+		let now = frame_system::Pallet::<T>::block_number();
+		StatementHistory::<T, I>::append(c, (statement, now));
+
+		Self::deposit_event(Event::Proposed { proposal_index: c });
+		if let Some(mediation) = mediation {
+			Self::deposit_event(Event::MediationStarted {
+				lawsuit_id: c,
+				mediator: mediation.mediator,
+			});
+		}
+		let weight =
+			T::OnLawsuitFiled::on_lawsuit_filed(c, &proposal.plaintiff, &proposal.defendent);
+		Ok((c, weight))
+	}
+
+	/// The terms of the agreement under which `plaintiff` can sue `defendent`, if it is live.
+	pub fn live_agreement(
+		defendent: &T::AccountId,
+		plaintiff: &T::AccountId,
+	) -> Option<AgreementTerms<BalanceOf<T, I>, T::BlockNumber>> {
+		let agreement = Self::agreements(defendent, plaintiff).filter(|a| a.signed)?;
+		let now = frame_system::Pallet::<T>::block_number();
+		let expired = agreement.terms.expires_at.map_or(false, |expires_at| expires_at <= now);
+		(!expired).then_some(agreement.terms)
+	}
+
+	/// Draw `count` distinct jurors for a round of a lawsuit in `sub_court`, `redraw` is `0` for
+	/// the jury of the round and counts the replacements of absent jurors after that. Only made at
+	/// a later block than the draw was committed to, see `schedule_draw`.
+	///
+	/// Jurors are weighted by their stake scaled by their reputation, jurors below the minimum
+	/// reputation of the sub-court, suspended jurors and accounts in `exclude` are never drawn.
+	fn draw_jurors(
+		lawsuit_id: ProposalIndex,
+		round: u32,
+		redraw: u32,
+		sub_court: SubCourtIndex,
+		count: u32,
+		exclude: &[&T::AccountId],
+	) -> Result<Vec<T::AccountId>, DispatchError> {
+		if count.is_zero() {
+			return Ok(vec![])
+		}
+
+		let mut candidates = Self::candidates(sub_court, exclude);
+		ensure!(candidates.len() >= count as usize, Error::<T, I>::NotEnoughJurors);
+
+		let (seed, _) = T::Randomness::random(&(b"court/jury", lawsuit_id, round, redraw).encode());
+		let mut jurors = Vec::with_capacity(count as usize);
+		for i in 0..count {
+			let total = candidates.iter().fold(0u128, |acc, (_, w)| acc.saturating_add(*w));
+			let random = (seed, i).using_encoded(blake2_256);
+			let mut target = u128::decode(&mut TrailingZeroInput::new(&random))
+				.expect("input is padded with zeroes; qed") %
+				total;
+			let index = candidates
+				.iter()
+				.position(|(_, weight)| {
+					if target < *weight {
+						true
+					} else {
+						target -= *weight;
+						false
+					}
+				})
+				.unwrap_or(candidates.len() - 1);
+			jurors.push(candidates.swap_remove(index).0);
+		}
+		for juror in jurors.iter() {
+			JurorStatistics::<T, I>::mutate(sub_court, juror, |stats| {
+				stats.drawn = stats.drawn.saturating_add(1)
+			});
+		}
+		Ok(jurors)
+	}
+
+	/// The jurors of a sub-court that can be drawn, other than `exclude`, with their weight in
+	/// the draw.
+	fn candidates(
+		sub_court: SubCourtIndex,
+		exclude: &[&T::AccountId],
+	) -> Vec<(T::AccountId, u128)> {
+		let min_reputation = Self::sub_courts(sub_court).min_reputation;
+		let max_reputation = T::MaxReputation::get();
+		let now = frame_system::Pallet::<T>::block_number();
+		Jurors::<T, I>::iter()
+			.filter(|(who, _)| !exclude.contains(&who))
+			.filter(|(who, _)| Suspensions::<T, I>::get(who).map_or(true, |until| until <= now))
+			.filter_map(|(who, juror)| {
+				let reputation = Self::reputation(&who);
+				if reputation < min_reputation {
+					return None
+				}
+				let stake = juror.weight().saturated_into::<u128>();
+				let weight = Perbill::from_rational(reputation, max_reputation) * stake;
+				Some((who, weight))
+			})
+			.filter(|(_, weight)| !weight.is_zero())
+			.collect()
+	}
+
+	/// Commit to making `draw` for a lawsuit at the next block, see `complete_draw`. Fails if the
+	/// sub-court has too few jurors other than `exclude`.
+	fn schedule_draw(
+		lawsuit_id: ProposalIndex,
+		sub_court: SubCourtIndex,
+		exclude: &[&T::AccountId],
+		draw: JuryDraw<T::AccountId>,
+	) -> DispatchResult {
+		if draw.count.is_zero() {
+			return Ok(())
+		}
+		ensure!(
+			Self::candidates(sub_court, exclude).len() >= draw.count as usize,
+			Error::<T, I>::NotEnoughJurors
+		);
+		let at = frame_system::Pallet::<T>::block_number().saturating_add(One::one());
+		PendingDraws::<T, I>::insert(lawsuit_id, draw);
+		DrawQueue::<T, I>::append(at, lawsuit_id);
+		Ok(())
+	}
+
+	/// Make the draw committed to for a lawsuit, with randomness that was not known when the
+	/// draw was requested. The parties and the jurors of the lawsuit are never drawn.
+	///
+	/// The draw is retried at the next block while the sub-court has too few jurors, up to
+	/// `MaxDrawAttempts` times before it is abandoned until `retry_draw`. It is dropped if the
+	/// lawsuit has been closed meanwhile.
+	fn complete_draw(lawsuit_id: ProposalIndex, now: T::BlockNumber) {
+		let mut draw = match PendingDraws::<T, I>::get(lawsuit_id) {
+			Some(draw) => draw,
+			None => return,
+		};
+		let mut lawsuit = match Proposals::<T, I>::get(lawsuit_id) {
+			Some(lawsuit) if !lawsuit.closed => lawsuit,
+			_ => {
+				PendingDraws::<T, I>::remove(lawsuit_id);
+				return
+			},
+		};
+		let mut exclude = vec![&lawsuit.plaintiff, &lawsuit.defendent];
+		exclude.extend(lawsuit.jurors.iter());
+		exclude.extend(draw.replacing.iter());
+		let drawn = match Self::draw_jurors(
+			lawsuit_id,
+			lawsuit.round,
+			draw.redraw,
+			lawsuit.sub_court,
+			draw.count,
+			&exclude,
+		) {
+			Ok(drawn) => drawn,
+			Err(_) => {
+				draw.attempts = draw.attempts.saturating_add(1);
+				if draw.attempts < T::MaxDrawAttempts::get() {
+					DrawQueue::<T, I>::append(now.saturating_add(One::one()), lawsuit_id);
+					Self::deposit_event(Event::JuryDrawDelayed { lawsuit_id });
 				} else {
-					Err(Error::<T, I>::ProposalNotApproved.into())
+					Self::deposit_event(Event::JuryDrawAbandoned { lawsuit_id });
 				}
-			})?;
+				PendingDraws::<T, I>::insert(lawsuit_id, draw);
+				return
+			},
+		};
+		PendingDraws::<T, I>::remove(lawsuit_id);
+		for juror in drawn.iter() {
+			DrawnInto::<T, I>::mutate(juror, |n| *n = n.saturating_add(1));
+		}
+		lawsuit.voting_ends = Self::voting_ends(&drawn);
+		lawsuit.jurors.extend(drawn.iter().cloned());
+		<Proposals<T, I>>::insert(lawsuit_id, lawsuit);
 
-			Ok(())
+		if draw.replacing.is_empty() {
+			Self::deposit_event(Event::JuryDrawn { lawsuit_id, jurors: drawn });
+		} else {
+			Self::deposit_event(Event::JurorsReplaced {
+				lawsuit_id,
+				absent: draw.replacing,
+				replacements: drawn,
+			});
 		}
 	}
-}
 
-impl<T: Config<I>, I: 'static> Pallet<T, I> {
+	/// Release jurors from a jury, their stake is unlocked once they sit on no other jury.
+	fn release_jurors(jurors: &[T::AccountId]) {
+		for juror in jurors {
+			DrawnInto::<T, I>::mutate_exists(juror, |n| {
+				*n = n.map(|n| n.saturating_sub(1)).filter(|n| !n.is_zero())
+			});
+		}
+	}
+
+	/// The weight of making `n` committed jury draws.
+	fn draw_weight(n: u32) -> Weight {
+		let db = T::DbWeight::get();
+		// The draw queue, then the draw, the lawsuit, the scan of the pool and the statistics
+		// and jury count of each juror drawn.
+		let per_draw = db
+			.reads_writes(2, 2)
+			.saturating_add(Self::scan_weight())
+			.saturating_add(db.reads_writes(2, 2).saturating_mul(T::JurySize::get().into()));
+		db.reads_writes(1, 1).saturating_add(per_draw.saturating_mul(n.into()))
+	}
+
+	/// The weight of scanning the pool for the candidates of a draw: each juror with its
+	/// suspension and reputation, for at most `MaxJurors` jurors.
+	pub fn scan_weight() -> Weight {
+		T::DbWeight::get().reads(3).saturating_mul(T::MaxJurors::get().into())
+	}
+
+	/// Pick a random mediator that is not in `exclude`.
+	fn assign_mediator(
+		lawsuit_id: ProposalIndex,
+		exclude: &[&T::AccountId],
+	) -> Result<T::AccountId, DispatchError> {
+		let mediators: Vec<_> =
+			Self::mediators().into_iter().filter(|m| !exclude.contains(&m)).collect();
+		ensure!(!mediators.is_empty(), Error::<T, I>::NoMediator);
+		let (seed, _) = T::Randomness::random(&(b"court/mediator", lawsuit_id).encode());
+		let random = u32::decode(&mut TrailingZeroInput::new(seed.as_ref()))
+			.expect("input is padded with zeroes; qed");
+		Ok(mediators[random as usize % mediators.len()].clone())
+	}
+
+	/// Whether `lawsuit` was closed by a settlement the parties accepted in mediation.
+	fn mediated(lawsuit: &LawsuitOf<T, I>) -> bool {
+		lawsuit.closed && lawsuit.mediation.as_ref().map_or(false, |m| !m.failed)
+	}
+
+	/// The mediation of `lawsuit`, if it is still going on.
+	fn ongoing_mediation(
+		lawsuit: &mut LawsuitOf<T, I>,
+	) -> Result<&mut Mediation<T::AccountId, BalanceOf<T, I>, T::BlockNumber>, DispatchError> {
+		let closed = lawsuit.closed;
+		let mediation = lawsuit
+			.mediation
+			.as_mut()
+			.filter(|m| !m.failed && !closed)
+			.ok_or(Error::<T, I>::NotInMediation)?;
+		Ok(mediation)
+	}
+
+	/// Ensure the disputed period of `metadata` is a range.
+	fn ensure_valid_metadata(metadata: &CaseMetadataOf<T, I>) -> DispatchResult {
+		ensure!(metadata.from <= metadata.to, Error::<T, I>::InvalidMetadata);
+		Ok(())
+	}
+
+	/// Ensure `plaintiff` is within the limits on filing lawsuits against `defendent`.
+	fn ensure_can_file(plaintiff: &T::AccountId, defendent: &T::AccountId) -> DispatchResult {
+		if let Some(until) = Self::cooldowns(plaintiff) {
+			ensure!(
+				frame_system::Pallet::<T>::block_number() >= until,
+				Error::<T, I>::InRejectionCooldown
+			);
+		}
+		ensure!(
+			Self::open_suits(plaintiff) < T::MaxOpenSuits::get(),
+			Error::<T, I>::TooManyOpenSuits
+		);
+		ensure!(
+			Self::open_suits_against(plaintiff, defendent) < T::MaxOpenSuitsPerDefendant::get(),
+			Error::<T, I>::TooManySuitsAgainstDefendant
+		);
+		Ok(())
+	}
+
+	/// Start unbonding stake of `who`.
+	fn unbond(who: &T::AccountId, value: BalanceOf<T, I>) {
+		if value.is_zero() {
+			return
+		}
+		let unlock_at =
+			<frame_system::Pallet<T>>::block_number().saturating_add(T::UnbondingPeriod::get());
+		Unbonding::<T, I>::append(who, UnbondingChunk { value, unlock_at });
+	}
+
+	/// The reputation score of `who` with decay applied.
+	pub fn reputation(who: &T::AccountId) -> u32 {
+		Reputations::<T, I>::get(who).map_or(0, |r| Self::decayed(&r))
+	}
+
+	/// Apply the decay since the last update to a reputation score.
+	fn decayed(reputation: &Reputation<T::BlockNumber>) -> u32 {
+		let period = T::ReputationDecayPeriod::get();
+		if period.is_zero() {
+			return reputation.score
+		}
+		let now = <frame_system::Pallet<T>>::block_number();
+		let periods: u32 = (now.saturating_sub(reputation.updated_at) / period).saturated_into();
+		let kept = (Perbill::one() - T::ReputationDecay::get()).saturating_pow(periods as usize);
+		kept * reputation.score
+	}
+
+	/// Raise the reputation of a juror for a coherent vote, or lower it otherwise.
+	fn update_reputation(who: &T::AccountId, coherent: bool) {
+		let updated_at = <frame_system::Pallet<T>>::block_number();
+		let current = Self::reputation(who);
+		let score = if coherent {
+			current.saturating_add(T::ReputationGain::get()).min(T::MaxReputation::get())
+		} else {
+			current.saturating_sub(T::ReputationLoss::get())
+		};
+		Reputations::<T, I>::insert(who, Reputation { score, updated_at });
+		Self::deposit_event(Event::ReputationUpdated { juror: who.clone(), score });
+	}
+
+	/// Rule on a lawsuit with the votes cast, by a majority of three quarters of them, and settle
+	/// the jury. The ruling is executed right away if there is no appeal period.
+	fn rule(
+		lawsuit_id: ProposalIndex,
+		mut proposal: LawsuitOf<T, I>,
+	) -> Result<Weight, DispatchError> {
+		let vote_count = proposal.votes.iter().filter(|v| **v).count() as u32;
+		let voter_count = proposal.votes.len() as u32;
+		proposal.closed = true;
+		proposal.approved = vote_count > 0 && vote_count >= voter_count * 3 / 4;
+		proposal.ruled_at = Some(frame_system::Pallet::<T>::block_number());
+		if let Some(counterclaim) = proposal.counterclaim.as_mut() {
+			// Counterclaims follow the rule of the lawsuit, and are dismissed without enough
+			// votes.
+			let approvals = counterclaim.votes.iter().filter(|v| **v).count() as u32;
+			let voters = counterclaim.votes.len() as u32;
+			counterclaim.approved = voters > 3 && approvals >= voters * 3 / 4;
+		}
+
+		// Fiat claims are only converted at the price of the ruling when they are upheld.
+		if let Some(claim) = proposal.claim {
+			if proposal.approved {
+				let value =
+					Self::fiat_value(claim, proposal.asset).ok_or(Error::<T, I>::NoPrice)?;
+				proposal.value = value;
+				Self::deposit_event(Event::ValueFixed { lawsuit_id, claim, value });
+			}
+		}
+		if let Some(counterclaim) = proposal.counterclaim.as_mut().filter(|c| c.approved) {
+			if let Some(claim) = counterclaim.claim {
+				counterclaim.value =
+					Self::fiat_value(claim, proposal.asset).ok_or(Error::<T, I>::NoPrice)?;
+			}
+		}
+
+		<Proposals<T, I>>::insert(lawsuit_id, &proposal);
+		Self::deposit_event(Event::ProposalClosed { lawsuit_id, approve: proposal.approved });
+		if let Some(counterclaim) = proposal.counterclaim.as_ref() {
+			let approve = counterclaim.approved;
+			Self::deposit_event(Event::CounterclaimClosed { lawsuit_id, approve });
+		}
+
+		Self::settle_jurors(lawsuit_id, &proposal);
+		let reasons: Vec<_> = PendingJustifications::<T, I>::take(lawsuit_id)
+			.into_iter()
+			.filter(|j| j.approve == proposal.approved)
+			.collect();
+		if reasons.is_empty() {
+			Reasons::<T, I>::remove(lawsuit_id);
+		} else {
+			Reasons::<T, I>::insert(lawsuit_id, reasons);
+		}
+		let mut weight = T::OnRuled::on_ruled(lawsuit_id, proposal.approved);
+		if T::AppealPeriod::get().is_zero() && Self::pending_reopens(lawsuit_id).is_zero() {
+			weight.saturating_accrue(Self::execute(lawsuit_id, proposal));
+		}
+		Ok(weight)
+	}
+
+	/// Settle the votes of the jurors on the ruling, see `settle_votes`, and release the jury.
+	///
+	/// Drawn jurors who did not vote by the end of the voting period are penalized as absent,
+	/// jurors still within it are not.
+	fn settle_jurors(lawsuit_id: ProposalIndex, lawsuit: &LawsuitOf<T, I>) {
+		Self::release_jurors(&lawsuit.jurors);
+		let now = frame_system::Pallet::<T>::block_number();
+		if lawsuit.voting_ends.map_or(false, |end| now > end) {
+			for juror in lawsuit.jurors.iter().filter(|juror| !lawsuit.voters.contains(juror)) {
+				Self::penalize_absentee(lawsuit_id, lawsuit.sub_court, juror, now);
+			}
+		}
+
+		for (voter, vote) in lawsuit.voters.iter().zip(lawsuit.votes.iter()) {
+			if Jurors::<T, I>::contains_key(voter) {
+				JurorStatistics::<T, I>::mutate(lawsuit.sub_court, voter, |stats| {
+					stats.votes = stats.votes.saturating_add(1)
+				});
+				CastVotes::<T, I>::append(lawsuit_id, (voter.clone(), *vote));
+			}
+		}
+		Self::settle_votes(
+			lawsuit_id,
+			lawsuit.sub_court,
+			&lawsuit.voters,
+			&lawsuit.votes,
+			lawsuit.approved,
+		);
+		if let Some(counterclaim) = &lawsuit.counterclaim {
+			let (voters, votes) = (&counterclaim.voters, &counterclaim.votes);
+			Self::settle_votes(lawsuit_id, lawsuit.sub_court, voters, votes, counterclaim.approved);
+		}
+	}
+
+	/// Slash the jurors who voted on a claim of a lawsuit against `ruling` and share the slashed
+	/// funds among the jurors who voted for it, in proportion to their weight. `TreasuryShare`
+	/// of each slash goes to the treasury.
+	///
+	/// The reputation of jurors is updated. Votes of accounts that are not jurors are neither
+	/// slashed nor rewarded.
+	fn settle_votes(
+		lawsuit_id: ProposalIndex,
+		sub_court: SubCourtIndex,
+		voters: &[T::AccountId],
+		votes: &[bool],
+		ruling: bool,
+	) {
+		let mut pot = NegativeImbalanceOf::<T, I>::zero();
+		let mut coherent = Vec::new();
+		for (voter, vote) in voters.iter().zip(votes.iter()) {
+			let juror = match Jurors::<T, I>::get(voter) {
+				Some(juror) => juror,
+				None => continue,
+			};
+			Self::update_reputation(voter, *vote == ruling);
+			if *vote == ruling {
+				coherent.push((voter.clone(), juror));
+			} else {
+				let slashed = Self::slash_juror(voter, juror, T::IncoherenceSlash::get());
+				JurorStatistics::<T, I>::mutate(sub_court, voter, |stats| {
+					stats.slashes = stats.slashes.saturating_add(slashed.peek())
+				});
+				Self::deposit_event(Event::JurorSlashed {
+					lawsuit_id,
+					juror: voter.clone(),
+					amount: slashed.peek(),
+				});
+				let share = T::TreasuryShare::get() * slashed.peek();
+				let (income, slashed) = slashed.split(share);
+				if !income.peek().is_zero() {
+					let amount = income.peek();
+					T::Currency::resolve_creating(&T::Treasury::account_id(), income);
+					T::Treasury::on_court_income(voter, amount);
+				}
+				pot.subsume(slashed);
+			}
+		}
+
+		let total_weight = coherent
+			.iter()
+			.fold(BalanceOf::<T, I>::zero(), |acc, (_, j)| acc.saturating_add(j.weight()));
+		if pot.peek().is_zero() || total_weight.is_zero() {
+			return
+		}
+		let total_reward = pot.peek();
+		for (who, juror) in coherent {
+			let reward = Perbill::from_rational(juror.weight(), total_weight) * total_reward;
+			let (reward, rest) = pot.split(reward);
+			pot = rest;
+			JurorStatistics::<T, I>::mutate(sub_court, &who, |stats| {
+				stats.rewards = stats.rewards.saturating_add(reward.peek())
+			});
+			Self::deposit_event(Event::JurorRewarded {
+				lawsuit_id,
+				juror: who.clone(),
+				amount: reward.peek(),
+			});
+			Self::reward_juror(&who, &juror, reward);
+		}
+		// Rounding dust is burned.
+	}
+
+	/// Slash `ratio` of the bond of a juror and of each delegation to the juror.
+	fn slash_juror(
+		who: &T::AccountId,
+		mut juror: Juror<BalanceOf<T, I>>,
+		ratio: Perbill,
+	) -> NegativeImbalanceOf<T, I> {
+		let (mut slashed, _) = T::Currency::slash_reserved(who, ratio * juror.bond);
+		juror.bond = juror.bond.saturating_sub(slashed.peek());
+
+		let delegations: Vec<_> = Delegations::<T, I>::iter_prefix(who).collect();
+		for (delegator, amount) in delegations {
+			let (imbalance, _) = T::Currency::slash_reserved(&delegator, ratio * amount);
+			let remaining = amount.saturating_sub(imbalance.peek());
+			juror.delegated = juror.delegated.saturating_sub(imbalance.peek());
+			if remaining.is_zero() {
+				Delegations::<T, I>::remove(who, &delegator);
+			} else {
+				Delegations::<T, I>::insert(who, &delegator, remaining);
+			}
+			slashed.subsume(imbalance);
+		}
+
+		Jurors::<T, I>::insert(who, juror);
+		slashed
+	}
+
+	/// Slash a juror who did not vote by the end of the voting period, the slashed funds go to
+	/// the treasury. The juror is suspended after `MaxAbsences` absences in a row.
+	fn penalize_absentee(
+		lawsuit_id: ProposalIndex,
+		sub_court: SubCourtIndex,
+		who: &T::AccountId,
+		now: T::BlockNumber,
+	) {
+		Self::update_reputation(who, false);
+		if let Some(juror) = Jurors::<T, I>::get(who) {
+			let slashed = Self::slash_juror(who, juror, T::NoShowSlash::get());
+			let amount = slashed.peek();
+			JurorStatistics::<T, I>::mutate(sub_court, who, |stats| {
+				stats.slashes = stats.slashes.saturating_add(amount)
+			});
+			Self::deposit_event(Event::JurorSlashed { lawsuit_id, juror: who.clone(), amount });
+			if !amount.is_zero() {
+				T::Currency::resolve_creating(&T::Treasury::account_id(), slashed);
+				T::Treasury::on_court_income(who, amount);
+			}
+		}
+
+		let absences = Self::absences(who).saturating_add(1);
+		if absences >= T::MaxAbsences::get() {
+			Absences::<T, I>::remove(who);
+			let until = now.saturating_add(T::SuspensionPeriod::get());
+			Suspensions::<T, I>::insert(who, until);
+			Self::deposit_event(Event::JurorSuspended { juror: who.clone(), until });
+		} else {
+			Absences::<T, I>::insert(who, absences);
+		}
+	}
+
+	/// The end of the voting period of a newly drawn jury, `None` if voting is open to anyone.
+	fn voting_ends(jurors: &[T::AccountId]) -> Option<T::BlockNumber> {
+		(!jurors.is_empty()).then(|| {
+			frame_system::Pallet::<T>::block_number().saturating_add(T::VotingPeriod::get())
+		})
+	}
+
+	/// Pay a reward to a juror and the delegators of the juror.
+	///
+	/// The juror keeps the commission, the rest is shared in proportion to the stake of the juror
+	/// and of each delegator.
+	fn reward_juror(
+		who: &T::AccountId,
+		juror: &Juror<BalanceOf<T, I>>,
+		reward: NegativeImbalanceOf<T, I>,
+	) {
+		let weight = juror.weight();
+		let shared = reward.peek().saturating_sub(juror.commission * reward.peek());
+		let mut rest = reward;
+		for (delegator, amount) in Delegations::<T, I>::iter_prefix(who) {
+			let (share, remaining) = rest.split(Perbill::from_rational(amount, weight) * shared);
+			rest = remaining;
+			T::Currency::resolve_creating(&delegator, share);
+		}
+		// The commission and the share of the juror's own bond.
+		T::Currency::resolve_creating(who, rest);
+	}
+
+	/// The amount of `asset` worth `claim` in the reference currency, at the current price.
+	pub fn fiat_value(
+		claim: BalanceOf<T, I>,
+		asset: Option<T::AssetId>,
+	) -> Option<BalanceOf<T, I>> {
+		let price = T::Prices::price(asset).filter(|price| !price.is_zero())?;
+		let claim = FixedU128::checked_from_integer(claim.saturated_into::<u128>())?;
+		Some(claim.checked_div(&price)?.saturating_mul_int(1u128).saturated_into())
+	}
+
+	/// The value of a claim in `Currency` at the current prices, `value` is denominated in the
+	/// reference currency of `PriceProvider` if `fiat` and in `asset` otherwise.
+	pub fn native_value(
+		value: BalanceOf<T, I>,
+		asset: Option<T::AssetId>,
+		fiat: bool,
+	) -> Option<BalanceOf<T, I>> {
+		if !fiat && asset.is_none() {
+			return Some(value)
+		}
+		let reference = if fiat {
+			value
+		} else {
+			let price = T::Prices::price(asset)?;
+			price.saturating_mul_int(value.saturated_into::<u128>()).saturated_into()
+		};
+		Self::fiat_value(reference, None)
+	}
+
+	/// The fee to appeal a lawsuit that has already been appealed `round` times.
+	pub fn appeal_fee(round: u32) -> BalanceOf<T, I> {
+		let factor: BalanceOf<T, I> = 2u128.saturating_pow(round).saturated_into();
+		T::AppealFee::get().saturating_mul(factor)
+	}
+
+	/// Ensure the ruling on a lawsuit can still be appealed.
+	fn ensure_appealable(lawsuit: &LawsuitOf<T, I>) -> DispatchResult {
+		ensure!(!lawsuit.executed, Error::<T, I>::RulingExecuted);
+		let ruled_at = lawsuit.ruled_at.ok_or(Error::<T, I>::NotRuled)?;
+		ensure!(
+			frame_system::Pallet::<T>::block_number() <=
+				ruled_at.saturating_add(T::AppealPeriod::get()),
+			Error::<T, I>::AppealPeriodOver
+		);
+		ensure!(lawsuit.round < T::MaxAppeals::get(), Error::<T, I>::TooManyAppeals);
+		Ok(())
+	}
+
+	/// Start the next round of an appealed lawsuit with a new jury, or escalate it to
+	/// `HigherCourt`. `funded` if the appeal fee was paid by an appeal fund. Returns the weight
+	/// of the `OnAppealed` hook.
+	fn start_appeal(
+		lawsuit_id: ProposalIndex,
+		mut lawsuit: LawsuitOf<T, I>,
+		appellant: T::AccountId,
+		funded: bool,
+	) -> Result<Weight, DispatchError> {
+		let round = lawsuit.round + 1;
+		let plaintiff_appealed = !lawsuit.approved;
+		let (loser, winner) = if lawsuit.approved {
+			(&lawsuit.defendent, &lawsuit.plaintiff)
+		} else {
+			(&lawsuit.plaintiff, &lawsuit.defendent)
+		};
+		let escalated = T::HigherCourt::file_appeal(
+			loser,
+			winner,
+			lawsuit.statement,
+			lawsuit.claim.unwrap_or(lawsuit.value),
+			lawsuit.asset,
+			lawsuit.claim.is_some(),
+			lawsuit.sub_court,
+			funded,
+		)?;
+		if escalated.is_none() {
+			Self::schedule_draw(
+				lawsuit_id,
+				lawsuit.sub_court,
+				&[&lawsuit.plaintiff, &lawsuit.defendent],
+				JuryDraw { redraw: 0, count: T::JurySize::get(), replacing: vec![], attempts: 0 },
+			)?;
+		}
+		Self::release_unfilled_funds(lawsuit_id, lawsuit.round);
+
+		lawsuit.round = round;
+		let counterclaim = lawsuit.counterclaim.clone();
+		Self::reset_round(&mut lawsuit);
+		if let Some(case_id) = escalated {
+			// Only the claim is appealed, the lawsuit is closed until the higher court rules.
+			lawsuit.counterclaim = counterclaim;
+			lawsuit.closed = true;
+			let deadline = frame_system::Pallet::<T>::block_number()
+				.saturating_add(T::EscalationTimeout::get());
+			Escalations::<T, I>::insert(case_id, (lawsuit_id, plaintiff_appealed, deadline));
+			Self::deposit_event(Event::AppealEscalated { lawsuit_id, case_id });
+		}
+		<Proposals<T, I>>::insert(lawsuit_id, lawsuit);
+
+		let weight = T::OnAppealed::on_appealed(lawsuit_id, &appellant, round);
+		Self::deposit_event(Event::Appealed { lawsuit_id, appellant, round });
+		Ok(weight)
+	}
+
+	/// Clear the jury, the votes and the ruling of the current round of a lawsuit for a new jury
+	/// to rule on it.
+	fn reset_round(lawsuit: &mut LawsuitOf<T, I>) {
+		lawsuit.voters = vec![];
+		lawsuit.votes = vec![];
+		lawsuit.approved = false;
+		if let Some(counterclaim) = lawsuit.counterclaim.as_mut() {
+			counterclaim.voters = vec![];
+			counterclaim.votes = vec![];
+			counterclaim.approved = false;
+		}
+		lawsuit.closed = false;
+		lawsuit.ruled_at = None;
+		lawsuit.voting_ends = None;
+		lawsuit.redraws = 0;
+		lawsuit.jurors = vec![];
+	}
+
+	/// Settle a misconduct case once `MisconductCourt` ruled on it.
+	fn rule_misconduct(
+		case_id: ProposalIndex,
+		case: MisconductCase<T::AccountId, BalanceOf<T, I>, T::BlockNumber>,
+		guilty: bool,
+	) {
+		Self::close_misconduct(&case);
+		if guilty {
+			T::Currency::unreserve(&case.reporter, case.bond);
+			Self::ban_juror(case.lawsuit_id, case.sub_court, &case.juror);
+			if case.reopen {
+				Self::reopen(case.lawsuit_id);
+			}
+		} else {
+			let (slashed, _) = T::Currency::slash_reserved(&case.reporter, case.bond);
+			let amount = slashed.peek();
+			if !amount.is_zero() {
+				T::Currency::resolve_creating(&T::Treasury::account_id(), slashed);
+				T::Treasury::on_court_income(&case.reporter, amount);
+			}
+		}
+		Self::deposit_event(Event::MisconductRuled { case_id, juror: case.juror, guilty });
+	}
+
+	/// Stop counting a closed misconduct case against the juror and towards the pending reopens
+	/// of the lawsuit.
+	fn close_misconduct(case: &MisconductCase<T::AccountId, BalanceOf<T, I>, T::BlockNumber>) {
+		if case.reopen {
+			PendingReopens::<T, I>::mutate_exists(case.lawsuit_id, |n| {
+				*n = n.map(|n| n.saturating_sub(1)).filter(|n| !n.is_zero())
+			});
+		}
+		Accusations::<T, I>::mutate_exists(&case.juror, |n| {
+			*n = n.map(|n| n.saturating_sub(1)).filter(|n| !n.is_zero())
+		});
+	}
+
+	/// Slash `MisconductSlash` of the stake of a juror to the treasury, unbond the rest and ban
+	/// the juror from the pool.
+	fn ban_juror(lawsuit_id: ProposalIndex, sub_court: SubCourtIndex, who: &T::AccountId) {
+		if let Some(juror) = Jurors::<T, I>::get(who) {
+			let slashed = Self::slash_juror(who, juror, T::MisconductSlash::get());
+			let amount = slashed.peek();
+			JurorStatistics::<T, I>::mutate(sub_court, who, |stats| {
+				stats.slashes = stats.slashes.saturating_add(amount)
+			});
+			Self::deposit_event(Event::JurorSlashed { lawsuit_id, juror: who.clone(), amount });
+			if !amount.is_zero() {
+				T::Currency::resolve_creating(&T::Treasury::account_id(), slashed);
+				T::Treasury::on_court_income(who, amount);
+			}
+		}
+		if let Some(juror) = Jurors::<T, I>::take(who) {
+			JurorCount::<T, I>::mutate(|n| *n = n.saturating_sub(1));
+			Self::unbond(who, juror.bond);
+			for (delegator, amount) in Delegations::<T, I>::drain_prefix(who) {
+				Self::unbond(&delegator, amount);
+			}
+		}
+		Banned::<T, I>::insert(who, true);
+		Self::deposit_event(Event::JurorBanned { juror: who.clone() });
+	}
+
+	/// Replace the ruling on an escalated lawsuit with the ruling of `HigherCourt` and execute
+	/// it.
+	fn override_ruling(lawsuit_id: ProposalIndex, approve: bool) -> Weight {
+		let mut lawsuit = match Proposals::<T, I>::get(lawsuit_id) {
+			Some(lawsuit) if !lawsuit.executed => lawsuit,
+			_ => return Weight::zero(),
+		};
+		lawsuit.approved = approve;
+		lawsuit.ruled_at = Some(frame_system::Pallet::<T>::block_number());
+		lawsuit.closed = true;
+		// The reasons of the overridden jury no longer back the ruling.
+		Reasons::<T, I>::remove(lawsuit_id);
+		Self::deposit_event(Event::RulingOverridden { lawsuit_id, approve });
+		Self::execute(lawsuit_id, lawsuit)
+	}
+
+	/// Commit to drawing a new jury to rule on the current round of a lawsuit again, unless the
+	/// ruling has been executed or there are not enough jurors.
+	fn reopen(lawsuit_id: ProposalIndex) {
+		let mut lawsuit = match Proposals::<T, I>::get(lawsuit_id) {
+			Some(lawsuit) if !lawsuit.executed => lawsuit,
+			_ => return,
+		};
+		let draw = JuryDraw {
+			redraw: lawsuit.redraws.saturating_add(1),
+			count: T::JurySize::get(),
+			replacing: vec![],
+			attempts: 0,
+		};
+		let parties = [&lawsuit.plaintiff, &lawsuit.defendent];
+		if Self::schedule_draw(lawsuit_id, lawsuit.sub_court, &parties, draw).is_err() {
+			return
+		}
+		// A jury that has ruled has been settled already.
+		if lawsuit.ruled_at.is_none() {
+			Self::release_jurors(&lawsuit.jurors);
+		}
+		Self::reset_round(&mut lawsuit);
+		<Proposals<T, I>>::insert(lawsuit_id, lawsuit);
+		PendingJustifications::<T, I>::remove(lawsuit_id);
+
+		Self::deposit_event(Event::LawsuitReopened { lawsuit_id });
+	}
+
+	/// Release the contributions to the appeal funds of `round` that have not been filled.
+	fn release_unfilled_funds(lawsuit_id: ProposalIndex, round: u32) {
+		let fee = Self::appeal_fee(round);
+		for approve in [true, false] {
+			let fund = match AppealFunds::<T, I>::get(lawsuit_id, (round, approve)) {
+				Some(fund) if fund.raised < fee => fund,
+				_ => continue,
+			};
+			AppealFunds::<T, I>::remove(lawsuit_id, (round, approve));
+			for (contributor, amount) in fund.contributions {
+				T::Currency::unreserve(&contributor, amount);
+			}
+			Self::deposit_event(Event::AppealFundFailed { lawsuit_id, round, approve });
+		}
+	}
+
+	/// Settle the appeal funds of an executed lawsuit.
+	///
+	/// The reserved funds of the losing side are slashed and shared among the contributors to
+	/// the winning side in proportion to their contributions, or go to the treasury if nobody
+	/// backed the winning side. The appeal fee paid by the funds of the winning side is returned
+	/// to their contributors from the treasury, as far as it can pay, and they share the pot as
+	/// well. The fee paid by the funds of the losing side is kept.
+	fn settle_appeal_funds(lawsuit_id: ProposalIndex, lawsuit: &LawsuitOf<T, I>) {
+		Self::release_unfilled_funds(lawsuit_id, lawsuit.round);
+
+		let mut pot = NegativeImbalanceOf::<T, I>::zero();
+		let mut winners = Vec::new();
+		for ((_, approve), fund) in AppealFunds::<T, I>::drain_prefix(lawsuit_id) {
+			for (contributor, amount) in fund.contributions {
+				if approve == lawsuit.approved {
+					if !fund.paid {
+						T::Currency::unreserve(&contributor, amount);
+					} else {
+						Self::refund_appeal_fee(lawsuit_id, &contributor, amount);
+					}
+					winners.push((contributor, amount));
+				} else if !fund.paid {
+					pot.subsume(T::Currency::slash_reserved(&contributor, amount).0);
+				}
+			}
+		}
+
+		let total_pot = pot.peek();
+		if total_pot.is_zero() {
+			return
+		}
+		let total_stake = winners
+			.iter()
+			.fold(BalanceOf::<T, I>::zero(), |acc, (_, amount)| acc.saturating_add(*amount));
+		if total_stake.is_zero() {
+			T::Currency::resolve_creating(&T::Treasury::account_id(), pot);
+			T::Treasury::on_court_income(&lawsuit.plaintiff, total_pot);
+			return
+		}
+		for (contributor, amount) in winners {
+			let reward = Perbill::from_rational(amount, total_stake) * total_pot;
+			let (reward, rest) = pot.split(reward);
+			pot = rest;
+			Self::deposit_event(Event::AppealFundRewarded {
+				lawsuit_id,
+				contributor: contributor.clone(),
+				amount: reward.peek(),
+			});
+			T::Currency::resolve_creating(&contributor, reward);
+		}
+		// Rounding dust is burned.
+	}
+
+	/// Return `amount` of the appeal fee paid by a contributor to a winning appeal fund from the
+	/// treasury.
+	fn refund_appeal_fee(
+		lawsuit_id: ProposalIndex,
+		contributor: &T::AccountId,
+		amount: BalanceOf<T, I>,
+	) {
+		let treasury = T::Treasury::account_id();
+		if T::Currency::transfer(&treasury, contributor, amount, AllowDeath).is_ok() {
+			Self::deposit_event(Event::AppealFeeRefunded {
+				lawsuit_id,
+				contributor: contributor.clone(),
+				amount,
+			});
+		}
+	}
+
+	/// Transfer a fee paid for a lawsuit to the treasury.
+	fn pay_fee(
+		lawsuit_id: ProposalIndex,
+		payer: &T::AccountId,
+		amount: BalanceOf<T, I>,
+	) -> DispatchResult {
+		if amount.is_zero() {
+			return Ok(())
+		}
+		T::Currency::transfer(payer, &T::Treasury::account_id(), amount, KeepAlive)?;
+		T::Treasury::on_court_income(payer, amount);
+		Self::deposit_event(Event::FeePaid { lawsuit_id, payer: payer.clone(), amount });
+		Ok(())
+	}
+
+	/// Pay the defendant's `share` of the filing fee of a lawsuit filed under an agreement from
+	/// the share reserved for it, the plaintiff pays what the reserve does not cover. The share
+	/// of the next lawsuit is reserved from the defendant again if possible.
+	fn pay_defendant_fee(
+		lawsuit_id: ProposalIndex,
+		plaintiff: &T::AccountId,
+		defendent: &T::AccountId,
+		share: BalanceOf<T, I>,
+	) -> DispatchResult {
+		let mut agreement = match Self::agreements(defendent, plaintiff) {
+			Some(agreement) if !share.is_zero() => agreement,
+			_ => return Ok(()),
+		};
+		let covered = share.min(agreement.reserved);
+		if !covered.is_zero() {
+			T::Currency::unreserve(defendent, covered);
+			T::Currency::transfer(defendent, &T::Treasury::account_id(), covered, AllowDeath)?;
+			T::Treasury::on_court_income(defendent, covered);
+			let payer = defendent.clone();
+			Self::deposit_event(Event::FeePaid { lawsuit_id, payer, amount: covered });
+		}
+		Self::pay_fee(lawsuit_id, plaintiff, share.saturating_sub(covered))?;
+
+		agreement.reserved = agreement.reserved.saturating_sub(covered);
+		let missing = share.saturating_sub(agreement.reserved);
+		if T::Currency::reserve(defendent, missing).is_ok() {
+			agreement.reserved = agreement.reserved.saturating_add(missing);
+		}
+		Agreements::<T, I>::insert(defendent, plaintiff, agreement);
+		Ok(())
+	}
+
+	/// Execute a final ruling, the netted award is queued for payout. Returns the weight of the
+	/// remedy and of the `OnRulingExecuted` hook.
+	fn execute(lawsuit_id: ProposalIndex, lawsuit: LawsuitOf<T, I>) -> Weight {
+		Self::do_execute(lawsuit_id, lawsuit, false)
+	}
+
+	/// Execute a lawsuit, notifying the handlers of its withdrawal if `withdrawn`.
+	fn do_execute(
+		lawsuit_id: ProposalIndex,
+		mut lawsuit: LawsuitOf<T, I>,
+		withdrawn: bool,
+	) -> Weight {
+		lawsuit.executed = true;
+		<Proposals<T, I>>::insert(lawsuit_id, &lawsuit);
+		OpenSuits::<T, I>::mutate_exists(&lawsuit.plaintiff, |n| {
+			*n = n.map(|n| n.saturating_sub(1)).filter(|n| !n.is_zero())
+		});
+		OpenSuitsAgainst::<T, I>::mutate_exists(&lawsuit.plaintiff, &lawsuit.defendent, |n| {
+			*n = n.map(|n| n.saturating_sub(1)).filter(|n| !n.is_zero())
+		});
+		let now = frame_system::Pallet::<T>::block_number();
+		let remedy = Remedies::<T, I>::take(lawsuit_id);
+		Self::settle_appeal_funds(lawsuit_id, &lawsuit);
+		for (voter, vote) in CastVotes::<T, I>::take(lawsuit_id) {
+			if vote == lawsuit.approved {
+				JurorStatistics::<T, I>::mutate(lawsuit.sub_court, &voter, |stats| {
+					stats.coherent = stats.coherent.saturating_add(1)
+				});
+			}
+		}
+		if Self::net_award(&lawsuit).is_some() {
+			// The award is paid out by the hooks
+			Self::queue_award(lawsuit_id);
+		}
+		let mut weight = Weight::zero();
+		if lawsuit.approved {
+			if let Some(remedy) = remedy {
+				let info = remedy.get_dispatch_info();
+				let origin =
+					RawOrigin::Remedy { lawsuit_id, plaintiff: lawsuit.plaintiff.clone() }.into();
+				let result = remedy.dispatch(origin);
+				weight = extract_actual_weight(&result, &info);
+				let result = result.map(|_| ()).map_err(|e| e.error);
+				Self::deposit_event(Event::RemedyExecuted { lawsuit_id, result });
+			}
+		} else if !T::RejectionCooldown::get().is_zero() &&
+			!Self::mediated(&lawsuit) &&
+			!lawsuit.escalated
+		{
+			Cooldowns::<T, I>::insert(
+				&lawsuit.plaintiff,
+				now.saturating_add(T::RejectionCooldown::get()),
+			);
+		}
+		let archive_at = now.saturating_add(T::RetentionPeriod::get());
+		ArchiveQueue::<T, I>::append((lawsuit_id, archive_at));
+		Self::deposit_event(Event::RulingExecuted { lawsuit_id, approve: lawsuit.approved });
+		let (plaintiff, defendent) = (&lawsuit.plaintiff, &lawsuit.defendent);
+		weight.saturating_add(if withdrawn {
+			T::OnRulingExecuted::on_lawsuit_withdrawn(lawsuit_id, plaintiff, defendent)
+		} else {
+			T::OnRulingExecuted::on_ruling_executed(
+				lawsuit_id,
+				plaintiff,
+				defendent,
+				lawsuit.approved,
+			)
+		})
+	}
+
+	/// The weight of archiving a lawsuit with `voters` voters and `statements` statements.
+	pub(crate) fn archive_weight(voters: u32, statements: u32) -> Weight {
+		let db = T::DbWeight::get();
+		// The lawsuit, the plaintiff's deposit, the statement history and the summary, then a
+		// contribution per voter and a preimage request per statement, and the hook.
+		db.reads_writes(3, 4)
+			.saturating_add(db.reads_writes(1, 1).saturating_mul(voters.into()))
+			.saturating_add(db.reads_writes(1, 1).saturating_mul(statements.into()))
+			.saturating_add(T::OnRulingExecuted::max_weight())
+	}
+
+	/// Archive the lawsuits at the front of the archive queue that are due at `now`, within
+	/// `limit`.
+	///
+	/// Lawsuits whose award has not been paid yet are put back at the end of the queue.
+	fn archive_lawsuits(now: T::BlockNumber, limit: Weight) -> Weight {
+		let db = T::DbWeight::get();
+		let mut used = db.reads_writes(1, 1);
+		if limit.any_lt(used) {
+			return Weight::zero()
+		}
+
+		let mut queue = ArchiveQueue::<T, I>::get();
+		let mut processed = 0;
+		let mut retry = Vec::new();
+		for (lawsuit_id, archive_at) in queue.iter() {
+			if *archive_at > now {
+				break
+			}
+			let lawsuit = match Proposals::<T, I>::get(lawsuit_id) {
+				Some(lawsuit) => lawsuit,
+				None => {
+					processed += 1;
+					continue
+				},
+			};
+			let statements = StatementHistory::<T, I>::decode_len(lawsuit_id).unwrap_or(0) +
+				lawsuit.counterclaim.iter().count();
+			let weight = Self::archive_weight(lawsuit.voters.len() as u32, statements as u32);
+			if used.saturating_add(weight).any_gt(limit) {
+				break
+			}
+			used.saturating_accrue(weight);
+			processed += 1;
+			if !lawsuit.paid && Self::net_award(&lawsuit).is_some() {
+				retry.push((*lawsuit_id, now.saturating_add(T::RetentionPeriod::get())));
+				continue
+			}
+			Self::archive_lawsuit(*lawsuit_id, lawsuit, now);
+		}
+		if processed == 0 {
+			return db.reads(1)
+		}
+
+		queue.drain(..processed);
+		queue.extend(retry);
+		ArchiveQueue::<T, I>::put(queue);
+		used
+	}
+
+	/// Replace a lawsuit with its summary and refund the deposit of the plaintiff. The
+	/// statements are no longer requested, the parties can unnote them to release their deposits.
+	fn archive_lawsuit(lawsuit_id: ProposalIndex, lawsuit: LawsuitOf<T, I>, now: T::BlockNumber) {
+		if lawsuit.approved {
+			for voter in lawsuit.voters.iter() {
+				ArchivedContributions::<T, I>::mutate(voter, |c| *c = c.saturating_add(1));
+			}
+		}
+		T::Currency::unreserve(&lawsuit.plaintiff, lawsuit.deposit);
+		if let Some((_, beneficiary, _)) = Self::net_award(&lawsuit) {
+			T::Currency::unreserve(beneficiary, lawsuit.holdback);
+		}
+		for (statement, _) in StatementHistory::<T, I>::take(lawsuit_id) {
+			T::Preimages::unrequest(&statement);
+		}
+		if let Some(counterclaim) = &lawsuit.counterclaim {
+			T::Preimages::unrequest(&counterclaim.statement);
+		}
+		T::OnRulingExecuted::on_lawsuit_archived(lawsuit_id);
+		Proposals::<T, I>::remove(lawsuit_id);
+		Archive::<T, I>::insert(
+			lawsuit_id,
+			ArchivedLawsuit {
+				plaintiff: lawsuit.plaintiff,
+				defendent: lawsuit.defendent,
+				approved: lawsuit.approved,
+				value: lawsuit.value,
+				asset: lawsuit.asset,
+				ruled_at: lawsuit.ruled_at.unwrap_or(now),
+			},
+		);
+		Self::deposit_event(Event::LawsuitArchived { lawsuit_id, deposit: lawsuit.deposit });
+	}
+
+	/// Put an approved lawsuit into the payout queue, or defer it while the queue is full.
+	/// Abandon the award if the deferred approvals are full as well.
+	fn queue_award(lawsuit_id: ProposalIndex) {
+		if Approvals::<T, I>::try_append(lawsuit_id).is_ok() {
+			return
+		}
+		if DeferredApprovals::<T, I>::try_append(lawsuit_id).is_ok() {
+			Self::deposit_event(Event::AwardDeferred { lawsuit_id });
+		} else {
+			Self::deposit_event(Event::AwardAbandoned { lawsuit_id });
+		}
+	}
+
+	/// The weight of paying `n` queued awards.
+	pub(crate) fn payout_weight(n: u32) -> Weight {
+		let db = T::DbWeight::get();
+		// `Approvals` and `DeferredApprovals`, then the lawsuit, its payout attempts and both
+		// parties for each award.
+		db.reads_writes(2, 2)
+			.saturating_add(db.reads_writes(4, 4).saturating_mul(n.into()))
+	}
+
+	/// Pay the awards at the front of the payout queue within `limit`.
+	///
+	/// Failed payouts are moved to the back of the queue and retried in a later batch, until
+	/// they failed `MaxPayoutAttempts` times. The freed slots are then refilled from the
+	/// deferred approvals.
+	fn pay_awards(limit: Weight) -> Weight {
+		if limit.any_lt(Self::payout_weight(1)) {
+			return Weight::zero()
+		}
+
+		let mut queue = Approvals::<T, I>::get().into_inner();
+		let mut deferred = DeferredApprovals::<T, I>::get().into_inner();
+		if queue.is_empty() && deferred.is_empty() {
+			return T::DbWeight::get().reads(2)
+		}
+
+		let mut processed = 0;
+		let mut failed = Vec::new();
+		while processed < queue.len() && Self::payout_weight(processed as u32 + 1).all_lte(limit) {
+			let lawsuit_id = queue[processed];
+			processed += 1;
+			if Self::pay_award(lawsuit_id) {
+				PayoutAttempts::<T, I>::remove(lawsuit_id);
+				continue
+			}
+			let attempts = PayoutAttempts::<T, I>::get(lawsuit_id).saturating_add(1);
+			if attempts < T::MaxPayoutAttempts::get() {
+				PayoutAttempts::<T, I>::insert(lawsuit_id, attempts);
+				failed.push(lawsuit_id);
+			} else {
+				PayoutAttempts::<T, I>::remove(lawsuit_id);
+				Self::deposit_event(Event::AwardAbandoned { lawsuit_id });
+			}
+		}
+
+		let mut queue = queue.split_off(processed);
+		queue.extend(failed);
+		let free = (T::MaxApprovals::get() as usize).saturating_sub(queue.len());
+		let refill = free.min(deferred.len());
+		queue.extend(deferred.drain(..refill));
+
+		Approvals::<T, I>::put(BoundedVec::truncate_from(queue));
+		DeferredApprovals::<T, I>::put(BoundedVec::truncate_from(deferred));
+		Self::payout_weight(processed as u32)
+	}
+
+	/// The payer, the beneficiary and the amount of the award of a ruling, once the upheld
+	/// claim and counterclaim are netted. `None` if nothing is owed, as for escalated appeals
+	/// whose ruling is executed by the lower court.
+	pub fn net_award(
+		lawsuit: &LawsuitOf<T, I>,
+	) -> Option<(&T::AccountId, &T::AccountId, BalanceOf<T, I>)> {
+		if lawsuit.escalated {
+			return None
+		}
+		let claim = if lawsuit.approved { lawsuit.value } else { Zero::zero() };
+		let counterclaim = lawsuit
+			.counterclaim
+			.as_ref()
+			.filter(|c| c.approved)
+			.map_or_else(Zero::zero, |c| c.value);
+		if claim > counterclaim {
+			Some((&lawsuit.defendent, &lawsuit.plaintiff, claim - counterclaim))
+		} else if counterclaim > claim {
+			Some((&lawsuit.plaintiff, &lawsuit.defendent, counterclaim - claim))
+		} else {
+			None
+		}
+	}
+
+	/// Pay the netted award of a lawsuit, returns `false` if the payout should be retried.
+	fn pay_award(lawsuit_id: ProposalIndex) -> bool {
+		let mut lawsuit = match Proposals::<T, I>::get(lawsuit_id) {
+			Some(lawsuit) if !lawsuit.paid => lawsuit,
+			// Nothing left to pay.
+			_ => return true,
+		};
+		let (payer, beneficiary, amount) = match Self::net_award(&lawsuit) {
+			Some((payer, beneficiary, amount)) => (payer.clone(), beneficiary.clone(), amount),
+			None => return true,
+		};
+
+		let transfer = match lawsuit.asset {
+			None => T::Currency::transfer(&payer, &beneficiary, amount, KeepAlive)
+				.map(|_| Zero::zero())
+				.or_else(|error| {
+					Self::insure_award(lawsuit_id, &payer, &beneficiary, amount).map_err(|_| error)
+				}),
+			Some(asset) => <T::Assets as fungibles::Transfer<_>>::transfer(
+				asset,
+				&payer,
+				&beneficiary,
+				amount,
+				true,
+			)
+			.map(|_| Zero::zero()),
+		};
+		match transfer {
+			Ok(insured) => {
+				lawsuit.paid = true;
+				lawsuit.insured = insured;
+				if lawsuit.asset.is_none() {
+					let holdback = T::AwardHoldback::get() * amount;
+					if T::Currency::reserve(&beneficiary, holdback).is_ok() {
+						lawsuit.holdback = holdback;
+					}
+				}
+				Proposals::<T, I>::insert(lawsuit_id, &lawsuit);
+				Self::deposit_event(Event::AwardPaid { lawsuit_id, amount, beneficiary });
+				true
+			},
+			Err(error) => {
+				Self::deposit_event(Event::AwardFailed { lawsuit_id, error });
+				false
+			},
+		}
+	}
+
+	/// Pay a native award with the help of the insurance pool, which covers the part the payer
+	/// cannot pay if it is at most `InsuranceCap`, and takes over the debt of the payer. Returns
+	/// the part covered.
+	fn insure_award(
+		lawsuit_id: ProposalIndex,
+		payer: &T::AccountId,
+		beneficiary: &T::AccountId,
+		amount: BalanceOf<T, I>,
+	) -> Result<BalanceOf<T, I>, DispatchError> {
+		let available =
+			T::Currency::free_balance(payer).saturating_sub(T::Currency::minimum_balance());
+		let own = amount.min(available);
+		let covered = amount.saturating_sub(own);
+		ensure!(
+			covered <= T::InsuranceCap::get() && covered <= Self::insurance_status().balance,
+			Error::<T, I>::InsuranceUnavailable
+		);
+
+		with_transaction(|| {
+			let result = T::Currency::transfer(payer, beneficiary, own, KeepAlive).and_then(|_| {
+				T::Currency::transfer(
+					&T::Treasury::insurance_account(),
+					beneficiary,
+					covered,
+					KeepAlive,
+				)
+			});
+			match result {
+				Ok(()) => TransactionOutcome::Commit(Ok(())),
+				Err(error) => TransactionOutcome::Rollback(Err(error)),
+			}
+		})?;
+		InsuranceDebts::<T, I>::mutate(payer, |d| *d = d.saturating_add(covered));
+		TotalInsuranceDebt::<T, I>::mutate(|d| *d = d.saturating_add(covered));
+		Self::deposit_event(Event::AwardInsured { lawsuit_id, debtor: payer.clone(), covered });
+		Ok(covered)
+	}
+
+	/// The funds, outstanding debts and cap of the insurance pool.
+	pub fn insurance_status() -> InsuranceStatus<BalanceOf<T, I>> {
+		let balance = T::Currency::free_balance(&T::Treasury::insurance_account())
+			.saturating_sub(T::Currency::minimum_balance());
+		InsuranceStatus {
+			balance,
+			debts: Self::total_insurance_debt(),
+			cap: T::InsuranceCap::get(),
+		}
+	}
+
+	/// The statistics of a juror added up over all sub-courts.
+	pub fn juror_stats(who: &T::AccountId) -> JurorStats<BalanceOf<T, I>> {
+		(0..=Self::sub_court_count())
+			.map(|sub_court| Self::juror_statistics(sub_court, who))
+			.fold(JurorStats::default(), JurorStats::saturating_add)
+	}
+
+	/// The jurors with statistics in `sub_court`, ranked by coherent votes then by votes cast,
+	/// at most `limit` of them from the `start`th.
+	pub fn leaderboard(
+		sub_court: SubCourtIndex,
+		start: u32,
+		limit: u32,
+	) -> Vec<(T::AccountId, JurorStats<BalanceOf<T, I>>)> {
+		let mut board: Vec<_> = JurorStatistics::<T, I>::iter_prefix(sub_court).collect();
+		board.sort_by(|(_, a), (_, b)| b.coherent.cmp(&a.coherent).then(b.votes.cmp(&a.votes)));
+		board.into_iter().skip(start as usize).take(limit as usize).collect()
+	}
+
+	/// The reasons for the ruling on a lawsuit with unbounded summaries, as the runtime API
+	/// returns them.
+	pub fn ruling_reasons(lawsuit_id: ProposalIndex) -> Vec<Justification<T::AccountId, Vec<u8>>> {
+		Self::reasons(lawsuit_id)
+			.into_iter()
+			.map(|j| Justification {
+				juror: j.juror,
+				approve: j.approve,
+				document: j.document,
+				summary: j.summary.into_inner(),
+			})
+			.collect()
+	}
+
+	/// Return the paid award of a lawsuit from the beneficiary to the payer, as far as the
+	/// beneficiary can pay it, and return the amount recovered.
+	///
+	/// The holdback is released to be clawed back first. The share covered by the insurance pool
+	/// goes back to the pool instead of the payer, as far as the payer still owes it, and the
+	/// debt of the payer is reduced accordingly.
+	fn claw_back(lawsuit: &LawsuitOf<T, I>) -> BalanceOf<T, I> {
+		let (payer, beneficiary, award) = match Self::net_award(lawsuit) {
+			Some(award) => award,
+			None => return Zero::zero(),
+		};
+		let taken = match lawsuit.asset {
+			None => {
+				T::Currency::unreserve(beneficiary, lawsuit.holdback);
+				let amount = award.min(T::Currency::free_balance(beneficiary));
+				let insured = amount.min(lawsuit.insured).min(Self::insurance_debts(payer));
+				with_transaction(|| {
+					let pool = T::Treasury::insurance_account();
+					let rest = amount.saturating_sub(insured);
+					let result = T::Currency::transfer(beneficiary, &pool, insured, AllowDeath)
+						.and_then(|_| T::Currency::transfer(beneficiary, payer, rest, AllowDeath));
+					match result {
+						Ok(()) => TransactionOutcome::Commit(Ok(())),
+						Err(error) => TransactionOutcome::Rollback(Err(error)),
+					}
+				})
+				.map(|_| {
+					Self::reduce_insurance_debt(payer, insured);
+					amount
+				})
+			},
+			Some(asset) => {
+				let reducible = <T::Assets as fungibles::Inspect<_>>::reducible_balance(
+					asset,
+					beneficiary,
+					false,
+				);
+				<T::Assets as fungibles::Transfer<_>>::transfer(
+					asset,
+					beneficiary,
+					payer,
+					award.min(reducible),
+					false,
+				)
+			},
+		};
+		taken.unwrap_or_else(|_| Zero::zero())
+	}
+
+	/// Reduce the debt of `debtor` to the insurance pool by `amount`, at most the debt.
+	fn reduce_insurance_debt(debtor: &T::AccountId, amount: BalanceOf<T, I>) {
+		let debt = InsuranceDebts::<T, I>::take(debtor);
+		let reduced = amount.min(debt);
+		if debt > reduced {
+			InsuranceDebts::<T, I>::insert(debtor, debt - reduced);
+		}
+		TotalInsuranceDebt::<T, I>::mutate(|d| *d = d.saturating_sub(reduced));
+	}
+
+	/// The indices of the lawsuits, archived ones excluded, whose metadata matches `filter`.
+	pub fn lawsuits(filter: LawsuitFilter<T::BlockNumber>) -> Vec<ProposalIndex> {
+		let mut lawsuits: Vec<_> = Proposals::<T, I>::iter()
+			.filter(|(_, lawsuit)| filter.matches(lawsuit.metadata.as_ref()))
+			.map(|(index, _)| index)
+			.collect();
+		lawsuits.sort();
+		lawsuits
+	}
+
 	pub fn contribution(beneficiary: T::AccountId) -> u32 {
-		Proposals::<T, I>::iter()
+		let live = Proposals::<T, I>::iter()
 			.filter(|(_, p)| p.approved && p.voters.contains(&beneficiary))
-			.count() as u32
+			.count() as u32;
+		Self::archived_contributions(&beneficiary).saturating_add(live)
+	}
+}
+
+impl<T: Config<I>, I: 'static> Arbitrator<T::AccountId> for Pallet<T, I> {
+	fn file_dispute(
+		plaintiff: &T::AccountId,
+		defendent: &T::AccountId,
+		statement: H256,
+	) -> Result<ProposalIndex, DispatchError> {
+		let terms = Self::agreement_terms(defendent, plaintiff)?;
+		let (lawsuit_id, _) = Self::do_submit_sue(
+			plaintiff.clone(),
+			Zero::zero(),
+			defendent.clone(),
+			statement,
+			0,
+			None,
+			None,
+			false,
+			false,
+			terms,
+			None,
+		)?;
+		Ok(lawsuit_id)
+	}
+
+	fn file_misconduct(
+		reporter: &T::AccountId,
+		juror: &T::AccountId,
+		statement: H256,
+	) -> Result<ProposalIndex, DispatchError> {
+		let (case_id, _) = Self::do_submit_sue(
+			reporter.clone(),
+			Zero::zero(),
+			juror.clone(),
+			statement,
+			0,
+			None,
+			None,
+			false,
+			false,
+			None,
+			None,
+		)?;
+		Ok(case_id)
+	}
+
+	fn file_weight() -> Weight {
+		Self::scan_weight()
+	}
+}
+
+impl<T: Config<I>, I: 'static> AppealCourt<T::AccountId, BalanceOf<T, I>, T::AssetId>
+	for Pallet<T, I>
+{
+	fn file_appeal(
+		appellant: &T::AccountId,
+		respondent: &T::AccountId,
+		statement: H256,
+		value: BalanceOf<T, I>,
+		asset: Option<T::AssetId>,
+		fiat: bool,
+		sub_court: SubCourtIndex,
+		funded: bool,
+	) -> Result<Option<ProposalIndex>, DispatchError> {
+		// Sub-courts are numbered per court, the general court rules on unknown ones.
+		let sub_court = if sub_court <= Self::sub_court_count() { sub_court } else { 0 };
+		// The appeal stays with the lower court while too few jurors could be drawn here.
+		if (Self::candidates(sub_court, &[appellant, respondent]).len() as u32) < T::JurySize::get()
+		{
+			return Ok(None)
+		}
+		let (case_id, _) = Self::do_submit_sue(
+			appellant.clone(),
+			value,
+			respondent.clone(),
+			statement,
+			sub_court,
+			asset,
+			None,
+			false,
+			fiat,
+			None,
+			Some(funded),
+		)?;
+		Ok(Some(case_id))
+	}
+
+	fn file_weight() -> Weight {
+		Self::scan_weight()
+	}
+}
+
+impl<T: Config<I>, I: 'static> OnRulingExecuted<T::AccountId> for Pallet<T, I> {
+	fn on_ruling_executed(
+		case_id: ProposalIndex,
+		_plaintiff: &T::AccountId,
+		_defendent: &T::AccountId,
+		approved: bool,
+	) -> Weight {
+		let mut weight = T::DbWeight::get().reads(2);
+		if let Some(case) = MisconductCases::<T, I>::take(case_id) {
+			Self::rule_misconduct(case_id, case, approved);
+			// The case, the juror, the accusations, both bonds, the treasury and the reopened
+			// lawsuit.
+			weight.saturating_accrue(T::DbWeight::get().reads_writes(8, 8));
+		}
+		if let Some((lawsuit_id, plaintiff_appealed, _)) = Escalations::<T, I>::take(case_id) {
+			// The appellant won the case in the higher court.
+			let approve = approved == plaintiff_appealed;
+			weight.saturating_accrue(Self::override_ruling(lawsuit_id, approve));
+		}
+		weight
+	}
+
+	fn on_lawsuit_withdrawn(
+		case_id: ProposalIndex,
+		plaintiff: &T::AccountId,
+		defendent: &T::AccountId,
+	) -> Weight {
+		// The reporter or appellant gave up the case, it is settled as lost.
+		Self::on_ruling_executed(case_id, plaintiff, defendent, false)
+	}
+
+	fn max_weight() -> Weight {
+		// A misconduct case drawing the jury of a reopened lawsuit and executing an overridden
+		// lawsuit. The hooks of the lawsuit are left out, as a court ruling on its own cases
+		// would recurse into itself, the actual weight is returned instead.
+		T::DbWeight::get()
+			.reads_writes(8 + 12, 8 + 12)
+			.saturating_add(Self::scan_weight())
+			.saturating_add(T::MaxRemedyWeight::get())
+	}
+}
+
+/// Storage migrations of the court.
+pub mod migrations {
+	use super::*;
+	use frame_support::{
+		pallet_prelude::StorageVersion,
+		traits::{GetStorageVersion, OnRuntimeUpgrade},
+	};
+
+	/// Migrate from the lawsuits of version 0, which kept the statement itself and no jury, to
+	/// version 1.
+	///
+	/// The lawsuits cannot be translated, so they are removed with the approvals to pay them
+	/// out. Version 0 took no deposits or fees, no funds are left reserved. The lawsuit count is
+	/// kept so that indices are not reused.
+	pub struct MigrateToV1<T, I = ()>(PhantomData<(T, I)>);
+
+	impl<T: Config<I>, I: 'static> OnRuntimeUpgrade for MigrateToV1<T, I> {
+		fn on_runtime_upgrade() -> Weight {
+			if Pallet::<T, I>::on_chain_storage_version() >= 1 {
+				return T::DbWeight::get().reads(1)
+			}
+			let removed = Proposals::<T, I>::clear(u32::MAX, None).unique;
+			Approvals::<T, I>::kill();
+			StorageVersion::new(1).put::<Pallet<T, I>>();
+			T::DbWeight::get().reads_writes(1, u64::from(removed).saturating_add(2))
+		}
 	}
 }
@@ -20,19 +20,25 @@
 use sp_core::H256;
 use sp_runtime::{
 	testing::Header,
-	traits::{BlakeTwo256, IdentityLookup},
+	traits::{BlakeTwo256, Hash, IdentityLookup},
 };
 
 use frame_support::{
 	assert_noop, assert_ok,
 	pallet_prelude::GenesisBuild,
 	parameter_types,
-	traits::{ConstU32, ConstU64, OnInitialize},
+	traits::{
+		AsEnsureOriginWithArg, ConstU32, ConstU64, Contains, EitherOfDiverse, GetStorageVersion,
+		OnIdle, OnInitialize, OnRuntimeUpgrade, Randomness, StorageVersion,
+	},
+	weights::{constants::RocksDbWeight, Weight},
 };
 
 use super::*;
 use crate as court;
 
+use frame_system::{EnsureRoot, EnsureSigned};
+
 type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
 type Block = frame_system::mocking::MockBlock<Test>;
 
@@ -44,8 +50,10 @@ frame_support::construct_runtime!(
 	{
 		System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
 		Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
-		Court: court::{Pallet, Call, Storage, Config, Event<T>},
+		Court: court::{Pallet, Call, Storage, Config, Event<T>, Origin<T>},
 		Utility: pallet_utility,
+		Preimage: pallet_preimage,
+		Assets: pallet_assets,
 	}
 );
 
@@ -53,7 +61,7 @@ impl frame_system::Config for Test {
 	type BaseCallFilter = frame_support::traits::Everything;
 	type BlockWeights = ();
 	type BlockLength = ();
-	type DbWeight = ();
+	type DbWeight = RocksDbWeight;
 	type RuntimeOrigin = RuntimeOrigin;
 	type Index = u64;
 	type BlockNumber = u64;
@@ -95,12 +103,255 @@ impl pallet_utility::Config for Test {
 	type WeightInfo = ();
 }
 
-parameter_types! {}
+impl pallet_preimage::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type WeightInfo = ();
+	type Currency = Balances;
+	type ManagerOrigin = EnsureRoot<u128>;
+	type BaseDeposit = ConstU64<2>;
+	type ByteDeposit = ConstU64<1>;
+}
+
+impl pallet_assets::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Balance = u64;
+	type AssetId = u32;
+	type AssetIdParameter = codec::Compact<u32>;
+	type Currency = Balances;
+	type CreateOrigin = AsEnsureOriginWithArg<EnsureSigned<u128>>;
+	type ForceOrigin = EnsureRoot<u128>;
+	type AssetDeposit = ConstU64<1>;
+	type AssetAccountDeposit = ConstU64<1>;
+	type MetadataDepositBase = ConstU64<1>;
+	type MetadataDepositPerByte = ConstU64<1>;
+	type ApprovalDeposit = ConstU64<1>;
+	type StringLimit = ConstU32<50>;
+	type Freezer = ();
+	type Extra = ();
+	type CallbackHandle = ();
+	type WeightInfo = ();
+	type RemoveItemsLimit = ConstU32<5>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = ();
+}
+
+pub struct TestRandomness;
+impl Randomness<H256, u64> for TestRandomness {
+	fn random(subject: &[u8]) -> (H256, u64) {
+		(H256::from(sp_io::hashing::blake2_256(subject)), System::block_number())
+	}
+}
+
+parameter_types! {
+	pub static MaxApprovals: u32 = 100;
+	pub static MaxDeferredApprovals: u32 = 100;
+	pub static MaxRemedyWeight: Weight = Weight::from_parts(1_000_000_000_000, 0);
+	pub static JurySize: u32 = 0;
+	pub const IncoherenceSlash: Perbill = Perbill::from_percent(10);
+	pub const ReputationDecay: Perbill = Perbill::from_percent(10);
+	pub const NoShowSlash: Perbill = Perbill::from_percent(10);
+	pub const MisconductSlash: Perbill = Perbill::from_percent(50);
+	pub static NativePrice: Option<FixedU128> = None;
+	pub static AwardHoldback: Perbill = Perbill::zero();
+	pub static InsuranceCap: u64 = 0;
+	pub static FiredHooks: Vec<Hook> = vec![];
+	pub static FilingFee: u64 = 0;
+	pub static AppealPeriod: u64 = 0;
+	pub static TreasuryShare: Perbill = Perbill::zero();
+	pub static TreasuryIncome: Vec<(u128, u64)> = vec![];
+	pub static CaseDeposit: u64 = 0;
+	pub static MaxOpenSuits: u32 = 100;
+	pub static MaxOpenSuitsPerDefendant: u32 = 100;
+	pub static RejectionCooldown: u64 = 0;
+	pub static Escalate: bool = false;
+	pub static EscalatedAppeals: Vec<(u128, u128, H256, u64, bool)> = vec![];
+}
+
+/// The account of the treasury pot.
+const TREASURY: u128 = 999;
+const INSURANCE: u128 = 998;
+
+pub struct TestTreasury;
+impl CourtTreasury<u128, u64> for TestTreasury {
+	fn account_id() -> u128 {
+		TREASURY
+	}
+
+	fn on_court_income(source: &u128, amount: u64) {
+		let mut income = TreasuryIncome::get();
+		income.push((*source, amount));
+		TreasuryIncome::set(&income);
+	}
+
+	fn insurance_account() -> u128 {
+		INSURANCE
+	}
+}
+
+/// Only the native currency has a price.
+pub struct TestPrices;
+impl PriceProvider<u32> for TestPrices {
+	fn price(asset: Option<u32>) -> Option<FixedU128> {
+		asset.map_or_else(NativePrice::get, |_| None)
+	}
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum Hook {
+	Filed(ProposalIndex, u128, u128),
+	Voted(ProposalIndex, u128, bool),
+	Ruled(ProposalIndex, bool),
+	Executed(ProposalIndex, bool),
+	Appealed(ProposalIndex, u128, u32),
+}
+
+const HOOK_WEIGHT: Weight = Weight::from_parts(1_000, 0);
+const HOOK_MAX_WEIGHT: Weight = Weight::from_parts(2_000, 0);
+
+/// Records the hooks fired into `FiredHooks`.
+pub struct RecordHooks;
+impl RecordHooks {
+	fn fire(hook: Hook) -> Weight {
+		FiredHooks::mutate(|hooks| hooks.push(hook));
+		HOOK_WEIGHT
+	}
+}
+impl OnLawsuitFiled<u128> for RecordHooks {
+	fn on_lawsuit_filed(lawsuit_id: ProposalIndex, plaintiff: &u128, defendent: &u128) -> Weight {
+		Self::fire(Hook::Filed(lawsuit_id, *plaintiff, *defendent))
+	}
+	fn max_weight() -> Weight {
+		HOOK_MAX_WEIGHT
+	}
+}
+impl OnVoteCast<u128> for RecordHooks {
+	fn on_vote_cast(lawsuit_id: ProposalIndex, voter: &u128, approve: bool) -> Weight {
+		Self::fire(Hook::Voted(lawsuit_id, *voter, approve))
+	}
+	fn max_weight() -> Weight {
+		HOOK_MAX_WEIGHT
+	}
+}
+impl OnRuled<u128> for RecordHooks {
+	fn on_ruled(lawsuit_id: ProposalIndex, approved: bool) -> Weight {
+		Self::fire(Hook::Ruled(lawsuit_id, approved))
+	}
+	fn max_weight() -> Weight {
+		HOOK_MAX_WEIGHT
+	}
+}
+impl OnRulingExecuted<u128> for RecordHooks {
+	fn on_ruling_executed(lawsuit_id: ProposalIndex, _: &u128, _: &u128, approved: bool) -> Weight {
+		Self::fire(Hook::Executed(lawsuit_id, approved))
+	}
+	fn max_weight() -> Weight {
+		HOOK_MAX_WEIGHT
+	}
+}
+impl OnAppealed<u128> for RecordHooks {
+	fn on_appealed(lawsuit_id: ProposalIndex, appellant: &u128, round: u32) -> Weight {
+		Self::fire(Hook::Appealed(lawsuit_id, *appellant, round))
+	}
+	fn max_weight() -> Weight {
+		HOOK_MAX_WEIGHT
+	}
+}
+
+/// Records the appeals escalated while `Escalate` is set, the cases are numbered from 100 in
+/// filing order.
+pub struct TestHigherCourt;
+impl AppealCourt<u128, u64, u32> for TestHigherCourt {
+	fn file_appeal(
+		appellant: &u128,
+		respondent: &u128,
+		statement: H256,
+		value: u64,
+		_: Option<u32>,
+		_: bool,
+		_: SubCourtIndex,
+		funded: bool,
+	) -> Result<Option<ProposalIndex>, DispatchError> {
+		if !Escalate::get() {
+			return Ok(None)
+		}
+		let case_id = 100 + EscalatedAppeals::get().len() as ProposalIndex;
+		EscalatedAppeals::mutate(|a| a.push((*appellant, *respondent, statement, value, funded)));
+		Ok(Some(case_id))
+	}
+}
+
+/// Remedies can call the balances and court pallets.
+pub struct RemedyFilter;
+impl Contains<RuntimeCall> for RemedyFilter {
+	fn contains(call: &RuntimeCall) -> bool {
+		matches!(call, RuntimeCall::Balances(_) | RuntimeCall::Court(_))
+	}
+}
 
 impl Config for Test {
 	type Currency = pallet_balances::Pallet<Test>;
 	type RuntimeEvent = RuntimeEvent;
-	type MaxApprovals = ConstU32<100>;
+	type MaxApprovals = MaxApprovals;
+	type MaxDeferredApprovals = MaxDeferredApprovals;
+	type MaxPayoutAttempts = ConstU32<3>;
+	type Randomness = TestRandomness;
+	type JurySize = JurySize;
+	type MaxJurors = ConstU32<10>;
+	type MaxDrawAttempts = ConstU32<2>;
+	type MinJurorBond = ConstU64<10>;
+	type UnbondingPeriod = ConstU64<10>;
+	type IncoherenceSlash = IncoherenceSlash;
+	type Preimages = Preimage;
+	type CourtAdminOrigin = EitherOfDiverse<EnsureRoot<u128>, EnsureRemedy<u128>>;
+	type InitialReputation = ConstU32<50>;
+	type MaxReputation = ConstU32<100>;
+	type ReputationGain = ConstU32<10>;
+	type ReputationLoss = ConstU32<20>;
+	type ReputationDecay = ReputationDecay;
+	type ReputationDecayPeriod = ConstU64<10>;
+	type AssetId = u32;
+	type Assets = Assets;
+	type Treasury = TestTreasury;
+	type FilingFee = FilingFee;
+	type AppealFee = ConstU64<10>;
+	type AppealPeriod = AppealPeriod;
+	type MaxAppeals = ConstU32<2>;
+	type TreasuryShare = TreasuryShare;
+	type CaseDeposit = CaseDeposit;
+	type RetentionPeriod = ConstU64<10>;
+	type MaxOpenSuits = MaxOpenSuits;
+	type MaxOpenSuitsPerDefendant = MaxOpenSuitsPerDefendant;
+	type RejectionCooldown = RejectionCooldown;
+	type MaxTags = ConstU32<4>;
+	type MaxTagLength = ConstU32<16>;
+	type MaxAmendments = ConstU32<2>;
+	type MediationFee = ConstU64<10>;
+	type MediationPeriod = ConstU64<5>;
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeCall = RuntimeCall;
+	type RemedyFilter = RemedyFilter;
+	type MaxRemedyLength = ConstU32<64>;
+	type MaxRemedyWeight = MaxRemedyWeight;
+	type VotingPeriod = ConstU64<5>;
+	type NoShowSlash = NoShowSlash;
+	type MaxRedraws = ConstU32<1>;
+	type MaxAbsences = ConstU32<2>;
+	type SuspensionPeriod = ConstU64<20>;
+	type Prices = TestPrices;
+	type OnLawsuitFiled = (RecordHooks, ());
+	type OnVoteCast = (RecordHooks, ());
+	type OnRuled = (RecordHooks, ());
+	type OnRulingExecuted = (RecordHooks, ());
+	type OnAppealed = (RecordHooks, ());
+	type AwardHoldback = AwardHoldback;
+	type InsuranceCap = InsuranceCap;
+	type MisconductCourt = Court;
+	type MisconductBond = ConstU64<20>;
+	type MisconductTimeout = ConstU64<10>;
+	type MisconductSlash = MisconductSlash;
+	type HigherCourt = TestHigherCourt;
+	type EscalationTimeout = ConstU64<10>;
+	type MaxSummaryLength = ConstU32<16>;
 }
 
 pub fn new_test_ext() -> sp_io::TestExternalities {
@@ -109,10 +360,33 @@ pub fn new_test_ext() -> sp_io::TestExternalities {
 	t.into()
 }
 
+/// Advance to the next block, making the jury draws committed to.
+fn next_block() {
+	let n = System::block_number() + 1;
+	System::set_block_number(n);
+	<Court as OnInitialize<u64>>::on_initialize(n);
+}
+
+/// Note `statement` without a deposit, returning its hash.
+fn note(statement: &[u8]) -> H256 {
+	assert_ok!(Preimage::note_preimage(RuntimeOrigin::root(), statement.to_vec()));
+	BlakeTwo256::hash(statement)
+}
+
 #[test]
 fn submite_sue_works() {
 	new_test_ext().execute_with(|| {
-		assert_ok!(Court::submit_sue(RuntimeOrigin::signed(1), 100, 0, vec![]));
+		assert_ok!(Court::submit_sue(
+			RuntimeOrigin::signed(1),
+			100,
+			0,
+			note(b""),
+			0,
+			None,
+			None,
+			false,
+			false
+		));
 		<Court as OnInitialize<u64>>::on_initialize(2);
 		assert_eq!(Court::approvals().len(), 0);
 		assert_eq!(Court::proposal_count(), 1);
@@ -122,7 +396,17 @@ fn submite_sue_works() {
 #[test]
 fn vote_sue_works() {
 	new_test_ext().execute_with(|| {
-		assert_ok!(Court::submit_sue(RuntimeOrigin::signed(1), 100, 0, vec![]));
+		assert_ok!(Court::submit_sue(
+			RuntimeOrigin::signed(1),
+			100,
+			0,
+			note(b""),
+			0,
+			None,
+			None,
+			false,
+			false
+		));
 		assert_ok!(Court::vote_sue(RuntimeOrigin::signed(2), 0, true));
 		assert_eq!(Court::approvals().len(), 0);
 		assert_eq!(Court::proposal_count(), 1);
@@ -133,7 +417,17 @@ fn vote_sue_works() {
 fn process_sue_works() {
 	new_test_ext().execute_with(|| {
 		Balances::make_free_balance_be(&0, 101);
-		assert_ok!(Court::submit_sue(RuntimeOrigin::signed(1), 100, 0, vec![]));
+		assert_ok!(Court::submit_sue(
+			RuntimeOrigin::signed(1),
+			100,
+			0,
+			note(b""),
+			0,
+			None,
+			None,
+			false,
+			false
+		));
 		assert_ok!(Court::vote_sue(RuntimeOrigin::signed(2), 0, true));
 		assert_ok!(Court::vote_sue(RuntimeOrigin::signed(3), 0, true));
 		assert_ok!(Court::vote_sue(RuntimeOrigin::signed(4), 0, true));
@@ -148,7 +442,17 @@ fn process_sue_works() {
 fn vote_against_works() {
 	new_test_ext().execute_with(|| {
 		Balances::make_free_balance_be(&0, 101);
-		assert_ok!(Court::submit_sue(RuntimeOrigin::signed(1), 100, 0, vec![]));
+		assert_ok!(Court::submit_sue(
+			RuntimeOrigin::signed(1),
+			100,
+			0,
+			note(b""),
+			0,
+			None,
+			None,
+			false,
+			false
+		));
 		assert_ok!(Court::vote_sue(RuntimeOrigin::signed(2), 0, false));
 		assert_ok!(Court::vote_sue(RuntimeOrigin::signed(3), 0, false));
 		assert_ok!(Court::vote_sue(RuntimeOrigin::signed(4), 0, true));
@@ -163,7 +467,17 @@ fn vote_against_works() {
 fn process_sue_before_vote() {
 	new_test_ext().execute_with(|| {
 		Balances::make_free_balance_be(&0, 101);
-		assert_ok!(Court::submit_sue(RuntimeOrigin::signed(1), 100, 0, vec![]));
+		assert_ok!(Court::submit_sue(
+			RuntimeOrigin::signed(1),
+			100,
+			0,
+			note(b""),
+			0,
+			None,
+			None,
+			false,
+			false
+		));
 		assert_noop!(
 			Court::process_sue(RuntimeOrigin::signed(6), 0),
 			Error::<Test, _>::VoterCountTooLow
@@ -177,7 +491,17 @@ fn process_sue_before_vote() {
 fn remove_unapproved_sue() {
 	new_test_ext().execute_with(|| {
 		Balances::make_free_balance_be(&0, 101);
-		assert_ok!(Court::submit_sue(RuntimeOrigin::signed(1), 100, 0, vec![]));
+		assert_ok!(Court::submit_sue(
+			RuntimeOrigin::signed(1),
+			100,
+			0,
+			note(b""),
+			0,
+			None,
+			None,
+			false,
+			false
+		));
 		assert_noop!(
 			Court::remove_sue(RuntimeOrigin::root(), 0),
 			Error::<Test, _>::ProposalNotApproved
@@ -189,13 +513,33 @@ fn remove_unapproved_sue() {
 fn remove_approved_sue() {
 	new_test_ext().execute_with(|| {
 		Balances::make_free_balance_be(&0, 101);
-		assert_ok!(Court::submit_sue(RuntimeOrigin::signed(1), 100, 0, vec![]));
+		assert_ok!(Court::submit_sue(
+			RuntimeOrigin::signed(1),
+			100,
+			0,
+			note(b""),
+			0,
+			None,
+			None,
+			false,
+			false
+		));
 		assert_ok!(Court::vote_sue(RuntimeOrigin::signed(2), 0, true));
 		assert_ok!(Court::vote_sue(RuntimeOrigin::signed(3), 0, true));
 		assert_ok!(Court::vote_sue(RuntimeOrigin::signed(4), 0, true));
 		assert_ok!(Court::vote_sue(RuntimeOrigin::signed(5), 0, true));
 		assert_ok!(Court::process_sue(RuntimeOrigin::signed(6), 0));
+		assert_noop!(Court::remove_sue(RuntimeOrigin::signed(1), 0), DispatchError::BadOrigin);
 		assert_ok!(Court::remove_sue(RuntimeOrigin::root(), 0));
+		assert!(Court::approvals().is_empty());
+
+		// The award is queued again on request.
+		assert_ok!(Court::retry_award(RuntimeOrigin::signed(1), 0));
+		assert_eq!(Court::approvals().into_inner(), vec![0]);
+		assert_noop!(
+			Court::retry_award(RuntimeOrigin::signed(1), 0),
+			Error::<Test, _>::AwardQueued
+		);
 	});
 }
 
@@ -203,7 +547,17 @@ fn remove_approved_sue() {
 fn contribution_works() {
 	new_test_ext().execute_with(|| {
 		Balances::make_free_balance_be(&0, 101);
-		assert_ok!(Court::submit_sue(RuntimeOrigin::signed(1), 100, 0, vec![]));
+		assert_ok!(Court::submit_sue(
+			RuntimeOrigin::signed(1),
+			100,
+			0,
+			note(b""),
+			0,
+			None,
+			None,
+			false,
+			false
+		));
 		assert_ok!(Court::vote_sue(RuntimeOrigin::signed(2), 0, true));
 		assert_ok!(Court::vote_sue(RuntimeOrigin::signed(3), 0, true));
 		assert_ok!(Court::vote_sue(RuntimeOrigin::signed(4), 0, true));
@@ -217,3 +571,2908 @@ fn contribution_works() {
 		assert_eq!(Court::contribution(11), 0);
 	});
 }
+
+fn approve_lawsuit(plaintiff: u128, defendent: u128, value: u64) -> ProposalIndex {
+	let lawsuit_id = Court::proposal_count();
+	assert_ok!(Court::submit_sue(
+		RuntimeOrigin::signed(plaintiff),
+		value,
+		defendent,
+		note(b""),
+		0,
+		None,
+		None,
+		false,
+		false
+	));
+	for voter in 2..6 {
+		assert_ok!(Court::vote_sue(RuntimeOrigin::signed(voter), lawsuit_id, true));
+	}
+	assert_ok!(Court::process_sue(RuntimeOrigin::signed(6), lawsuit_id));
+	lawsuit_id
+}
+
+#[test]
+fn approved_award_is_paid_by_hooks() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		Balances::make_free_balance_be(&0, 101);
+		approve_lawsuit(1, 0, 100);
+		// Nothing moves until the queue is processed.
+		assert_eq!(Balances::free_balance(1), 0);
+
+		<Court as OnIdle<u64>>::on_idle(1, Weight::MAX);
+		assert_eq!(Balances::free_balance(0), 1);
+		assert_eq!(Balances::free_balance(1), 100);
+		assert_eq!(Court::approvals().len(), 0);
+		assert!(Court::proposals(0).unwrap().paid);
+		System::assert_last_event(
+			Event::AwardPaid { lawsuit_id: 0, amount: 100, beneficiary: 1 }.into(),
+		);
+	});
+}
+
+#[test]
+fn failed_award_is_retried() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		approve_lawsuit(1, 0, 100);
+
+		<Court as OnIdle<u64>>::on_idle(1, Weight::MAX);
+		assert_eq!(Court::approvals().into_inner(), vec![0]);
+		assert!(!Court::proposals(0).unwrap().paid);
+
+		Balances::make_free_balance_be(&0, 101);
+		<Court as OnIdle<u64>>::on_idle(2, Weight::MAX);
+		assert_eq!(Court::approvals().len(), 0);
+		assert_eq!(Balances::free_balance(1), 100);
+	});
+}
+
+#[test]
+fn award_is_abandoned_after_max_attempts() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		MaxApprovals::set(&1);
+		MaxDeferredApprovals::set(&1);
+		approve_lawsuit(1, 0, 100);
+		approve_lawsuit(1, 0, 100);
+		// Neither queue has room left.
+		approve_lawsuit(1, 0, 100);
+		System::assert_has_event(Event::AwardAbandoned { lawsuit_id: 2 }.into());
+		assert_eq!(Court::deferred_approvals(), vec![1]);
+
+		for n in 1..3 {
+			<Court as OnIdle<u64>>::on_idle(n, Weight::MAX);
+			assert_eq!(Court::payout_attempts(0), n as u32);
+		}
+		<Court as OnIdle<u64>>::on_idle(3, Weight::MAX);
+		System::assert_has_event(Event::AwardAbandoned { lawsuit_id: 0 }.into());
+		assert_eq!(Court::payout_attempts(0), 0);
+		assert_eq!(Court::approvals().into_inner(), vec![1]);
+		assert!(!Court::proposals(0).unwrap().paid);
+
+		assert_noop!(
+			Court::retry_award(RuntimeOrigin::signed(1), 0),
+			Error::<Test, _>::TooManyApprovals
+		);
+		Balances::make_free_balance_be(&0, 201);
+		<Court as OnIdle<u64>>::on_idle(4, Weight::MAX);
+		assert_ok!(Court::retry_award(RuntimeOrigin::signed(1), 0));
+		<Court as OnIdle<u64>>::on_idle(5, Weight::MAX);
+		assert!(Court::proposals(0).unwrap().paid);
+	});
+}
+
+#[test]
+fn award_batches_are_limited_by_weight() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&0, 301);
+		for _ in 0..3 {
+			approve_lawsuit(1, 0, 100);
+		}
+
+		let used = <Court as OnIdle<u64>>::on_idle(1, Court::payout_weight(2));
+		assert_eq!(used, Court::payout_weight(2));
+		assert_eq!(Court::approvals().into_inner(), vec![2]);
+		assert_eq!(Balances::free_balance(1), 200);
+		// Not even a single award fits.
+		assert_eq!(<Court as OnIdle<u64>>::on_idle(2, Weight::zero()), Weight::zero());
+		assert_eq!(Court::approvals().len(), 1);
+	});
+}
+
+#[test]
+fn full_payout_queue_defers_approvals() {
+	new_test_ext().execute_with(|| {
+		MaxApprovals::set(&1);
+		Balances::make_free_balance_be(&0, 201);
+		approve_lawsuit(1, 0, 100);
+		// The ruling still succeeds when the queue is full.
+		approve_lawsuit(1, 0, 100);
+		assert!(Court::proposals(1).unwrap().approved);
+		assert_eq!(Court::approvals().into_inner(), vec![0]);
+		assert_eq!(Court::deferred_approvals(), vec![1]);
+
+		<Court as OnIdle<u64>>::on_idle(1, Weight::MAX);
+		assert_eq!(Court::approvals().into_inner(), vec![1]);
+		assert!(Court::deferred_approvals().is_empty());
+
+		<Court as OnIdle<u64>>::on_idle(2, Weight::MAX);
+		assert_eq!(Court::approvals().len(), 0);
+		assert_eq!(Balances::free_balance(1), 200);
+	});
+}
+
+#[test]
+fn join_jury_and_delegate_works() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&10, 1000);
+		Balances::make_free_balance_be(&20, 1000);
+		assert_noop!(
+			Court::join_jury(RuntimeOrigin::signed(10), 9, Perbill::zero()),
+			Error::<Test, _>::BondTooLow
+		);
+		assert_noop!(
+			Court::delegate(RuntimeOrigin::signed(20), 10, 100),
+			Error::<Test, _>::NotJuror
+		);
+		assert_ok!(Court::join_jury(RuntimeOrigin::signed(10), 100, Perbill::from_percent(5)));
+		assert_ok!(Court::delegate(RuntimeOrigin::signed(20), 10, 300));
+
+		let juror = Court::jurors(10).unwrap();
+		assert_eq!(juror.weight(), 400);
+		assert_eq!(Balances::reserved_balance(10), 100);
+		assert_eq!(Balances::reserved_balance(20), 300);
+		assert_eq!(Court::delegations(10, 20), 300);
+	});
+}
+
+#[test]
+fn undelegated_stake_unbonds() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		Balances::make_free_balance_be(&10, 1000);
+		Balances::make_free_balance_be(&20, 1000);
+		assert_ok!(Court::join_jury(RuntimeOrigin::signed(10), 100, Perbill::zero()));
+		assert_ok!(Court::delegate(RuntimeOrigin::signed(20), 10, 300));
+
+		assert_noop!(
+			Court::undelegate(RuntimeOrigin::signed(20), 10, 301),
+			Error::<Test, _>::InsufficientDelegation
+		);
+		assert_ok!(Court::undelegate(RuntimeOrigin::signed(20), 10, 200));
+		assert_eq!(Court::delegations(10, 20), 100);
+		assert_eq!(Court::jurors(10).unwrap().delegated, 100);
+		// Still reserved during the unbonding period.
+		assert_eq!(Balances::reserved_balance(20), 300);
+		assert_noop!(
+			Court::withdraw_unbonded(RuntimeOrigin::signed(20)),
+			Error::<Test, _>::NothingToWithdraw
+		);
+
+		System::set_block_number(11);
+		assert_ok!(Court::withdraw_unbonded(RuntimeOrigin::signed(20)));
+		assert_eq!(Balances::reserved_balance(20), 100);
+		assert!(Court::unbonding(20).is_empty());
+
+		// Leaving the jury unbonds the remaining delegations too.
+		assert_ok!(Court::leave_jury(RuntimeOrigin::signed(10)));
+		assert_eq!(Court::delegations(10, 20), 0);
+		System::set_block_number(21);
+		assert_ok!(Court::withdraw_unbonded(RuntimeOrigin::signed(10)));
+		assert_ok!(Court::withdraw_unbonded(RuntimeOrigin::signed(20)));
+		assert_eq!(Balances::reserved_balance(10), 0);
+		assert_eq!(Balances::reserved_balance(20), 0);
+	});
+}
+
+#[test]
+fn only_drawn_jurors_can_vote() {
+	new_test_ext().execute_with(|| {
+		JurySize::set(&4);
+		for juror in 10..15 {
+			Balances::make_free_balance_be(&juror, 1000);
+			assert_ok!(Court::join_jury(RuntimeOrigin::signed(juror), 100, Perbill::zero()));
+		}
+		// The defendant is never drawn into its own jury.
+		assert_ok!(Court::submit_sue(
+			RuntimeOrigin::signed(1),
+			100,
+			10,
+			note(b""),
+			0,
+			None,
+			None,
+			false,
+			false
+		));
+		// The jury is drawn at the next block.
+		assert!(Court::proposals(0).unwrap().jurors.is_empty());
+		assert_noop!(
+			Court::vote_sue(RuntimeOrigin::signed(2), 0, true),
+			Error::<Test, _>::DrawPending
+		);
+		next_block();
+
+		let jurors = Court::proposals(0).unwrap().jurors;
+		assert_eq!(jurors.len(), 4);
+		assert!(!jurors.contains(&10));
+		assert_noop!(
+			Court::vote_sue(RuntimeOrigin::signed(2), 0, true),
+			Error::<Test, _>::NotDrawn
+		);
+		assert_ok!(Court::vote_sue(RuntimeOrigin::signed(jurors[0]), 0, true));
+
+		JurySize::set(&5);
+		let statement = note(b"");
+		assert_noop!(
+			Court::submit_sue(
+				RuntimeOrigin::signed(1),
+				100,
+				10,
+				statement,
+				0,
+				None,
+				None,
+				false,
+				false
+			),
+			Error::<Test, _>::NotEnoughJurors
+		);
+	});
+}
+
+#[test]
+fn incoherent_stake_is_shared_with_coherent_jurors() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		for who in 10..16 {
+			Balances::make_free_balance_be(&who, 1000);
+		}
+		assert_ok!(Court::join_jury(RuntimeOrigin::signed(10), 100, Perbill::from_percent(20)));
+		for juror in 11..14 {
+			assert_ok!(Court::join_jury(RuntimeOrigin::signed(juror), 100, Perbill::zero()));
+		}
+		assert_ok!(Court::delegate(RuntimeOrigin::signed(14), 13, 100));
+		assert_ok!(Court::delegate(RuntimeOrigin::signed(15), 10, 100));
+
+		assert_ok!(Court::submit_sue(
+			RuntimeOrigin::signed(1),
+			100,
+			0,
+			note(b""),
+			0,
+			None,
+			None,
+			false,
+			false
+		));
+		for juror in 10..13 {
+			assert_ok!(Court::vote_sue(RuntimeOrigin::signed(juror), 0, true));
+		}
+		assert_ok!(Court::vote_sue(RuntimeOrigin::signed(13), 0, false));
+		assert_ok!(Court::process_sue(RuntimeOrigin::signed(6), 0));
+
+		// Juror 13 and its delegator lose 10% each.
+		assert_eq!(Court::jurors(13).unwrap().weight(), 180);
+		assert_eq!(Court::delegations(13, 14), 90);
+		assert_eq!(Balances::reserved_balance(13), 90);
+		assert_eq!(Balances::reserved_balance(14), 90);
+		System::assert_has_event(
+			Event::JurorSlashed { lawsuit_id: 0, juror: 13, amount: 20 }.into(),
+		);
+
+		// Juror 10 has half of the coherent weight, and keeps 20% of its reward as commission.
+		System::assert_has_event(
+			Event::JurorRewarded { lawsuit_id: 0, juror: 10, amount: 10 }.into(),
+		);
+		assert_eq!(Balances::free_balance(10), 906);
+		assert_eq!(Balances::free_balance(15), 904);
+		assert_eq!(Balances::free_balance(11), 905);
+		assert_eq!(Balances::free_balance(12), 905);
+
+		// A ruling is only made, and settled, once.
+		assert_noop!(
+			Court::process_sue(RuntimeOrigin::signed(6), 0),
+			Error::<Test, _>::ProposalAlreadyApproved
+		);
+
+		// Once reversed, the jurors who approved are slashed in turn and juror 13 shares the
+		// slashes with its delegator.
+		assert_ok!(Court::reverse_ruling(RuntimeOrigin::root(), 0, H256::zero()));
+		assert_eq!(Court::jurors(10).unwrap().weight(), 180);
+		assert_eq!(Court::jurors(11).unwrap().weight(), 90);
+		System::assert_has_event(
+			Event::JurorSlashed { lawsuit_id: 0, juror: 10, amount: 20 }.into(),
+		);
+		System::assert_has_event(
+			Event::JurorRewarded { lawsuit_id: 0, juror: 13, amount: 40 }.into(),
+		);
+		assert_eq!(Balances::free_balance(13), 920);
+		assert_eq!(Balances::free_balance(14), 920);
+	});
+}
+
+#[test]
+fn rejected_lawsuit_is_closed() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Court::submit_sue(
+			RuntimeOrigin::signed(1),
+			100,
+			0,
+			note(b""),
+			0,
+			None,
+			None,
+			false,
+			false
+		));
+		for voter in 2..6 {
+			assert_ok!(Court::vote_sue(RuntimeOrigin::signed(voter), 0, false));
+		}
+		assert_ok!(Court::process_sue(RuntimeOrigin::signed(6), 0));
+		assert!(Court::proposals(0).unwrap().closed);
+
+		assert_noop!(
+			Court::process_sue(RuntimeOrigin::signed(6), 0),
+			Error::<Test, _>::LawsuitClosed
+		);
+		assert_noop!(
+			Court::vote_sue(RuntimeOrigin::signed(7), 0, true),
+			Error::<Test, _>::LawsuitClosed
+		);
+	});
+}
+
+#[test]
+fn submit_sue_requires_noted_statement() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Court::submit_sue(
+				RuntimeOrigin::signed(1),
+				100,
+				0,
+				BlakeTwo256::hash(b"unknown"),
+				0,
+				None,
+				None,
+				false,
+				false
+			),
+			Error::<Test, _>::StatementNotNoted
+		);
+	});
+}
+
+#[test]
+fn large_statement_deposit_is_released_after_archival() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		Balances::make_free_balance_be(&1, 10_000);
+		let statement = vec![1u8; 4096];
+		let hash = BlakeTwo256::hash(&statement);
+		assert_ok!(Preimage::note_preimage(RuntimeOrigin::signed(1), statement));
+		assert_eq!(Balances::reserved_balance(1), 2 + 4096);
+
+		assert_ok!(Court::submit_sue(
+			RuntimeOrigin::signed(1),
+			100,
+			0,
+			hash,
+			0,
+			None,
+			None,
+			false,
+			false
+		));
+		assert_eq!(Court::proposals(0).unwrap().statement, hash);
+		assert!(<Preimage as QueryPreimage>::is_requested(&hash));
+
+		for voter in 2..6 {
+			assert_ok!(Court::vote_sue(RuntimeOrigin::signed(voter), 0, false));
+		}
+		assert_ok!(Court::process_sue(RuntimeOrigin::signed(6), 0));
+		assert!(<Preimage as QueryPreimage>::is_requested(&hash));
+		<Court as OnIdle<u64>>::on_idle(11, Weight::MAX);
+		assert!(!<Preimage as QueryPreimage>::is_requested(&hash));
+
+		assert_ok!(Preimage::unnote_preimage(RuntimeOrigin::signed(1), hash));
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(<Preimage as QueryPreimage>::len(&hash), None);
+	});
+}
+
+#[test]
+fn reputation_follows_votes_and_decays() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		for juror in 10..14 {
+			Balances::make_free_balance_be(&juror, 1000);
+			assert_ok!(Court::join_jury(RuntimeOrigin::signed(juror), 100, Perbill::zero()));
+		}
+		assert_eq!(Court::reputation(&10), 50);
+
+		assert_ok!(Court::submit_sue(
+			RuntimeOrigin::signed(1),
+			100,
+			0,
+			note(b""),
+			0,
+			None,
+			None,
+			false,
+			false
+		));
+		for juror in 10..13 {
+			assert_ok!(Court::vote_sue(RuntimeOrigin::signed(juror), 0, true));
+		}
+		assert_ok!(Court::vote_sue(RuntimeOrigin::signed(13), 0, false));
+		assert_ok!(Court::process_sue(RuntimeOrigin::signed(6), 0));
+		assert_eq!(Court::reputation(&10), 60);
+		assert_eq!(Court::reputation(&13), 30);
+		System::assert_has_event(Event::ReputationUpdated { juror: 13, score: 30 }.into());
+
+		// 10% is lost every 10 blocks.
+		System::set_block_number(11);
+		assert_eq!(Court::reputation(&10), 54);
+		assert_eq!(Court::reputation(&13), 27);
+		// Voters that are not jurors have no reputation.
+		assert_eq!(Court::reputation(&6), 0);
+	});
+}
+
+#[test]
+fn missed_votes_lower_reputation() {
+	new_test_ext().execute_with(|| {
+		JurySize::set(&5);
+		for juror in 10..15 {
+			Balances::make_free_balance_be(&juror, 1000);
+			assert_ok!(Court::join_jury(RuntimeOrigin::signed(juror), 100, Perbill::zero()));
+		}
+		assert_ok!(Court::submit_sue(
+			RuntimeOrigin::signed(1),
+			100,
+			0,
+			note(b""),
+			0,
+			None,
+			None,
+			false,
+			false
+		));
+		next_block();
+		for juror in 10..14 {
+			assert_ok!(Court::vote_sue(RuntimeOrigin::signed(juror), 0, true));
+		}
+		// Juror 14 can still vote until the end of the voting period.
+		assert_noop!(
+			Court::process_sue(RuntimeOrigin::signed(6), 0),
+			Error::<Test, _>::VotingPeriodNotOver
+		);
+		System::set_block_number(8);
+		assert_ok!(Court::process_sue(RuntimeOrigin::signed(6), 0));
+		assert_eq!(Court::reputation(&13), 60);
+		assert_eq!(Court::reputation(&14), 30);
+		assert_eq!(Balances::reserved_balance(14), 90);
+	});
+}
+
+#[test]
+fn sub_court_requires_min_reputation() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Court::create_sub_court(RuntimeOrigin::signed(1), 55),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert_ok!(Court::create_sub_court(RuntimeOrigin::root(), 55));
+		assert_eq!(Court::sub_courts(1).min_reputation, 55);
+
+		JurySize::set(&2);
+		for juror in 10..14 {
+			Balances::make_free_balance_be(&juror, 1000);
+			assert_ok!(Court::join_jury(RuntimeOrigin::signed(juror), 100, Perbill::zero()));
+		}
+		for juror in [10, 11] {
+			Reputations::<Test>::insert(juror, Reputation { score: 60, updated_at: 0 });
+		}
+		assert_ok!(Court::submit_sue(
+			RuntimeOrigin::signed(1),
+			100,
+			0,
+			note(b""),
+			1,
+			None,
+			None,
+			false,
+			false
+		));
+		next_block();
+		let mut jurors = Court::proposals(0).unwrap().jurors;
+		jurors.sort();
+		assert_eq!(jurors, vec![10, 11]);
+
+		JurySize::set(&3);
+		let statement = note(b"");
+		assert_noop!(
+			Court::submit_sue(
+				RuntimeOrigin::signed(1),
+				100,
+				0,
+				statement,
+				1,
+				None,
+				None,
+				false,
+				false
+			),
+			Error::<Test, _>::NotEnoughJurors
+		);
+		assert_noop!(
+			Court::submit_sue(
+				RuntimeOrigin::signed(1),
+				100,
+				0,
+				statement,
+				2,
+				None,
+				None,
+				false,
+				false
+			),
+			Error::<Test, _>::InvalidSubCourt
+		);
+		// The general court has no requirement.
+		assert_ok!(Court::submit_sue(
+			RuntimeOrigin::signed(1),
+			100,
+			0,
+			statement,
+			0,
+			None,
+			None,
+			false,
+			false
+		));
+	});
+}
+
+#[test]
+fn jury_draws_are_abandoned_after_max_draw_attempts() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		for juror in 10..20 {
+			Balances::make_free_balance_be(&juror, 1000);
+			assert_ok!(Court::join_jury(RuntimeOrigin::signed(juror), 100, Perbill::zero()));
+		}
+		Balances::make_free_balance_be(&20, 1000);
+		assert_noop!(
+			Court::join_jury(RuntimeOrigin::signed(20), 100, Perbill::zero()),
+			Error::<Test, _>::TooManyJurors
+		);
+		for juror in 12..20 {
+			assert_ok!(Court::leave_jury(RuntimeOrigin::signed(juror)));
+		}
+		assert_eq!(Court::juror_count(), 2);
+
+		JurySize::set(&2);
+		assert_ok!(Court::submit_sue(
+			RuntimeOrigin::signed(1),
+			100,
+			0,
+			note(b""),
+			0,
+			None,
+			None,
+			false,
+			false
+		));
+		assert_noop!(
+			Court::retry_draw(RuntimeOrigin::signed(3), 0),
+			Error::<Test, _>::DrawNotAbandoned
+		);
+		assert_ok!(Court::leave_jury(RuntimeOrigin::signed(11)));
+		next_block();
+		System::assert_last_event(Event::JuryDrawDelayed { lawsuit_id: 0 }.into());
+		next_block();
+		System::assert_last_event(Event::JuryDrawAbandoned { lawsuit_id: 0 }.into());
+		next_block();
+		assert!(Court::proposals(0).unwrap().jurors.is_empty());
+		assert_eq!(Court::pending_draws(0).unwrap().attempts, 2);
+
+		assert_noop!(
+			Court::retry_draw(RuntimeOrigin::signed(3), 0),
+			Error::<Test, _>::NotEnoughJurors
+		);
+		assert_ok!(Court::join_jury(RuntimeOrigin::signed(11), 100, Perbill::zero()));
+		assert_ok!(Court::retry_draw(RuntimeOrigin::signed(3), 0));
+		next_block();
+		let mut jurors = Court::proposals(0).unwrap().jurors;
+		jurors.sort();
+		assert_eq!(jurors, vec![10, 11]);
+		assert!(Court::pending_draws(0).is_none());
+	});
+}
+
+#[test]
+fn fees_and_slashes_go_to_treasury() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		FilingFee::set(&5);
+		TreasuryShare::set(&Perbill::from_percent(50));
+		Balances::make_free_balance_be(&1, 100);
+		for juror in 10..14 {
+			Balances::make_free_balance_be(&juror, 1000);
+			assert_ok!(Court::join_jury(RuntimeOrigin::signed(juror), 100, Perbill::zero()));
+		}
+
+		assert_ok!(Court::submit_sue(
+			RuntimeOrigin::signed(1),
+			100,
+			0,
+			note(b""),
+			0,
+			None,
+			None,
+			false,
+			false
+		));
+		assert_eq!(Balances::free_balance(1), 95);
+		System::assert_has_event(Event::FeePaid { lawsuit_id: 0, payer: 1, amount: 5 }.into());
+
+		for juror in 10..13 {
+			assert_ok!(Court::vote_sue(RuntimeOrigin::signed(juror), 0, true));
+		}
+		assert_ok!(Court::vote_sue(RuntimeOrigin::signed(13), 0, false));
+		assert_ok!(Court::process_sue(RuntimeOrigin::signed(6), 0));
+
+		// Half of the 10 slashed from juror 13 goes to the treasury.
+		assert_eq!(Balances::free_balance(TREASURY), 10);
+		assert_eq!(TreasuryIncome::get(), vec![(1, 5), (13, 5)]);
+
+		// Without funds for the fee nothing can be filed.
+		let statement = note(b"");
+		assert_noop!(
+			Court::submit_sue(
+				RuntimeOrigin::signed(2),
+				100,
+				0,
+				statement,
+				0,
+				None,
+				None,
+				false,
+				false
+			),
+			pallet_balances::Error::<Test, _>::InsufficientBalance
+		);
+	});
+}
+
+#[test]
+fn losing_party_can_appeal_until_ruling_is_executed() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		AppealPeriod::set(&5);
+		Balances::make_free_balance_be(&0, 200);
+		Balances::make_free_balance_be(&1, 100);
+		assert_ok!(Court::submit_sue(
+			RuntimeOrigin::signed(1),
+			50,
+			0,
+			note(b""),
+			0,
+			None,
+			None,
+			false,
+			false
+		));
+		for voter in 2..6 {
+			assert_ok!(Court::vote_sue(RuntimeOrigin::signed(voter), 0, false));
+		}
+		assert_ok!(Court::process_sue(RuntimeOrigin::signed(6), 0));
+
+		// The ruling waits for the appeal period.
+		assert_noop!(
+			Court::execute_ruling(RuntimeOrigin::signed(6), 0),
+			Error::<Test, _>::AppealPeriodNotOver
+		);
+		assert_noop!(Court::appeal(RuntimeOrigin::signed(0), 0), Error::<Test, _>::NotLosingParty);
+		assert_ok!(Court::appeal(RuntimeOrigin::signed(1), 0));
+		System::assert_has_event(Event::Appealed { lawsuit_id: 0, appellant: 1, round: 1 }.into());
+		assert_eq!(Balances::free_balance(TREASURY), 10);
+		let lawsuit = Court::proposals(0).unwrap();
+		assert!(lawsuit.voters.is_empty() && !lawsuit.closed);
+		assert_noop!(Court::appeal(RuntimeOrigin::signed(1), 0), Error::<Test, _>::NotRuled);
+
+		// The defendant appeals the new ruling for twice the fee.
+		for voter in 2..6 {
+			assert_ok!(Court::vote_sue(RuntimeOrigin::signed(voter), 0, true));
+		}
+		assert_ok!(Court::process_sue(RuntimeOrigin::signed(6), 0));
+		assert_ok!(Court::appeal(RuntimeOrigin::signed(0), 0));
+		assert_eq!(Balances::free_balance(TREASURY), 30);
+		assert_eq!(Court::appeal_fee(2), 40);
+
+		for voter in 2..6 {
+			assert_ok!(Court::vote_sue(RuntimeOrigin::signed(voter), 0, true));
+		}
+		assert_ok!(Court::process_sue(RuntimeOrigin::signed(6), 0));
+		assert_noop!(Court::appeal(RuntimeOrigin::signed(0), 0), Error::<Test, _>::TooManyAppeals);
+
+		System::set_block_number(7);
+		assert_noop!(
+			Court::appeal(RuntimeOrigin::signed(0), 0),
+			Error::<Test, _>::AppealPeriodOver
+		);
+		assert_ok!(Court::execute_ruling(RuntimeOrigin::signed(6), 0));
+		System::assert_has_event(Event::RulingExecuted { lawsuit_id: 0, approve: true }.into());
+		assert_eq!(Court::approvals().into_inner(), vec![0]);
+		assert_noop!(
+			Court::execute_ruling(RuntimeOrigin::signed(6), 0),
+			Error::<Test, _>::RulingExecuted
+		);
+	});
+}
+
+#[test]
+fn awards_can_be_paid_in_assets() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), 7.into(), 1, true, 1));
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 7.into(), 0, 70));
+		Balances::make_free_balance_be(&0, 31);
+
+		let statement = note(b"");
+		assert_noop!(
+			Court::submit_sue(
+				RuntimeOrigin::signed(1),
+				50,
+				0,
+				statement,
+				0,
+				Some(8),
+				None,
+				false,
+				false
+			),
+			Error::<Test, _>::UnknownAsset
+		);
+		// One award in the asset, one in the native currency and one the defendant cannot pay.
+		for (value, asset) in [(50, Some(7)), (30, None), (50, Some(7))] {
+			let lawsuit_id = Court::proposal_count();
+			assert_ok!(Court::submit_sue(
+				RuntimeOrigin::signed(1),
+				value,
+				0,
+				statement,
+				0,
+				asset,
+				None,
+				false,
+				false
+			));
+			for voter in 2..6 {
+				assert_ok!(Court::vote_sue(RuntimeOrigin::signed(voter), lawsuit_id, true));
+			}
+			assert_ok!(Court::process_sue(RuntimeOrigin::signed(6), lawsuit_id));
+		}
+		assert_eq!(Court::proposals(0).unwrap().asset, Some(7));
+
+		<Court as OnIdle<u64>>::on_idle(1, Weight::MAX);
+		assert_eq!(Assets::balance(7, 1), 50);
+		assert_eq!(Assets::balance(7, 0), 20);
+		assert_eq!(Balances::free_balance(1), 30);
+		assert_eq!(Balances::free_balance(0), 1);
+		assert!(Court::proposals(0).unwrap().paid && Court::proposals(1).unwrap().paid);
+		assert_eq!(Court::approvals().into_inner(), vec![2]);
+
+		// The third award is paid once the defendant holds enough of the asset.
+		assert_ok!(Assets::mint(RuntimeOrigin::signed(1), 7.into(), 0, 31));
+		<Court as OnIdle<u64>>::on_idle(1, Weight::MAX);
+		assert_eq!(Assets::balance(7, 1), 100);
+		assert_eq!(Court::approvals().len(), 0);
+	});
+}
+
+#[test]
+fn executed_lawsuits_are_archived_after_retention() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		CaseDeposit::set(&5);
+		Balances::make_free_balance_be(&0, 101);
+		Balances::make_free_balance_be(&1, 10);
+		let approved = approve_lawsuit(1, 0, 50);
+		assert_eq!(Balances::reserved_balance(1), 5);
+		// The second award cannot be paid yet.
+		let unpaid = approve_lawsuit(1, 0, 100);
+		<Court as OnIdle<u64>>::on_idle(1, Weight::MAX);
+		assert_eq!(Court::archive_queue(), vec![(approved, 11), (unpaid, 11)]);
+
+		// Nothing is archived before the retention period is over.
+		<Court as OnIdle<u64>>::on_idle(10, Weight::MAX);
+		assert!(Court::proposals(approved).is_some());
+
+		System::set_block_number(11);
+		<Court as OnIdle<u64>>::on_idle(11, Weight::MAX);
+		assert!(Court::proposals(approved).is_none());
+		assert_eq!(
+			Court::archive(approved),
+			Some(ArchivedLawsuit {
+				plaintiff: 1,
+				defendent: 0,
+				approved: true,
+				value: 50,
+				asset: None,
+				ruled_at: 1,
+			})
+		);
+		System::assert_has_event(
+			Event::LawsuitArchived { lawsuit_id: approved, deposit: 5 }.into(),
+		);
+		assert_eq!(Balances::reserved_balance(1), 5);
+		// The voters keep their contribution.
+		assert_eq!(Court::archived_contributions(2), 1);
+		assert_eq!(Court::contribution(2), 2);
+
+		// The unpaid lawsuit waits for its award.
+		assert_eq!(Court::archive_queue(), vec![(unpaid, 21)]);
+		assert!(Court::proposals(unpaid).is_some());
+	});
+}
+
+#[test]
+fn archiving_is_limited_by_weight() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		Balances::make_free_balance_be(&0, 1000);
+		for _ in 0..2 {
+			approve_lawsuit(1, 0, 10);
+		}
+		<Court as OnIdle<u64>>::on_idle(1, Weight::MAX);
+
+		// Checking the empty payout queue, then the archive queue and one lawsuit.
+		let db = <Test as frame_system::Config>::DbWeight::get();
+		let limit = db.reads(2) + db.reads_writes(1, 1) + Court::archive_weight(4, 1);
+		assert_eq!(<Court as OnIdle<u64>>::on_idle(11, limit), limit);
+		assert!(Court::proposals(0).is_none());
+		assert!(Court::proposals(1).is_some());
+
+		<Court as OnIdle<u64>>::on_idle(11, Weight::MAX);
+		assert!(Court::proposals(1).is_none());
+		assert_eq!(Court::contribution(2), 2);
+	});
+}
+
+#[test]
+fn open_suits_are_limited() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		MaxOpenSuits::set(&3);
+		MaxOpenSuitsPerDefendant::set(&2);
+		Balances::make_free_balance_be(&0, 1000);
+		let statement = note(b"");
+		for _ in 0..2 {
+			assert_ok!(Court::submit_sue(
+				RuntimeOrigin::signed(1),
+				10,
+				0,
+				statement,
+				0,
+				None,
+				None,
+				false,
+				false
+			));
+		}
+		assert_noop!(
+			Court::submit_sue(
+				RuntimeOrigin::signed(1),
+				10,
+				0,
+				statement,
+				0,
+				None,
+				None,
+				false,
+				false
+			),
+			Error::<Test, _>::TooManySuitsAgainstDefendant
+		);
+		assert_ok!(Court::submit_sue(
+			RuntimeOrigin::signed(1),
+			10,
+			7,
+			statement,
+			0,
+			None,
+			None,
+			false,
+			false
+		));
+		assert_noop!(
+			Court::submit_sue(
+				RuntimeOrigin::signed(1),
+				10,
+				8,
+				statement,
+				0,
+				None,
+				None,
+				false,
+				false
+			),
+			Error::<Test, _>::TooManyOpenSuits
+		);
+
+		// An executed ruling frees a slot.
+		for voter in 2..6 {
+			assert_ok!(Court::vote_sue(RuntimeOrigin::signed(voter), 0, true));
+		}
+		assert_ok!(Court::process_sue(RuntimeOrigin::signed(6), 0));
+		assert_eq!(Court::open_suits(1), 2);
+		assert_ok!(Court::submit_sue(
+			RuntimeOrigin::signed(1),
+			10,
+			0,
+			statement,
+			0,
+			None,
+			None,
+			false,
+			false
+		));
+
+		// Exempt accounts are not limited.
+		assert_noop!(
+			Court::set_exemption(RuntimeOrigin::signed(1), 1, true),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert_ok!(Court::set_exemption(RuntimeOrigin::root(), 1, true));
+		System::assert_last_event(Event::ExemptionSet { who: 1, exempt: true }.into());
+		assert_ok!(Court::submit_sue(
+			RuntimeOrigin::signed(1),
+			10,
+			0,
+			statement,
+			0,
+			None,
+			None,
+			false,
+			false
+		));
+	});
+}
+
+#[test]
+fn withdrawn_suits_free_their_slot_and_jury() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		MaxOpenSuits::set(&1);
+		JurySize::set(&2);
+		CaseDeposit::set(&5);
+		Balances::make_free_balance_be(&1, 100);
+		for juror in 10..12 {
+			Balances::make_free_balance_be(&juror, 1000);
+			assert_ok!(Court::join_jury(RuntimeOrigin::signed(juror), 100, Perbill::zero()));
+		}
+		note(b"");
+		assert_ok!(sue(1, 0, 100));
+		assert_noop!(sue(1, 0, 100), Error::<Test, _>::TooManyOpenSuits);
+
+		// The draw of a withdrawn lawsuit is dropped.
+		assert_noop!(
+			Court::withdraw_sue(RuntimeOrigin::signed(0), 0),
+			Error::<Test, _>::NotPlaintiff
+		);
+		assert_ok!(Court::withdraw_sue(RuntimeOrigin::signed(1), 0));
+		System::assert_has_event(Event::LawsuitWithdrawn { lawsuit_id: 0 }.into());
+		System::assert_has_event(Event::RulingExecuted { lawsuit_id: 0, approve: false }.into());
+		assert!(Court::pending_draws(0).is_none());
+		assert_eq!(Court::open_suits(1), 0);
+		assert_noop!(
+			Court::withdraw_sue(RuntimeOrigin::signed(1), 0),
+			Error::<Test, _>::LawsuitClosed
+		);
+
+		// The jury of a withdrawn lawsuit is released.
+		assert_ok!(sue(1, 0, 100));
+		next_block();
+		assert_eq!(Court::drawn_into(10), 1);
+		assert_ok!(Court::withdraw_sue(RuntimeOrigin::signed(1), 1));
+		assert_eq!(Court::drawn_into(10), 0);
+		assert_ok!(Court::leave_jury(RuntimeOrigin::signed(10)));
+		assert_eq!(Court::open_suits(1), 0);
+		assert_eq!(Balances::reserved_balance(1), 10);
+
+		// The deposits are refunded once the lawsuits are archived.
+		<Court as OnIdle<u64>>::on_idle(12, Weight::MAX);
+		assert_eq!(Balances::reserved_balance(1), 0);
+	});
+}
+
+#[test]
+fn rejected_suit_starts_cooldown() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		RejectionCooldown::set(&5);
+		let statement = note(b"");
+		assert_ok!(Court::submit_sue(
+			RuntimeOrigin::signed(1),
+			10,
+			0,
+			statement,
+			0,
+			None,
+			None,
+			false,
+			false
+		));
+		for voter in 2..6 {
+			assert_ok!(Court::vote_sue(RuntimeOrigin::signed(voter), 0, false));
+		}
+		assert_ok!(Court::process_sue(RuntimeOrigin::signed(6), 0));
+		assert_eq!(Court::cooldowns(1), Some(6));
+
+		assert_noop!(
+			Court::submit_sue(
+				RuntimeOrigin::signed(1),
+				10,
+				0,
+				statement,
+				0,
+				None,
+				None,
+				false,
+				false
+			),
+			Error::<Test, _>::InRejectionCooldown
+		);
+		// Other plaintiffs are not affected.
+		assert_ok!(Court::submit_sue(
+			RuntimeOrigin::signed(2),
+			10,
+			0,
+			statement,
+			0,
+			None,
+			None,
+			false,
+			false
+		));
+
+		System::set_block_number(6);
+		assert_ok!(Court::submit_sue(
+			RuntimeOrigin::signed(1),
+			10,
+			0,
+			statement,
+			0,
+			None,
+			None,
+			false,
+			false
+		));
+	});
+}
+
+fn case_metadata(
+	kind: DisputeKind,
+	subject: OracleSubject,
+	from: u64,
+	to: u64,
+) -> CaseMetadataOf<Test> {
+	let tags = BoundedVec::truncate_from(vec![BoundedVec::truncate_from(b"eth-usd".to_vec())]);
+	CaseMetadata { kind, subject, from, to, tags }
+}
+
+#[test]
+fn lawsuits_can_be_filtered_by_metadata() {
+	new_test_ext().execute_with(|| {
+		let statement = note(b"");
+		let invalid = case_metadata(DisputeKind::Latency, OracleSubject::Feed(1), 10, 5);
+		assert_noop!(
+			Court::submit_sue(
+				RuntimeOrigin::signed(1),
+				10,
+				0,
+				statement,
+				0,
+				None,
+				Some(invalid),
+				false,
+				false
+			),
+			Error::<Test, _>::InvalidMetadata
+		);
+		// Tags are bounded when the call is decoded.
+		assert!(CaseTagsOf::<Test>::decode(&mut &vec![vec![0u8; 17]].encode()[..]).is_err());
+		assert!(CaseTagsOf::<Test>::decode(&mut &vec![vec![0u8]; 5].encode()[..]).is_err());
+
+		for metadata in [
+			Some(case_metadata(DisputeKind::Latency, OracleSubject::Feed(1), 5, 10)),
+			Some(case_metadata(DisputeKind::DataAccuracy, OracleSubject::Feed(1), 20, 30)),
+			Some(case_metadata(DisputeKind::Latency, OracleSubject::Request(7), 8, 12)),
+			None,
+		] {
+			assert_ok!(Court::submit_sue(
+				RuntimeOrigin::signed(1),
+				10,
+				0,
+				statement,
+				0,
+				None,
+				metadata,
+				false,
+				false
+			));
+		}
+
+		assert_eq!(Court::lawsuits(LawsuitFilter::default()), vec![0, 1, 2, 3]);
+		let latency = LawsuitFilter { kind: Some(DisputeKind::Latency), ..Default::default() };
+		assert_eq!(Court::lawsuits(latency.clone()), vec![0, 2]);
+		let feed = LawsuitFilter { subject: Some(OracleSubject::Feed(1)), ..latency };
+		assert_eq!(Court::lawsuits(feed), vec![0]);
+		let during = LawsuitFilter { during: Some((11, 25)), ..Default::default() };
+		assert_eq!(Court::lawsuits(during), vec![1, 2]);
+		let tag = LawsuitFilter { tag: Some(b"eth-usd".to_vec()), ..Default::default() };
+		assert_eq!(Court::lawsuits(tag), vec![0, 1, 2]);
+	});
+}
+
+#[test]
+fn parties_can_settle_through_mediation() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		Balances::make_free_balance_be(&0, 200);
+		Balances::make_free_balance_be(&1, 100);
+		assert_noop!(
+			Court::add_mediator(RuntimeOrigin::signed(1), 20),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert_ok!(Court::add_mediator(RuntimeOrigin::root(), 20));
+
+		let statement = note(b"");
+		assert_ok!(Court::submit_sue(
+			RuntimeOrigin::signed(1),
+			100,
+			0,
+			statement,
+			0,
+			None,
+			None,
+			true,
+			false
+		));
+		System::assert_has_event(Event::MediationStarted { lawsuit_id: 0, mediator: 20 }.into());
+		assert_eq!(Balances::reserved_balance(1), 10);
+		assert_noop!(
+			Court::vote_sue(RuntimeOrigin::signed(2), 0, true),
+			Error::<Test, _>::InMediation
+		);
+		assert_noop!(
+			Court::accept_resolution(RuntimeOrigin::signed(1), 0),
+			Error::<Test, _>::NoResolution
+		);
+
+		let terms = H256::repeat_byte(1);
+		assert_noop!(
+			Court::propose_resolution(RuntimeOrigin::signed(2), 0, 40, terms),
+			Error::<Test, _>::NotMediator
+		);
+		assert_ok!(Court::propose_resolution(RuntimeOrigin::signed(20), 0, 40, terms));
+		assert_noop!(
+			Court::accept_resolution(RuntimeOrigin::signed(7), 0),
+			Error::<Test, _>::NotParty
+		);
+		assert_ok!(Court::accept_resolution(RuntimeOrigin::signed(1), 0));
+		assert!(!Court::proposals(0).unwrap().closed);
+		// The accepted resolution cannot be changed by a remedy.
+		let remedy = Box::new(RuntimeCall::Court(Call::set_exemption { who: 1, exempt: true }));
+		assert_noop!(
+			Court::attach_remedy(RuntimeOrigin::signed(1), 0, remedy),
+			Error::<Test, _>::InMediation
+		);
+		// Nor can the statement it was accepted on.
+		let amended = note(b"amended");
+		assert_noop!(
+			Court::amend_statement(RuntimeOrigin::signed(1), 0, amended),
+			Error::<Test, _>::ResolutionAccepted
+		);
+		assert_ok!(Court::accept_resolution(RuntimeOrigin::signed(0), 0));
+		System::assert_has_event(Event::Mediated { lawsuit_id: 0, award: 40 }.into());
+
+		// The mediator earns the fee and the award is paid like any other.
+		System::assert_has_event(
+			Event::MediatorPaid { lawsuit_id: 0, mediator: 20, fee: 10 }.into(),
+		);
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(Balances::free_balance(20), 10);
+		<Court as OnIdle<u64>>::on_idle(1, Weight::MAX);
+		assert_eq!(Balances::free_balance(1), 130);
+		assert_eq!(Balances::free_balance(0), 160);
+		assert_noop!(Court::appeal(RuntimeOrigin::signed(0), 0), Error::<Test, _>::RulingExecuted);
+	});
+}
+
+#[test]
+fn settling_for_nothing_does_not_start_cooldown() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		RejectionCooldown::set(&5);
+		Balances::make_free_balance_be(&1, 100);
+		assert_ok!(Court::add_mediator(RuntimeOrigin::root(), 20));
+		assert_ok!(Court::submit_sue(
+			RuntimeOrigin::signed(1),
+			100,
+			0,
+			note(b""),
+			0,
+			None,
+			None,
+			true,
+			false
+		));
+		assert_ok!(Court::propose_resolution(
+			RuntimeOrigin::signed(20),
+			0,
+			0,
+			H256::repeat_byte(1)
+		));
+		assert_ok!(Court::accept_resolution(RuntimeOrigin::signed(1), 0));
+		assert_ok!(Court::accept_resolution(RuntimeOrigin::signed(0), 0));
+
+		System::assert_has_event(Event::RulingExecuted { lawsuit_id: 0, approve: false }.into());
+		assert_eq!(Court::cooldowns(1), None);
+		assert_ok!(sue(1, 0, 100));
+	});
+}
+
+#[test]
+fn failed_mediation_goes_to_jury() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		Balances::make_free_balance_be(&1, 100);
+		Balances::make_free_balance_be(&20, 100);
+		let statement = note(b"");
+		assert_noop!(
+			Court::submit_sue(
+				RuntimeOrigin::signed(1),
+				100,
+				0,
+				statement,
+				0,
+				None,
+				None,
+				true,
+				false
+			),
+			Error::<Test, _>::NoMediator
+		);
+		assert_ok!(Court::add_mediator(RuntimeOrigin::root(), 20));
+		// Mediators are never assigned to their own lawsuits.
+		assert_noop!(
+			Court::submit_sue(
+				RuntimeOrigin::signed(20),
+				100,
+				0,
+				statement,
+				0,
+				None,
+				None,
+				true,
+				false
+			),
+			Error::<Test, _>::NoMediator
+		);
+
+		assert_ok!(Court::submit_sue(
+			RuntimeOrigin::signed(1),
+			100,
+			0,
+			statement,
+			0,
+			None,
+			None,
+			true,
+			false
+		));
+		let terms = H256::repeat_byte(1);
+		assert_ok!(Court::propose_resolution(RuntimeOrigin::signed(20), 0, 40, terms));
+		assert_noop!(
+			Court::end_mediation(RuntimeOrigin::signed(6), 0),
+			Error::<Test, _>::MediationPeriodNotOver
+		);
+
+		System::set_block_number(7);
+		assert_noop!(
+			Court::accept_resolution(RuntimeOrigin::signed(1), 0),
+			Error::<Test, _>::MediationPeriodOver
+		);
+		assert_ok!(Court::end_mediation(RuntimeOrigin::signed(6), 0));
+		System::assert_has_event(Event::MediationFailed { lawsuit_id: 0 }.into());
+		assert_eq!(Balances::reserved_balance(1), 0);
+		// The proposal stays in the case file.
+		let mediation = Court::proposals(0).unwrap().mediation.unwrap();
+		assert_eq!(mediation.proposal, Some(Resolution { award: 40, terms }));
+		assert_noop!(
+			Court::end_mediation(RuntimeOrigin::signed(1), 0),
+			Error::<Test, _>::NotInMediation
+		);
+
+		for voter in 2..6 {
+			assert_ok!(Court::vote_sue(RuntimeOrigin::signed(voter), 0, true));
+		}
+		assert_ok!(Court::process_sue(RuntimeOrigin::signed(6), 0));
+		assert!(Court::proposals(0).unwrap().approved);
+	});
+}
+
+#[test]
+fn remedy_is_dispatched_on_approval() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		Balances::make_free_balance_be(&0, 101);
+		assert_ok!(Court::submit_sue(
+			RuntimeOrigin::signed(1),
+			100,
+			0,
+			note(b""),
+			0,
+			None,
+			None,
+			false,
+			false
+		));
+		let remedy = Box::new(RuntimeCall::Court(Call::set_exemption { who: 3, exempt: true }));
+		assert_noop!(
+			Court::attach_remedy(RuntimeOrigin::signed(0), 0, remedy.clone()),
+			Error::<Test, _>::NotPlaintiff
+		);
+		assert_noop!(
+			Court::attach_remedy(
+				RuntimeOrigin::signed(1),
+				0,
+				Box::new(RuntimeCall::System(frame_system::Call::remark { remark: vec![] }))
+			),
+			Error::<Test, _>::RemedyNotAllowed
+		);
+		assert_ok!(Court::attach_remedy(RuntimeOrigin::signed(1), 0, remedy));
+		System::assert_last_event(Event::RemedyAttached { lawsuit_id: 0 }.into());
+
+		for voter in 2..6 {
+			assert_ok!(Court::vote_sue(RuntimeOrigin::signed(voter), 0, true));
+		}
+		assert_ok!(Court::process_sue(RuntimeOrigin::signed(6), 0));
+		System::assert_has_event(Event::RemedyExecuted { lawsuit_id: 0, result: Ok(()) }.into());
+		assert!(Court::exempt(3));
+		assert!(Court::remedies(0).is_none());
+	});
+}
+
+#[test]
+fn remedy_failures_are_reported() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		Balances::make_free_balance_be(&0, 101);
+		assert_ok!(Court::submit_sue(
+			RuntimeOrigin::signed(1),
+			100,
+			0,
+			note(b""),
+			0,
+			None,
+			None,
+			false,
+			false
+		));
+		// The court origin is not root.
+		let remedy = Box::new(RuntimeCall::Balances(pallet_balances::Call::force_transfer {
+			source: 0,
+			dest: 1,
+			value: 1,
+		}));
+		MaxRemedyWeight::set(&Weight::zero());
+		assert_noop!(
+			Court::attach_remedy(RuntimeOrigin::signed(1), 0, remedy.clone()),
+			Error::<Test, _>::RemedyTooHeavy
+		);
+		MaxRemedyWeight::set(&Weight::MAX);
+		assert_ok!(Court::attach_remedy(RuntimeOrigin::signed(1), 0, remedy));
+		assert_ok!(Court::vote_sue(RuntimeOrigin::signed(2), 0, true));
+		let remedy = Box::new(RuntimeCall::Court(Call::set_exemption { who: 3, exempt: true }));
+		assert_noop!(
+			Court::attach_remedy(RuntimeOrigin::signed(1), 0, remedy.clone()),
+			Error::<Test, _>::VotingStarted
+		);
+		for voter in 3..6 {
+			assert_ok!(Court::vote_sue(RuntimeOrigin::signed(voter), 0, true));
+		}
+		assert_ok!(Court::process_sue(RuntimeOrigin::signed(6), 0));
+		System::assert_has_event(
+			Event::RemedyExecuted { lawsuit_id: 0, result: Err(DispatchError::BadOrigin) }.into(),
+		);
+		assert_eq!(Balances::free_balance(0), 101);
+
+		// The remedy of a rejected lawsuit is dropped.
+		assert_ok!(Court::submit_sue(
+			RuntimeOrigin::signed(1),
+			100,
+			0,
+			note(b"1"),
+			0,
+			None,
+			None,
+			false,
+			false
+		));
+		assert_ok!(Court::attach_remedy(RuntimeOrigin::signed(1), 1, remedy));
+		for voter in 2..6 {
+			assert_ok!(Court::vote_sue(RuntimeOrigin::signed(voter), 1, false));
+		}
+		assert_ok!(Court::process_sue(RuntimeOrigin::signed(6), 1));
+		assert!(Court::remedies(1).is_none());
+		assert!(!Court::exempt(3));
+	});
+}
+
+#[test]
+fn absent_jurors_are_replaced() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		JurySize::set(&2);
+		for juror in 10..14 {
+			Balances::make_free_balance_be(&juror, 1000);
+			assert_ok!(Court::join_jury(RuntimeOrigin::signed(juror), 100, Perbill::zero()));
+		}
+		assert_ok!(Court::submit_sue(
+			RuntimeOrigin::signed(1),
+			100,
+			0,
+			note(b""),
+			0,
+			None,
+			None,
+			false,
+			false
+		));
+		next_block();
+		let jurors = Court::proposals(0).unwrap().jurors;
+		assert_eq!(Court::proposals(0).unwrap().voting_ends, Some(7));
+		assert_ok!(Court::vote_sue(RuntimeOrigin::signed(jurors[0]), 0, true));
+
+		System::set_block_number(7);
+		assert_noop!(
+			Court::replace_absentees(RuntimeOrigin::signed(6), 0),
+			Error::<Test, _>::VotingPeriodNotOver
+		);
+		System::set_block_number(8);
+		// No juror is left to replace the absent one.
+		let others: Vec<_> = (10..14).filter(|j| !jurors.contains(j)).collect();
+		for juror in others.iter() {
+			assert_ok!(Court::leave_jury(RuntimeOrigin::signed(*juror)));
+		}
+		assert_noop!(
+			Court::replace_absentees(RuntimeOrigin::signed(6), 0),
+			Error::<Test, _>::NotEnoughJurors
+		);
+		assert_ok!(Court::join_jury(RuntimeOrigin::signed(others[0]), 100, Perbill::zero()));
+		assert_ok!(Court::replace_absentees(RuntimeOrigin::signed(6), 0));
+		// The absent juror loses 10% of its stake to the treasury.
+		let absent = jurors[1];
+		assert_eq!(Balances::reserved_balance(absent), 90);
+		assert_eq!(Balances::free_balance(TREASURY), 10);
+		assert_eq!(Court::absences(absent), 1);
+		assert_eq!(Court::proposals(0).unwrap().jurors, vec![jurors[0]]);
+		assert_noop!(
+			Court::replace_absentees(RuntimeOrigin::signed(6), 0),
+			Error::<Test, _>::DrawPending
+		);
+
+		// The replacement is drawn at the next block.
+		next_block();
+		let lawsuit = Court::proposals(0).unwrap();
+		assert_eq!(lawsuit.jurors.len(), 2);
+		assert_eq!(lawsuit.jurors[0], jurors[0]);
+		let replacement = lawsuit.jurors[1];
+		assert_eq!(replacement, others[0]);
+		assert_eq!(lawsuit.voting_ends, Some(14));
+		assert_eq!(lawsuit.redraws, 1);
+		System::assert_last_event(
+			Event::JurorsReplaced {
+				lawsuit_id: 0,
+				absent: vec![absent],
+				replacements: vec![replacement],
+			}
+			.into(),
+		);
+
+		// The stake of the jurors is locked until the jury is settled, replaced jurors are
+		// released.
+		assert_eq!(Court::drawn_into(jurors[0]), 1);
+		assert_noop!(
+			Court::leave_jury(RuntimeOrigin::signed(jurors[0])),
+			Error::<Test, _>::JurorDrawn
+		);
+		assert_noop!(
+			Court::undelegate(RuntimeOrigin::signed(1), jurors[0], 1),
+			Error::<Test, _>::JurorDrawn
+		);
+		assert_ok!(Court::leave_jury(RuntimeOrigin::signed(absent)));
+
+		// The redraws are used up, the lawsuit is ruled on with the votes present.
+		System::set_block_number(15);
+		assert_noop!(
+			Court::process_sue(RuntimeOrigin::signed(6), 0),
+			Error::<Test, _>::VoterCountTooLow
+		);
+		assert_ok!(Court::replace_absentees(RuntimeOrigin::signed(6), 0));
+		assert_eq!(Balances::reserved_balance(replacement), 90);
+		System::assert_has_event(
+			Event::JurorsReplaced {
+				lawsuit_id: 0,
+				absent: vec![replacement],
+				replacements: vec![],
+			}
+			.into(),
+		);
+		let lawsuit = Court::proposals(0).unwrap();
+		assert!(lawsuit.closed && lawsuit.approved);
+		assert_eq!(lawsuit.jurors, vec![jurors[0]]);
+		assert_eq!(Court::drawn_into(jurors[0]), 0);
+		assert_eq!(Court::drawn_into(replacement), 0);
+		assert_ok!(Court::leave_jury(RuntimeOrigin::signed(jurors[0])));
+	});
+}
+
+#[test]
+fn repeat_absentees_are_suspended() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		JurySize::set(&5);
+		for juror in 10..15 {
+			Balances::make_free_balance_be(&juror, 1000);
+			assert_ok!(Court::join_jury(RuntimeOrigin::signed(juror), 100, Perbill::zero()));
+		}
+		for (lawsuit_id, statement) in [b"0", b"1"].iter().enumerate() {
+			let lawsuit_id = lawsuit_id as u32;
+			assert_ok!(Court::submit_sue(
+				RuntimeOrigin::signed(1),
+				100,
+				0,
+				note(*statement),
+				0,
+				None,
+				None,
+				false,
+				false
+			));
+			next_block();
+			for juror in 10..14 {
+				assert_ok!(Court::vote_sue(RuntimeOrigin::signed(juror), lawsuit_id, true));
+			}
+			// Juror 14 is penalized when the ruling is made after the voting period.
+			System::set_block_number(System::block_number() + 6);
+			assert_ok!(Court::process_sue(RuntimeOrigin::signed(6), lawsuit_id));
+		}
+		assert_eq!(Balances::reserved_balance(14), 81);
+		assert_eq!(Court::absences(14), 0);
+		assert_eq!(Court::suspensions(14), Some(35));
+		System::assert_has_event(Event::JurorSuspended { juror: 14, until: 35 }.into());
+
+		// Only four jurors can be drawn while juror 14 is suspended.
+		let statement = note(b"2");
+		assert_noop!(
+			Court::submit_sue(
+				RuntimeOrigin::signed(1),
+				100,
+				0,
+				statement,
+				0,
+				None,
+				None,
+				false,
+				false
+			),
+			Error::<Test, _>::NotEnoughJurors
+		);
+		System::set_block_number(35);
+		assert_ok!(Court::submit_sue(
+			RuntimeOrigin::signed(1),
+			100,
+			0,
+			statement,
+			0,
+			None,
+			None,
+			false,
+			false
+		));
+	});
+}
+
+#[test]
+fn fiat_claims_are_fixed_at_ruling() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		Balances::make_free_balance_be(&0, 101);
+		let statement = note(b"");
+		assert_noop!(
+			Court::submit_sue(
+				RuntimeOrigin::signed(1),
+				100,
+				0,
+				statement,
+				0,
+				None,
+				None,
+				false,
+				true
+			),
+			Error::<Test, _>::NoPrice
+		);
+		NativePrice::set(&Some(FixedU128::saturating_from_integer(2)));
+		assert_ok!(Court::submit_sue(
+			RuntimeOrigin::signed(1),
+			100,
+			0,
+			statement,
+			0,
+			None,
+			None,
+			false,
+			true
+		));
+		let lawsuit = Court::proposals(0).unwrap();
+		assert_eq!(lawsuit.claim, Some(100));
+		assert_eq!(lawsuit.value, 0);
+		for voter in 2..6 {
+			assert_ok!(Court::vote_sue(RuntimeOrigin::signed(voter), 0, true));
+		}
+
+		// The ruling upholding the claim cannot be made without a price.
+		NativePrice::set(&None);
+		assert_noop!(Court::process_sue(RuntimeOrigin::signed(6), 0), Error::<Test, _>::NoPrice);
+		// The price at ruling time is used.
+		NativePrice::set(&Some(FixedU128::from_rational(5, 2)));
+		assert_ok!(Court::process_sue(RuntimeOrigin::signed(6), 0));
+		assert_eq!(Court::proposals(0).unwrap().value, 40);
+		System::assert_has_event(Event::ValueFixed { lawsuit_id: 0, claim: 100, value: 40 }.into());
+
+		<Court as OnIdle<u64>>::on_idle(1, Weight::MAX);
+		assert_eq!(Balances::free_balance(1), 40);
+
+		// A rejected claim is not converted.
+		assert_ok!(Court::submit_sue(
+			RuntimeOrigin::signed(1),
+			100,
+			0,
+			statement,
+			0,
+			None,
+			None,
+			false,
+			true
+		));
+		for voter in 2..6 {
+			assert_ok!(Court::vote_sue(RuntimeOrigin::signed(voter), 1, false));
+		}
+		NativePrice::set(&None);
+		assert_ok!(Court::process_sue(RuntimeOrigin::signed(6), 1));
+		assert_eq!(Court::proposals(1).unwrap().value, 0);
+	});
+}
+
+#[test]
+fn fiat_value_rounds_down() {
+	new_test_ext().execute_with(|| {
+		NativePrice::set(&Some(FixedU128::saturating_from_integer(3)));
+		assert_eq!(Court::fiat_value(100, None), Some(33));
+		assert_eq!(Court::fiat_value(100, Some(1)), None);
+		NativePrice::set(&Some(FixedU128::zero()));
+		assert_eq!(Court::fiat_value(100, None), None);
+	});
+}
+
+#[test]
+fn lifecycle_hooks_fire_in_order() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		AppealPeriod::set(&5);
+		Balances::make_free_balance_be(&1, 100);
+		let info = Court::submit_sue(
+			RuntimeOrigin::signed(1),
+			50,
+			0,
+			note(b""),
+			0,
+			None,
+			None,
+			false,
+			false,
+		)
+		.unwrap();
+		assert_eq!(info.actual_weight, Some(HOOK_WEIGHT));
+		for voter in 2..6 {
+			assert_ok!(Court::vote_sue(RuntimeOrigin::signed(voter), 0, false));
+		}
+		assert_ok!(Court::process_sue(RuntimeOrigin::signed(6), 0));
+		assert_ok!(Court::appeal(RuntimeOrigin::signed(1), 0));
+		for voter in 2..6 {
+			assert_ok!(Court::vote_sue(RuntimeOrigin::signed(voter), 0, true));
+		}
+		assert_ok!(Court::process_sue(RuntimeOrigin::signed(6), 0));
+		System::set_block_number(7);
+		assert_ok!(Court::execute_ruling(RuntimeOrigin::signed(6), 0));
+
+		let mut expected = vec![Hook::Filed(0, 1, 0)];
+		expected.extend((2..6).map(|voter| Hook::Voted(0, voter, false)));
+		expected.extend([Hook::Ruled(0, false), Hook::Appealed(0, 1, 1)]);
+		expected.extend((2..6).map(|voter| Hook::Voted(0, voter, true)));
+		expected.extend([Hook::Ruled(0, true), Hook::Executed(0, true)]);
+		assert_eq!(FiredHooks::get(), expected);
+	});
+}
+
+#[test]
+fn hook_weight_is_charged_upfront_and_refunded() {
+	new_test_ext().execute_with(|| {
+		let call = Call::<Test>::process_sue { lawsuit_id: 0 };
+		assert_eq!(call.get_dispatch_info().weight, HOOK_MAX_WEIGHT * 2 + MaxRemedyWeight::get());
+		let call = Call::<Test>::vote_sue { lawsuit_id: 0, approve: true };
+		assert_eq!(call.get_dispatch_info().weight, HOOK_MAX_WEIGHT);
+
+		assert_ok!(Court::submit_sue(
+			RuntimeOrigin::signed(1),
+			50,
+			0,
+			note(b""),
+			0,
+			None,
+			None,
+			false,
+			false
+		));
+		for voter in 2..6 {
+			assert_ok!(Court::vote_sue(RuntimeOrigin::signed(voter), 0, true));
+		}
+		// The ruling is executed straight away, both hooks fire.
+		let info = Court::process_sue(RuntimeOrigin::signed(6), 0).unwrap();
+		assert_eq!(info.actual_weight, Some(HOOK_WEIGHT * 2));
+	});
+}
+
+#[test]
+fn paid_award_is_clawed_back_on_reversal() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		AwardHoldback::set(&Perbill::from_percent(10));
+		Balances::make_free_balance_be(&0, 101);
+		approve_lawsuit(1, 0, 100);
+		<Court as OnIdle<u64>>::on_idle(1, Weight::MAX);
+		assert_eq!(Balances::free_balance(1), 90);
+		assert_eq!(Balances::reserved_balance(1), 10);
+		assert_eq!(Court::contribution(2), 1);
+
+		// The plaintiff spent part of the award.
+		Balances::make_free_balance_be(&1, 40);
+		let reason = H256::repeat_byte(1);
+		assert_noop!(
+			Court::reverse_ruling(RuntimeOrigin::signed(0), 0, reason),
+			DispatchError::BadOrigin
+		);
+		assert_ok!(Court::reverse_ruling(RuntimeOrigin::root(), 0, reason));
+		assert_eq!(Balances::free_balance(0), 51);
+		assert_eq!(Balances::total_balance(&1), 0);
+		System::assert_has_event(
+			Event::AwardClawedBack { lawsuit_id: 0, amount: 50, shortfall: 50 }.into(),
+		);
+		System::assert_last_event(Event::RulingReversed { lawsuit_id: 0, reason }.into());
+
+		let lawsuit = Court::proposals(0).unwrap();
+		assert!(!lawsuit.approved);
+		assert_eq!(lawsuit.reversal, Some(Reversal { reason, reversed_at: 1 }));
+		assert_eq!(Court::contribution(2), 0);
+		assert_noop!(
+			Court::reverse_ruling(RuntimeOrigin::root(), 0, reason),
+			Error::<Test, _>::NotReversible
+		);
+	});
+}
+
+#[test]
+fn unpaid_award_is_cancelled_on_reversal() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		approve_lawsuit(1, 0, 100);
+		<Court as OnIdle<u64>>::on_idle(1, Weight::MAX);
+		assert_eq!(Court::approvals().into_inner(), vec![0]);
+
+		assert_ok!(Court::reverse_ruling(RuntimeOrigin::root(), 0, H256::zero()));
+		assert_eq!(Court::approvals().len(), 0);
+		System::assert_has_event(Event::AwardCancelled { lawsuit_id: 0 }.into());
+
+		// The award is not paid once the defendant can afford it.
+		Balances::make_free_balance_be(&0, 101);
+		<Court as OnIdle<u64>>::on_idle(2, Weight::MAX);
+		assert_eq!(Balances::free_balance(1), 0);
+		assert!(!Court::proposals(0).unwrap().paid);
+	});
+}
+
+#[test]
+fn counterclaim_is_netted_against_the_award() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		Balances::make_free_balance_be(&0, 101);
+		assert_ok!(Court::submit_sue(
+			RuntimeOrigin::signed(1),
+			100,
+			0,
+			note(b""),
+			0,
+			None,
+			None,
+			false,
+			false
+		));
+		let statement = note(b"unpaid oracle fees");
+		assert_noop!(
+			Court::file_counterclaim(RuntimeOrigin::signed(1), 0, 30, statement),
+			Error::<Test, _>::NotDefendant
+		);
+		assert_ok!(Court::file_counterclaim(RuntimeOrigin::signed(0), 0, 30, statement));
+		System::assert_last_event(Event::CounterclaimFiled { lawsuit_id: 0, value: 30 }.into());
+		assert_noop!(
+			Court::file_counterclaim(RuntimeOrigin::signed(0), 0, 30, statement),
+			Error::<Test, _>::CounterclaimExists
+		);
+
+		for voter in 2..6 {
+			assert_ok!(Court::vote_sue(RuntimeOrigin::signed(voter), 0, true));
+			assert_ok!(Court::vote_counterclaim(RuntimeOrigin::signed(voter), 0, true));
+		}
+		assert_noop!(
+			Court::vote_counterclaim(RuntimeOrigin::signed(2), 0, false),
+			Error::<Test, _>::DuplicateVote
+		);
+		assert_ok!(Court::process_sue(RuntimeOrigin::signed(6), 0));
+		System::assert_has_event(Event::CounterclaimClosed { lawsuit_id: 0, approve: true }.into());
+
+		// Only the difference moves.
+		<Court as OnIdle<u64>>::on_idle(1, Weight::MAX);
+		assert_eq!(Balances::free_balance(0), 31);
+		assert_eq!(Balances::free_balance(1), 70);
+		System::assert_last_event(
+			Event::AwardPaid { lawsuit_id: 0, amount: 70, beneficiary: 1 }.into(),
+		);
+	});
+}
+
+#[test]
+fn upheld_counterclaim_is_paid_by_the_plaintiff() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		Balances::make_free_balance_be(&1, 100);
+		assert_ok!(Court::submit_sue(
+			RuntimeOrigin::signed(1),
+			100,
+			0,
+			note(b""),
+			0,
+			None,
+			None,
+			false,
+			false
+		));
+		assert_noop!(
+			Court::vote_counterclaim(RuntimeOrigin::signed(2), 0, true),
+			Error::<Test, _>::NoCounterclaim
+		);
+		assert_ok!(Court::file_counterclaim(RuntimeOrigin::signed(0), 0, 30, note(b"fees")));
+		for voter in 2..6 {
+			assert_ok!(Court::vote_sue(RuntimeOrigin::signed(voter), 0, false));
+			assert_ok!(Court::vote_counterclaim(RuntimeOrigin::signed(voter), 0, true));
+		}
+		assert_ok!(Court::process_sue(RuntimeOrigin::signed(6), 0));
+		assert!(!Court::proposals(0).unwrap().approved);
+		assert_eq!(Court::approvals().into_inner(), vec![0]);
+
+		<Court as OnIdle<u64>>::on_idle(1, Weight::MAX);
+		assert_eq!(Balances::free_balance(0), 30);
+		assert_eq!(Balances::free_balance(1), 70);
+		System::assert_last_event(
+			Event::AwardPaid { lawsuit_id: 0, amount: 30, beneficiary: 0 }.into(),
+		);
+
+		// Reversing the counterclaim returns the award and lets the lawsuit be archived.
+		assert_ok!(Court::reverse_ruling(RuntimeOrigin::root(), 0, H256::zero()));
+		assert_eq!(Balances::free_balance(0), 0);
+		assert_eq!(Balances::free_balance(1), 100);
+		let lawsuit = Court::proposals(0).unwrap();
+		assert!(!lawsuit.counterclaim.unwrap().approved);
+		assert!(Court::net_award(&Court::proposals(0).unwrap()).is_none());
+		<Court as OnIdle<u64>>::on_idle(11, Weight::MAX);
+		assert!(Court::proposals(0).is_none());
+	});
+}
+
+#[test]
+fn fiat_counterclaims_are_fixed_from_their_claim() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		NativePrice::set(&Some(FixedU128::saturating_from_integer(2)));
+		assert_ok!(Court::submit_sue(
+			RuntimeOrigin::signed(1),
+			100,
+			0,
+			note(b""),
+			0,
+			None,
+			None,
+			false,
+			true
+		));
+		assert_ok!(Court::file_counterclaim(RuntimeOrigin::signed(0), 0, 60, note(b"fees")));
+		System::assert_last_event(Event::CounterclaimFiled { lawsuit_id: 0, value: 60 }.into());
+		let counterclaim = Court::proposals(0).unwrap().counterclaim.unwrap();
+		assert_eq!((counterclaim.value, counterclaim.claim), (0, Some(60)));
+
+		for voter in 2..6 {
+			assert_ok!(Court::vote_sue(RuntimeOrigin::signed(voter), 0, true));
+			assert_ok!(Court::vote_counterclaim(RuntimeOrigin::signed(voter), 0, true));
+		}
+		assert_ok!(Court::process_sue(RuntimeOrigin::signed(6), 0));
+		let lawsuit = Court::proposals(0).unwrap();
+		let counterclaim = lawsuit.counterclaim.clone().unwrap();
+		assert_eq!((counterclaim.value, counterclaim.claim), (30, Some(60)));
+		assert_eq!(Court::net_award(&lawsuit), Some((&0, &1, 20)));
+	});
+}
+
+#[test]
+fn counterclaim_votes_wait_for_the_jury() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		AppealPeriod::set(&5);
+		JurySize::set(&4);
+		Balances::make_free_balance_be(&0, 1000);
+		for juror in 10..14 {
+			Balances::make_free_balance_be(&juror, 1000);
+			assert_ok!(Court::join_jury(RuntimeOrigin::signed(juror), 100, Perbill::zero()));
+		}
+		assert_ok!(Court::submit_sue(
+			RuntimeOrigin::signed(1),
+			100,
+			0,
+			note(b""),
+			0,
+			None,
+			None,
+			false,
+			false
+		));
+		assert_ok!(Court::file_counterclaim(RuntimeOrigin::signed(0), 0, 30, note(b"fees")));
+		next_block();
+		for juror in 10..14 {
+			assert_ok!(Court::vote_sue(RuntimeOrigin::signed(juror), 0, true));
+			assert_ok!(Court::vote_counterclaim(RuntimeOrigin::signed(juror), 0, false));
+		}
+		assert_ok!(Court::process_sue(RuntimeOrigin::signed(6), 0));
+
+		// Until the new jury is drawn, no one can vote on the counterclaim.
+		assert_ok!(Court::appeal(RuntimeOrigin::signed(0), 0));
+		for voter in [7, 10] {
+			assert_noop!(
+				Court::vote_counterclaim(RuntimeOrigin::signed(voter), 0, true),
+				Error::<Test, _>::DrawPending
+			);
+		}
+		next_block();
+		assert_noop!(
+			Court::vote_counterclaim(RuntimeOrigin::signed(7), 0, true),
+			Error::<Test, _>::NotDrawn
+		);
+		Absences::<Test>::insert(10, 1);
+		FiredHooks::set(vec![]);
+		assert_ok!(Court::vote_counterclaim(RuntimeOrigin::signed(10), 0, true));
+		assert_eq!(Court::absences(10), 0);
+		assert_eq!(FiredHooks::get(), vec![Hook::Voted(0, 10, true)]);
+	});
+}
+
+#[test]
+fn insurance_covers_insolvent_defendants() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		InsuranceCap::set(&50);
+		Balances::make_free_balance_be(&INSURANCE, 41);
+		Balances::make_free_balance_be(&0, 71);
+		approve_lawsuit(1, 0, 100);
+
+		<Court as OnIdle<u64>>::on_idle(1, Weight::MAX);
+		assert_eq!(Balances::free_balance(1), 100);
+		assert_eq!(Balances::free_balance(0), 1);
+		System::assert_has_event(
+			Event::AwardInsured { lawsuit_id: 0, debtor: 0, covered: 30 }.into(),
+		);
+		assert_eq!(Court::insurance_status(), InsuranceStatus { balance: 10, debts: 30, cap: 50 });
+
+		assert_noop!(
+			Court::repay_insurance(RuntimeOrigin::signed(1), 10),
+			Error::<Test, _>::NoInsuranceDebt
+		);
+		Balances::make_free_balance_be(&0, 51);
+		assert_ok!(Court::repay_insurance(RuntimeOrigin::signed(0), 100));
+		System::assert_last_event(Event::InsuranceRepaid { debtor: 0, amount: 30 }.into());
+		assert_eq!(Court::insurance_debts(0), 0);
+		assert_eq!(Court::insurance_status().balance, 40);
+	});
+}
+
+#[test]
+fn insured_share_returns_to_the_pool_on_reversal() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		InsuranceCap::set(&50);
+		Balances::make_free_balance_be(&INSURANCE, 41);
+		Balances::make_free_balance_be(&0, 71);
+		approve_lawsuit(1, 0, 100);
+		<Court as OnIdle<u64>>::on_idle(1, Weight::MAX);
+		assert_eq!(Court::proposals(0).unwrap().insured, 30);
+
+		assert_ok!(Court::reverse_ruling(RuntimeOrigin::root(), 0, H256::zero()));
+		System::assert_has_event(
+			Event::AwardClawedBack { lawsuit_id: 0, amount: 100, shortfall: 0 }.into(),
+		);
+		assert_eq!(Balances::free_balance(1), 0);
+		assert_eq!(Balances::free_balance(0), 71);
+		assert_eq!(Court::insurance_status(), InsuranceStatus { balance: 40, debts: 0, cap: 50 });
+		assert_eq!(Court::insurance_debts(0), 0);
+	});
+}
+
+#[test]
+fn shortfalls_above_the_cap_are_not_insured() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		InsuranceCap::set(&20);
+		Balances::make_free_balance_be(&INSURANCE, 1_000);
+		Balances::make_free_balance_be(&0, 71);
+		approve_lawsuit(1, 0, 100);
+
+		<Court as OnIdle<u64>>::on_idle(1, Weight::MAX);
+		assert_eq!(Court::approvals().into_inner(), vec![0]);
+		assert_eq!(Balances::free_balance(0), 71);
+		assert_eq!(Court::total_insurance_debt(), 0);
+	});
+}
+
+#[test]
+fn crowdfunded_appeal_rewards_the_winning_side() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		AppealPeriod::set(&5);
+		for who in 7..10 {
+			Balances::make_free_balance_be(&who, 100);
+		}
+		approve_lawsuit(1, 0, 50);
+
+		// Backing the winning side does not appeal the ruling.
+		assert_ok!(Court::fund_appeal(RuntimeOrigin::signed(9), 0, true, 10));
+		assert_noop!(
+			Court::fund_appeal(RuntimeOrigin::signed(9), 0, true, 1),
+			Error::<Test, _>::FundFilled
+		);
+		assert_ok!(Court::fund_appeal(RuntimeOrigin::signed(7), 0, false, 6));
+		assert_eq!(Court::proposals(0).unwrap().round, 0);
+		// The contribution is capped at the fee, filling the fund pays the fee and appeals the
+		// ruling.
+		assert_ok!(Court::fund_appeal(RuntimeOrigin::signed(8), 0, false, 10));
+		System::assert_has_event(Event::Appealed { lawsuit_id: 0, appellant: 8, round: 1 }.into());
+		System::assert_has_event(Event::FeePaid { lawsuit_id: 0, payer: 7, amount: 6 }.into());
+		System::assert_has_event(Event::FeePaid { lawsuit_id: 0, payer: 8, amount: 4 }.into());
+		assert_eq!(Balances::free_balance(8), 96);
+		assert_eq!(Balances::reserved_balance(8), 0);
+		assert_eq!(Balances::free_balance(TREASURY), 10);
+
+		for voter in 2..6 {
+			assert_ok!(Court::vote_sue(RuntimeOrigin::signed(voter), 0, false));
+		}
+		assert_ok!(Court::process_sue(RuntimeOrigin::signed(6), 0));
+		assert_ok!(Court::fund_appeal(RuntimeOrigin::signed(9), 0, true, 5));
+
+		System::set_block_number(7);
+		assert_ok!(Court::execute_ruling(RuntimeOrigin::signed(6), 0));
+		System::assert_has_event(
+			Event::AppealFundFailed { lawsuit_id: 0, round: 1, approve: true }.into(),
+		);
+		System::assert_has_event(
+			Event::AppealFundRewarded { lawsuit_id: 0, contributor: 7, amount: 6 }.into(),
+		);
+		System::assert_has_event(
+			Event::AppealFeeRefunded { lawsuit_id: 0, contributor: 8, amount: 4 }.into(),
+		);
+		// The fee is returned from the treasury and the pot of the losing side is shared.
+		assert_eq!(Balances::free_balance(7), 106);
+		assert_eq!(Balances::free_balance(8), 104);
+		assert_eq!(Balances::free_balance(TREASURY), 0);
+		assert_eq!(Balances::free_balance(9), 90);
+		assert_eq!(Balances::reserved_balance(9), 0);
+		assert_eq!(AppealFunds::<Test>::iter_prefix(0).count(), 0);
+	});
+}
+
+#[test]
+fn unfilled_appeal_funds_are_released() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		AppealPeriod::set(&5);
+		Balances::make_free_balance_be(&7, 100);
+		Balances::make_free_balance_be(&9, 100);
+		approve_lawsuit(1, 0, 50);
+
+		assert_ok!(Court::fund_appeal(RuntimeOrigin::signed(9), 0, true, 3));
+		assert_ok!(Court::fund_appeal(RuntimeOrigin::signed(7), 0, false, 10));
+		System::assert_has_event(
+			Event::AppealFundFailed { lawsuit_id: 0, round: 0, approve: true }.into(),
+		);
+		assert_eq!(Balances::reserved_balance(9), 0);
+
+		// The fund paid the fee to the treasury and the appeal fails, nothing is returned.
+		for voter in 2..6 {
+			assert_ok!(Court::vote_sue(RuntimeOrigin::signed(voter), 0, true));
+		}
+		assert_ok!(Court::process_sue(RuntimeOrigin::signed(6), 0));
+		System::set_block_number(7);
+		assert_noop!(
+			Court::fund_appeal(RuntimeOrigin::signed(7), 0, false, 20),
+			Error::<Test, _>::AppealPeriodOver
+		);
+		assert_ok!(Court::execute_ruling(RuntimeOrigin::signed(6), 0));
+		assert_eq!(Balances::free_balance(7), 90);
+		assert_eq!(Balances::free_balance(TREASURY), 10);
+	});
+}
+
+/// Sue with the statement `b""`, which has to be noted beforehand.
+fn sue(plaintiff: u128, defendent: u128, value: u64) -> DispatchResult {
+	Court::submit_sue(
+		RuntimeOrigin::signed(plaintiff),
+		value,
+		defendent,
+		BlakeTwo256::hash(b""),
+		0,
+		None,
+		None,
+		false,
+		false,
+	)
+	.map(|_| ())
+	.map_err(|e| e.error)
+}
+
+#[test]
+fn agreements_override_the_court_parameters() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		FilingFee::set(&10);
+		Balances::make_free_balance_be(&0, 100);
+		Balances::make_free_balance_be(&1, 100);
+		note(b"");
+		assert_ok!(Court::create_sub_court(RuntimeOrigin::root(), 0));
+		assert_ok!(Court::set_agreement_required(RuntimeOrigin::signed(0), true));
+		assert_noop!(sue(1, 0, 50), Error::<Test, _>::NoAgreement);
+
+		let terms = AgreementTerms {
+			sub_court: 1,
+			max_claim: 50,
+			plaintiff_fee: Perbill::from_percent(40),
+			expires_at: Some(10),
+		};
+		assert_ok!(Court::offer_agreement(RuntimeOrigin::signed(0), 1, terms.clone()));
+		assert_noop!(sue(1, 0, 50), Error::<Test, _>::NoAgreement);
+		assert_noop!(
+			Court::sign_agreement(
+				RuntimeOrigin::signed(1),
+				0,
+				AgreementTerms { max_claim: 100, ..terms.clone() }
+			),
+			Error::<Test, _>::TermsMismatch
+		);
+		assert_ok!(Court::sign_agreement(RuntimeOrigin::signed(1), 0, terms));
+		System::assert_last_event(Event::AgreementSigned { defendent: 0, plaintiff: 1 }.into());
+		// The defendant's share of the fee is reserved.
+		assert_eq!(Balances::reserved_balance(0), 6);
+
+		assert_noop!(sue(1, 0, 51), Error::<Test, _>::ClaimAboveAgreement);
+		assert_ok!(sue(1, 0, 50));
+		assert_eq!(Court::proposals(0).unwrap().sub_court, 1);
+		System::assert_has_event(Event::FeePaid { lawsuit_id: 0, payer: 1, amount: 4 }.into());
+		System::assert_has_event(Event::FeePaid { lawsuit_id: 0, payer: 0, amount: 6 }.into());
+		assert_eq!(Balances::free_balance(0), 88);
+		assert_eq!(Balances::reserved_balance(0), 6);
+
+		// Fiat claims are capped at their native value.
+		NativePrice::set(&Some(FixedU128::saturating_from_integer(2)));
+		let fiat_sue = |value| {
+			Court::submit_sue(
+				RuntimeOrigin::signed(1),
+				value,
+				0,
+				BlakeTwo256::hash(b""),
+				0,
+				None,
+				None,
+				false,
+				true,
+			)
+		};
+		assert_noop!(fiat_sue(102).map_err(|e| e.error), Error::<Test, _>::ClaimAboveAgreement);
+		assert_ok!(fiat_sue(100));
+
+		// Once the defendant cannot reserve its share anymore, the plaintiff pays it.
+		Balances::make_free_balance_be(&0, 1);
+		assert_ok!(sue(1, 0, 10));
+		assert_ok!(sue(1, 0, 10));
+		System::assert_has_event(Event::FeePaid { lawsuit_id: 3, payer: 1, amount: 6 }.into());
+		assert_eq!(Balances::reserved_balance(0), 0);
+	});
+}
+
+#[test]
+fn agreements_lapse_when_expired_or_revoked() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let terms = AgreementTerms {
+			sub_court: 0,
+			max_claim: 50,
+			plaintiff_fee: Perbill::one(),
+			expires_at: Some(10),
+		};
+		note(b"");
+		assert_ok!(Court::set_agreement_required(RuntimeOrigin::signed(0), true));
+		assert_ok!(Court::offer_agreement(RuntimeOrigin::signed(0), 1, terms.clone()));
+		assert_ok!(Court::sign_agreement(RuntimeOrigin::signed(1), 0, terms.clone()));
+		assert_ok!(sue(1, 0, 10));
+
+		System::set_block_number(10);
+		assert_noop!(sue(1, 0, 10), Error::<Test, _>::NoAgreement);
+		assert_noop!(
+			Court::offer_agreement(RuntimeOrigin::signed(0), 1, terms),
+			Error::<Test, _>::AgreementExpired
+		);
+		// Without the requirement the expired terms do not apply anymore.
+		assert_ok!(Court::set_agreement_required(RuntimeOrigin::signed(0), false));
+		assert_ok!(sue(1, 0, 100));
+
+		assert_ok!(Court::revoke_agreement(RuntimeOrigin::signed(1), 0));
+		System::assert_last_event(Event::AgreementRevoked { defendent: 0, plaintiff: 1 }.into());
+		assert!(Court::agreements(0, 1).is_none());
+		assert_noop!(
+			Court::revoke_agreement(RuntimeOrigin::signed(1), 0),
+			Error::<Test, _>::NoAgreement
+		);
+	});
+}
+
+#[test]
+fn statement_can_be_amended_before_voting() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let original = note(b"original");
+		let amended = note(b"amended");
+		assert_ok!(Court::submit_sue(
+			RuntimeOrigin::signed(1),
+			50,
+			0,
+			original,
+			0,
+			None,
+			None,
+			false,
+			false
+		));
+		assert_noop!(
+			Court::amend_statement(RuntimeOrigin::signed(0), 0, amended),
+			Error::<Test, _>::NotPlaintiff
+		);
+		assert_noop!(
+			Court::amend_statement(RuntimeOrigin::signed(1), 0, H256::repeat_byte(1)),
+			Error::<Test, _>::StatementNotNoted
+		);
+
+		System::set_block_number(3);
+		assert_ok!(Court::amend_statement(RuntimeOrigin::signed(1), 0, amended));
+		System::assert_last_event(
+			Event::StatementAmended { lawsuit_id: 0, statement: amended }.into(),
+		);
+		assert_eq!(Court::proposals(0).unwrap().statement, amended);
+		assert_eq!(Court::statement_history(0), vec![(original, 1), (amended, 3)]);
+		assert_ok!(Court::amend_statement(RuntimeOrigin::signed(1), 0, original));
+		assert_noop!(
+			Court::amend_statement(RuntimeOrigin::signed(1), 0, amended),
+			Error::<Test, _>::TooManyAmendments
+		);
+
+		assert_ok!(Court::vote_sue(RuntimeOrigin::signed(2), 0, true));
+		assert_noop!(
+			Court::amend_statement(RuntimeOrigin::signed(1), 0, original),
+			Error::<Test, _>::VotingStarted
+		);
+	});
+}
+
+#[test]
+fn every_statement_version_is_released_on_archival() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		Balances::make_free_balance_be(&1, 100);
+		let (original, amended) = (BlakeTwo256::hash(b"original"), BlakeTwo256::hash(b"amended"));
+		assert_ok!(Preimage::note_preimage(RuntimeOrigin::signed(1), b"original".to_vec()));
+		assert_ok!(Preimage::note_preimage(RuntimeOrigin::signed(1), b"amended".to_vec()));
+		assert_ok!(Court::submit_sue(
+			RuntimeOrigin::signed(1),
+			50,
+			0,
+			original,
+			0,
+			None,
+			None,
+			false,
+			false
+		));
+		assert_ok!(Court::amend_statement(RuntimeOrigin::signed(1), 0, amended));
+		assert!(<Preimage as QueryPreimage>::is_requested(&original));
+		assert!(<Preimage as QueryPreimage>::is_requested(&amended));
+
+		for voter in 2..6 {
+			assert_ok!(Court::vote_sue(RuntimeOrigin::signed(voter), 0, false));
+		}
+		assert_ok!(Court::process_sue(RuntimeOrigin::signed(6), 0));
+		assert!(<Preimage as QueryPreimage>::is_requested(&amended));
+		<Court as OnIdle<u64>>::on_idle(11, Weight::MAX);
+		assert!(Court::proposals(0).is_none());
+		assert!(!<Preimage as QueryPreimage>::is_requested(&original));
+		assert!(!<Preimage as QueryPreimage>::is_requested(&amended));
+	});
+}
+
+#[test]
+fn juror_statistics_are_kept_per_sub_court() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		JurySize::set(&4);
+		for juror in 10..14 {
+			Balances::make_free_balance_be(&juror, 1000);
+			assert_ok!(Court::join_jury(RuntimeOrigin::signed(juror), 100, Perbill::zero()));
+		}
+		note(b"");
+		assert_ok!(sue(1, 0, 100));
+		next_block();
+		for juror in 10..13 {
+			assert_ok!(Court::vote_sue(RuntimeOrigin::signed(juror), 0, true));
+		}
+		assert_ok!(Court::vote_sue(RuntimeOrigin::signed(13), 0, false));
+		assert_ok!(Court::process_sue(RuntimeOrigin::signed(6), 0));
+
+		// The 10 slashed from juror 13 are shared among the coherent jurors.
+		let coherent = JurorStats { drawn: 1, votes: 1, coherent: 1, rewards: 3, slashes: 0 };
+		assert_eq!(Court::juror_statistics(0, 10), coherent);
+		assert_eq!(
+			Court::juror_stats(&13),
+			JurorStats { drawn: 1, votes: 1, coherent: 0, rewards: 0, slashes: 10 }
+		);
+
+		let board = Court::leaderboard(0, 0, 2);
+		assert_eq!(board.len(), 2);
+		assert!(board.iter().all(|(_, stats)| stats == &coherent));
+		assert_eq!(Court::leaderboard(0, 3, 10), vec![(13, Court::juror_stats(&13))]);
+		assert!(Court::leaderboard(1, 0, 10).is_empty());
+	});
+}
+
+#[test]
+fn coherence_is_counted_against_the_final_ruling() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		AppealPeriod::set(&5);
+		JurySize::set(&4);
+		Balances::make_free_balance_be(&0, 100);
+		for juror in 10..14 {
+			Balances::make_free_balance_be(&juror, 1000);
+			assert_ok!(Court::join_jury(RuntimeOrigin::signed(juror), 100, Perbill::zero()));
+		}
+		assert_ok!(Court::create_sub_court(RuntimeOrigin::root(), 0));
+		assert_ok!(Court::submit_sue(
+			RuntimeOrigin::signed(1),
+			100,
+			0,
+			note(b""),
+			1,
+			None,
+			None,
+			false,
+			false
+		));
+		next_block();
+		for juror in 10..14 {
+			assert_ok!(Court::vote_sue(RuntimeOrigin::signed(juror), 0, true));
+		}
+		assert_ok!(Court::process_sue(RuntimeOrigin::signed(6), 0));
+		assert_eq!(Court::juror_statistics(1, 10).coherent, 0);
+
+		// The appeal reverses the ruling, the votes of the first round were incoherent.
+		assert_ok!(Court::appeal(RuntimeOrigin::signed(0), 0));
+		next_block();
+		for juror in 10..14 {
+			assert_ok!(Court::vote_sue(RuntimeOrigin::signed(juror), 0, false));
+		}
+		assert_ok!(Court::process_sue(RuntimeOrigin::signed(6), 0));
+		System::set_block_number(9);
+		assert_ok!(Court::execute_ruling(RuntimeOrigin::signed(6), 0));
+
+		let stats = JurorStats { drawn: 2, votes: 2, coherent: 1, rewards: 0, slashes: 0 };
+		assert_eq!(Court::juror_statistics(1, 10), stats);
+		assert_eq!(Court::juror_stats(&10), stats);
+		assert_eq!(Court::juror_statistics(0, 10), JurorStats::default());
+	});
+}
+
+fn rule_case(case_id: ProposalIndex, guilty: bool) {
+	<Court as OnRulingExecuted<u128>>::on_ruling_executed(case_id, &0, &0, guilty);
+}
+
+#[test]
+fn jurors_guilty_of_misconduct_are_banned() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		AppealPeriod::set(&5);
+		JurySize::set(&4);
+		Balances::make_free_balance_be(&7, 100);
+		for juror in 10..15 {
+			Balances::make_free_balance_be(&juror, 1000);
+			assert_ok!(Court::join_jury(RuntimeOrigin::signed(juror), 100, Perbill::zero()));
+		}
+		note(b"");
+		assert_ok!(sue(1, 0, 100));
+		next_block();
+		let jurors = Court::proposals(0).unwrap().jurors;
+		for juror in jurors.iter() {
+			assert_ok!(Court::vote_sue(RuntimeOrigin::signed(*juror), 0, true));
+		}
+		assert_ok!(Court::process_sue(RuntimeOrigin::signed(6), 0));
+
+		let accused = jurors[0];
+		let statement = note(b"bribed");
+		assert_noop!(
+			Court::report_misconduct(RuntimeOrigin::signed(7), 0, 2, statement, true),
+			Error::<Test, _>::NotDrawn
+		);
+		assert_ok!(Court::report_misconduct(RuntimeOrigin::signed(7), 0, accused, statement, true));
+		System::assert_has_event(
+			Event::MisconductReported { case_id: 1, lawsuit_id: 0, juror: accused, reporter: 7 }
+				.into(),
+		);
+		assert_eq!(Balances::reserved_balance(7), 20);
+		// The stake of the accused juror is frozen until the case is closed.
+		assert_noop!(
+			Court::leave_jury(RuntimeOrigin::signed(accused)),
+			Error::<Test, _>::JurorAccused
+		);
+		System::set_block_number(8);
+		assert_noop!(
+			Court::execute_ruling(RuntimeOrigin::signed(6), 0),
+			Error::<Test, _>::MisconductPending
+		);
+
+		// Half of the bond of the juror is slashed, the rest is unbonding.
+		rule_case(1, true);
+		System::assert_has_event(Event::JurorBanned { juror: accused }.into());
+		assert_eq!(Balances::free_balance(TREASURY), 50);
+		assert_eq!(Balances::reserved_balance(7), 0);
+		assert!(Court::jurors(accused).is_none());
+		assert_eq!(Court::unbonding(accused)[0].value, 50);
+		assert_eq!(Court::accusations(accused), 0);
+		assert_noop!(
+			Court::join_jury(RuntimeOrigin::signed(accused), 100, Perbill::zero()),
+			Error::<Test, _>::JurorBanned
+		);
+
+		// The lawsuit is ruled on again by a new jury.
+		System::assert_has_event(Event::LawsuitReopened { lawsuit_id: 0 }.into());
+		next_block();
+		let lawsuit = Court::proposals(0).unwrap();
+		assert!(lawsuit.voters.is_empty() && lawsuit.ruled_at.is_none());
+		assert_eq!(lawsuit.jurors.len(), 4);
+		assert!(!lawsuit.jurors.contains(&accused));
+		assert_eq!(Court::pending_reopens(0), 0);
+	});
+}
+
+#[test]
+fn acquittal_forfeits_the_misconduct_bond() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		Balances::make_free_balance_be(&7, 100);
+		let lawsuit_id = approve_lawsuit(1, 0, 0);
+		let statement = note(b"bribed");
+		assert_noop!(
+			Court::report_misconduct(RuntimeOrigin::signed(7), lawsuit_id, 2, statement, true),
+			Error::<Test, _>::RulingExecuted
+		);
+		assert_ok!(Court::report_misconduct(
+			RuntimeOrigin::signed(7),
+			lawsuit_id,
+			2,
+			statement,
+			false
+		));
+
+		rule_case(1, false);
+		System::assert_last_event(
+			Event::MisconductRuled { case_id: 1, juror: 2, guilty: false }.into(),
+		);
+		assert_eq!(Balances::free_balance(7), 80);
+		assert_eq!(Balances::free_balance(TREASURY), 20);
+		assert!(!Court::banned(2));
+		assert!(Court::misconduct_cases(1).is_none());
+	});
+}
+
+#[test]
+fn misconduct_cases_not_ruled_on_in_time_are_dismissed() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		AppealPeriod::set(&5);
+		Balances::make_free_balance_be(&2, 100);
+		Balances::make_free_balance_be(&7, 100);
+		assert_ok!(Court::join_jury(RuntimeOrigin::signed(2), 50, Perbill::zero()));
+		note(b"");
+		assert_ok!(sue(1, 0, 0));
+		for voter in 2..6 {
+			assert_ok!(Court::vote_sue(RuntimeOrigin::signed(voter), 0, true));
+		}
+		assert_ok!(Court::process_sue(RuntimeOrigin::signed(6), 0));
+		let statement = note(b"bribed");
+		assert_ok!(Court::report_misconduct(RuntimeOrigin::signed(7), 0, 2, statement, true));
+		assert_noop!(
+			Court::undelegate(RuntimeOrigin::signed(7), 2, 0),
+			Error::<Test, _>::JurorAccused
+		);
+
+		System::set_block_number(11);
+		assert_noop!(
+			Court::dismiss_misconduct(RuntimeOrigin::signed(6), 1),
+			Error::<Test, _>::MisconductNotExpired
+		);
+		assert_noop!(
+			Court::execute_ruling(RuntimeOrigin::signed(6), 0),
+			Error::<Test, _>::MisconductPending
+		);
+		System::set_block_number(12);
+		assert_noop!(
+			Court::dismiss_misconduct(RuntimeOrigin::signed(6), 2),
+			Error::<Test, _>::NoMisconductCase
+		);
+		assert_ok!(Court::dismiss_misconduct(RuntimeOrigin::signed(6), 1));
+		System::assert_last_event(Event::MisconductDismissed { case_id: 1, juror: 2 }.into());
+		assert_eq!(Balances::free_balance(7), 100);
+		assert_eq!(Court::accusations(2), 0);
+		assert_ok!(Court::execute_ruling(RuntimeOrigin::signed(6), 0));
+
+		// A late ruling on the case is ignored.
+		rule_case(1, true);
+		assert!(!Court::banned(2));
+		assert_ok!(Court::leave_jury(RuntimeOrigin::signed(2)));
+	});
+}
+
+#[test]
+fn appeals_are_escalated_to_the_higher_court() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		AppealPeriod::set(&5);
+		Escalate::set(true);
+		Balances::make_free_balance_be(&0, 100);
+		let statement = note(b"");
+		let lawsuit_id = approve_lawsuit(1, 0, 0);
+		assert_ok!(Court::appeal(RuntimeOrigin::signed(0), lawsuit_id));
+		System::assert_has_event(Event::AppealEscalated { lawsuit_id, case_id: 100 }.into());
+		assert_eq!(EscalatedAppeals::get(), vec![(0, 1, statement, 0, false)]);
+		assert_eq!(Court::escalations(100), Some((lawsuit_id, false, 11)));
+		assert_eq!(Balances::free_balance(TREASURY), 10);
+
+		// No jury is drawn, the lawsuit waits for the higher court.
+		let lawsuit = Court::proposals(lawsuit_id).unwrap();
+		assert!(lawsuit.closed && lawsuit.jurors.is_empty() && lawsuit.ruled_at.is_none());
+		assert_noop!(
+			Court::vote_sue(RuntimeOrigin::signed(2), lawsuit_id, true),
+			Error::<Test, _>::LawsuitClosed
+		);
+		System::set_block_number(10);
+		assert_noop!(
+			Court::execute_ruling(RuntimeOrigin::signed(6), lawsuit_id),
+			Error::<Test, _>::NotRuled
+		);
+		assert_noop!(
+			Court::appeal(RuntimeOrigin::signed(0), lawsuit_id),
+			Error::<Test, _>::NotRuled
+		);
+
+		// Without a ruling of the higher court in time, a new jury rules on the appeal.
+		System::set_block_number(11);
+		assert_noop!(
+			Court::expire_escalation(RuntimeOrigin::signed(6), 100),
+			Error::<Test, _>::EscalationNotExpired
+		);
+		System::set_block_number(12);
+		assert_noop!(
+			Court::expire_escalation(RuntimeOrigin::signed(6), 101),
+			Error::<Test, _>::NoEscalation
+		);
+		assert_ok!(Court::expire_escalation(RuntimeOrigin::signed(6), 100));
+		System::assert_last_event(Event::EscalationExpired { lawsuit_id, case_id: 100 }.into());
+		assert!(!Court::proposals(lawsuit_id).unwrap().closed);
+		assert_ok!(Court::vote_sue(RuntimeOrigin::signed(2), lawsuit_id, true));
+		rule_case(100, false);
+		assert!(!Court::proposals(lawsuit_id).unwrap().executed);
+	});
+}
+
+#[test]
+fn escalated_appeals_carry_the_claim_and_award_nothing() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		FilingFee::set(&10);
+		CaseDeposit::set(&5);
+		RejectionCooldown::set(&5);
+		MaxOpenSuits::set(&0);
+		Balances::make_free_balance_be(&0, 100);
+		Balances::make_free_balance_be(&1, 100);
+		let statement = note(b"");
+		assert_ok!(Court::create_sub_court(RuntimeOrigin::root(), 0));
+		let file = |sub_court, funded| {
+			<Court as AppealCourt<u128, u64, u32>>::file_appeal(
+				&1, &0, statement, 50, None, false, sub_court, funded,
+			)
+		};
+
+		// The fund of a crowdfunded appeal covered the fees, the limits do not apply.
+		assert_eq!(file(1, true), Ok(Some(0)));
+		let case = Court::proposals(0).unwrap();
+		assert!(case.escalated && case.value == 50 && case.sub_court == 1);
+		assert_eq!(Balances::free_balance(1), 100);
+		// Unknown sub-courts fall back to the general court.
+		assert_eq!(file(5, false), Ok(Some(1)));
+		assert_eq!(Court::proposals(1).unwrap().sub_court, 0);
+		assert_eq!(Balances::free_balance(1), 85);
+
+		// The rulings award nothing and start no cooldown.
+		for (case_id, approve) in [(0, true), (1, false)] {
+			for voter in 2..6 {
+				assert_ok!(Court::vote_sue(RuntimeOrigin::signed(voter), case_id, approve));
+			}
+			assert_ok!(Court::process_sue(RuntimeOrigin::signed(6), case_id));
+		}
+		assert!(Court::proposals(0).unwrap().executed);
+		<Court as OnIdle<u64>>::on_idle(1, Weight::MAX);
+		assert_eq!(Balances::free_balance(0), 100);
+		assert!(Court::cooldowns(1).is_none());
+	});
+}
+
+#[test]
+fn under_populated_courts_take_no_escalations_or_misconduct() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		JurySize::set(&2);
+		let statement = note(b"");
+		Balances::make_free_balance_be(&7, 100);
+		for juror in 10..12 {
+			Balances::make_free_balance_be(&juror, 1000);
+			assert_ok!(Court::join_jury(RuntimeOrigin::signed(juror), 100, Perbill::zero()));
+		}
+		let file = || {
+			<Court as AppealCourt<u128, u64, u32>>::file_appeal(
+				&1, &0, statement, 50, None, false, 0, false,
+			)
+		};
+
+		// The appeal is left to a new jury of the lower court.
+		assert_ok!(Court::leave_jury(RuntimeOrigin::signed(11)));
+		assert_eq!(file(), Ok(None));
+		assert_eq!(Court::proposal_count(), 0);
+		assert_ok!(Court::join_jury(RuntimeOrigin::signed(11), 100, Perbill::zero()));
+		assert_eq!(file(), Ok(Some(0)));
+		next_block();
+
+		// A juror of the case cannot be reported while no other jury can be drawn.
+		assert_noop!(
+			Court::report_misconduct(RuntimeOrigin::signed(7), 0, 10, statement, false),
+			Error::<Test, _>::NotEnoughJurors
+		);
+		assert_eq!(Balances::reserved_balance(7), 0);
+		assert_eq!(Court::accusations(10), 0);
+	});
+}
+
+#[test]
+fn higher_court_ruling_overrides_the_lawsuit() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		AppealPeriod::set(&5);
+		Escalate::set(true);
+		Balances::make_free_balance_be(&0, 100);
+		Balances::make_free_balance_be(&1, 100);
+		let approved = approve_lawsuit(1, 0, 0);
+		assert_ok!(Court::appeal(RuntimeOrigin::signed(0), approved));
+		assert_ok!(Court::submit_sue(
+			RuntimeOrigin::signed(1),
+			0,
+			0,
+			note(b""),
+			0,
+			None,
+			None,
+			false,
+			false
+		));
+		let rejected = approved + 1;
+		justify(rejected, 2, false, b"no evidence");
+		for voter in 3..6 {
+			assert_ok!(Court::vote_sue(RuntimeOrigin::signed(voter), rejected, false));
+		}
+		assert_ok!(Court::process_sue(RuntimeOrigin::signed(6), rejected));
+		assert_eq!(Court::reasons(rejected).len(), 1);
+		assert_ok!(Court::appeal(RuntimeOrigin::signed(1), rejected));
+		assert_eq!(Court::escalations(101), Some((rejected, true, 11)));
+		FiredHooks::set(&vec![]);
+
+		// The defendant wins the first appeal and the plaintiff the second.
+		rule_case(100, true);
+		System::assert_has_event(
+			Event::RulingOverridden { lawsuit_id: approved, approve: false }.into(),
+		);
+		rule_case(101, true);
+		System::assert_has_event(
+			Event::RulingOverridden { lawsuit_id: rejected, approve: true }.into(),
+		);
+		assert_eq!(
+			FiredHooks::get(),
+			vec![Hook::Executed(approved, false), Hook::Executed(rejected, true)]
+		);
+		assert!(Court::proposals(approved).unwrap().executed);
+		assert!(Court::escalations(100).is_none() && Court::escalations(101).is_none());
+		// The reasons of the overridden jury are dropped.
+		assert!(Court::reasons(rejected).is_empty());
+	});
+}
+
+fn justify(lawsuit_id: ProposalIndex, juror: u128, approve: bool, summary: &[u8]) -> H256 {
+	let document = H256::repeat_byte(juror as u8);
+	assert_ok!(Court::vote_with_justification(
+		RuntimeOrigin::signed(juror),
+		lawsuit_id,
+		approve,
+		document,
+		summary.to_vec()
+	));
+	document
+}
+
+#[test]
+fn ruling_keeps_the_majority_justifications() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		note(b"");
+		assert_ok!(sue(1, 0, 0));
+		assert_noop!(
+			Court::vote_with_justification(
+				RuntimeOrigin::signed(2),
+				0,
+				true,
+				H256::zero(),
+				vec![0; 17]
+			),
+			Error::<Test, _>::SummaryTooLong
+		);
+		let document = justify(0, 2, true, b"breach of terms");
+		System::assert_last_event(
+			Event::VoteJustified { lawsuit_id: 0, juror: 2, document }.into(),
+		);
+		assert_noop!(
+			Court::vote_with_justification(RuntimeOrigin::signed(2), 0, true, document, vec![]),
+			Error::<Test, _>::DuplicateVote
+		);
+		assert_ok!(Court::vote_sue(RuntimeOrigin::signed(3), 0, true));
+		justify(0, 4, true, b"");
+		justify(0, 5, false, b"no evidence");
+		assert_eq!(PendingJustifications::<Test>::get(0).len(), 3);
+
+		// The justification of the dissenting juror is dropped.
+		assert_ok!(Court::process_sue(RuntimeOrigin::signed(6), 0));
+		assert_eq!(
+			Court::reasons(0),
+			vec![
+				Justification {
+					juror: 2,
+					approve: true,
+					document,
+					summary: SummaryOf::<Test>::try_from(b"breach of terms".to_vec()).unwrap()
+				},
+				Justification {
+					juror: 4,
+					approve: true,
+					document: H256::repeat_byte(4),
+					summary: SummaryOf::<Test>::default()
+				},
+			]
+		);
+		assert!(PendingJustifications::<Test>::get(0).is_empty());
+	});
+}
+
+#[test]
+fn reasons_follow_the_last_ruling_and_outlive_the_lawsuit() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		AppealPeriod::set(&5);
+		Balances::make_free_balance_be(&0, 100);
+		note(b"");
+		assert_ok!(sue(1, 0, 0));
+		justify(0, 2, true, b"first");
+		for voter in 3..6 {
+			assert_ok!(Court::vote_sue(RuntimeOrigin::signed(voter), 0, true));
+		}
+		assert_ok!(Court::process_sue(RuntimeOrigin::signed(6), 0));
+		assert_eq!(Court::reasons(0).len(), 1);
+
+		// The ruling on appeal replaces the reasons.
+		assert_ok!(Court::appeal(RuntimeOrigin::signed(0), 0));
+		assert_ok!(Court::vote_sue(RuntimeOrigin::signed(2), 0, false));
+		let document = justify(0, 3, false, b"second");
+		for voter in 4..6 {
+			assert_ok!(Court::vote_sue(RuntimeOrigin::signed(voter), 0, false));
+		}
+		assert_ok!(Court::process_sue(RuntimeOrigin::signed(6), 0));
+		let summary = SummaryOf::<Test>::try_from(b"second".to_vec()).unwrap();
+		let reasons = vec![Justification { juror: 3, approve: false, document, summary }];
+		assert_eq!(Court::reasons(0), reasons);
+		assert_eq!(
+			Court::ruling_reasons(0),
+			vec![Justification { juror: 3, approve: false, document, summary: b"second".to_vec() }]
+		);
+
+		System::set_block_number(7);
+		assert_ok!(Court::execute_ruling(RuntimeOrigin::signed(6), 0));
+		System::set_block_number(17);
+		<Court as OnIdle<u64>>::on_idle(17, Weight::MAX);
+		assert!(Court::proposals(0).is_none() && Court::archive(0).is_some());
+		assert_eq!(Court::reasons(0), reasons);
+	});
+}
+
+#[test]
+fn lawsuits_of_version_0_are_removed() {
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(0).put::<Court>();
+		// A lawsuit of version 0, with the statement itself and the votes.
+		let lawsuit = (1u128, 10u64, 2u128, b"statement".to_vec(), vec![3u128], vec![true], true);
+		frame_support::storage::unhashed::put(&Proposals::<Test>::hashed_key_for(0), &lawsuit);
+		<ProposalCount<Test>>::put(1);
+		assert_ok!(Approvals::<Test>::try_append(0));
+
+		migrations::MigrateToV1::<Test>::on_runtime_upgrade();
+		assert_eq!(Court::on_chain_storage_version(), 1);
+		assert!(!Proposals::<Test>::contains_key(0));
+		assert!(Approvals::<Test>::get().is_empty());
+		assert_eq!(Court::proposal_count(), 1);
+
+		// Version 1 is left as it is.
+		note(b"");
+		assert_ok!(sue(1, 2, 10));
+		migrations::MigrateToV1::<Test>::on_runtime_upgrade();
+		assert!(Court::proposals(1).is_some());
+	});
+}
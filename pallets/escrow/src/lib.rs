@@ -0,0 +1,461 @@
+// Copyright (C) SaaS3.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Escrow Pallet
+//!
+//! The Escrow pallet holds the payment of a buyer for an oracle service until the provider
+//! delivers, with the court as a fallback.
+//!
+//! - [`Config`]
+//! - [`Call`]
+//!
+//! ## Overview
+//!
+//! The buyer opens an escrow with the hash of its terms and a deadline, the amount is reserved
+//! from the buyer. The funds are released to the provider once both parties agree, and refunded
+//! to the buyer after the deadline. Before the deadline, either party can dispute the escrow in
+//! the court, claiming part of the funds. When the ruling is executed, the claim goes to the
+//! claimant if it is approved and the rest of the funds to the other party. A claim paid out is
+//! clawed back if the court reverses its ruling before archiving the lawsuit, and the buyer is
+//! refunded if the court has not ruled `DisputeTimeout` blocks after the deadline. A dispute
+//! withdrawn from the court leaves the escrow as it was before the dispute.
+//!
+//! ## Interface
+//!
+//! ### Dispatchable Functions
+//!
+//! - `open` - Open an escrow with a provider.
+//! - `agree_release` - Agree to release the funds to the provider.
+//! - `refund` - Refund the buyer after the deadline, or after a dispute timed out.
+//! - `dispute` - Dispute an escrow in the court.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(test)]
+mod tests;
+
+use codec::{Decode, Encode};
+use scale_info::TypeInfo;
+
+use sp_core::H256;
+use sp_runtime::{
+	traits::{Saturating, StaticLookup, Zero},
+	RuntimeDebug,
+};
+use sp_std::prelude::*;
+
+use frame_support::{
+	traits::{BalanceStatus, Currency, ExistenceRequirement::AllowDeath, Get, ReservableCurrency},
+	weights::Weight,
+};
+use pallet_court::{Arbitrator, OnRulingExecuted, ProposalIndex};
+
+pub use pallet::*;
+
+pub type BalanceOf<T, I = ()> =
+	<<T as Config<I>>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+pub type EscrowOf<T, I = ()> = Escrow<
+	<T as frame_system::Config>::AccountId,
+	BalanceOf<T, I>,
+	<T as frame_system::Config>::BlockNumber,
+>;
+type AccountIdLookupOf<T> = <<T as frame_system::Config>::Lookup as StaticLookup>::Source;
+
+/// An index of an escrow. Just a `u32`.
+pub type EscrowIndex = u32;
+
+/// Funds of a buyer held until a provider delivers.
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct Escrow<AccountId, Balance, BlockNumber> {
+	/// The account paying, the amount is reserved from it.
+	pub buyer: AccountId,
+	/// The account paid on delivery.
+	pub provider: AccountId,
+	pub amount: Balance,
+	/// The hash of the terms of the service.
+	pub terms: H256,
+	/// The block after which the buyer can be refunded.
+	pub deadline: BlockNumber,
+	/// The parties who agreed to release the funds to the provider.
+	pub released_by: Vec<AccountId>,
+	/// The dispute of the escrow in the court, if any.
+	pub dispute: Option<Dispute<AccountId, Balance>>,
+}
+
+/// A dispute of an escrow in the court.
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct Dispute<AccountId, Balance> {
+	/// The lawsuit filed in the court.
+	pub lawsuit_id: ProposalIndex,
+	/// The party who disputed the escrow, the plaintiff of the lawsuit.
+	pub claimant: AccountId,
+	/// The part of the funds the claimant is awarded if the lawsuit is approved.
+	pub claim: Balance,
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+
+	#[pallet::pallet]
+	#[pallet::without_storage_info]
+	pub struct Pallet<T, I = ()>(PhantomData<(T, I)>);
+
+	#[pallet::config]
+	pub trait Config<I: 'static = ()>: frame_system::Config {
+		/// The overarching event type.
+		type RuntimeEvent: From<Event<Self, I>>
+			+ IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// The currency escrowed.
+		type Currency: ReservableCurrency<Self::AccountId>;
+
+		/// The court disputes are submitted to.
+		type Arbitrator: Arbitrator<Self::AccountId>;
+
+		/// The number of blocks after the deadline of a disputed escrow after which the buyer
+		/// can be refunded if the ruling has not been executed.
+		#[pallet::constant]
+		type DisputeTimeout: Get<Self::BlockNumber>;
+	}
+
+	/// Number of escrows that have been opened.
+	#[pallet::storage]
+	#[pallet::getter(fn escrow_count)]
+	pub type EscrowCount<T: Config<I>, I: 'static = ()> = StorageValue<_, EscrowIndex, ValueQuery>;
+
+	/// Escrows that have not been settled yet.
+	#[pallet::storage]
+	#[pallet::getter(fn escrows)]
+	pub type Escrows<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Twox64Concat, EscrowIndex, EscrowOf<T, I>, OptionQuery>;
+
+	/// The escrow disputed by each lawsuit.
+	#[pallet::storage]
+	#[pallet::getter(fn disputes)]
+	pub type Disputes<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Twox64Concat, ProposalIndex, EscrowIndex, OptionQuery>;
+
+	/// The escrow and the claim paid to the claimant by each approved lawsuit, until the lawsuit
+	/// is archived and the ruling can no longer be reversed.
+	#[pallet::storage]
+	#[pallet::getter(fn awards)]
+	pub type Awards<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Twox64Concat, ProposalIndex, (EscrowIndex, BalanceOf<T, I>), OptionQuery>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config<I>, I: 'static = ()> {
+		/// An escrow has been opened.
+		Opened { escrow_id: EscrowIndex, buyer: T::AccountId, provider: T::AccountId },
+		/// A party agreed to release the funds.
+		ReleaseAgreed { escrow_id: EscrowIndex, who: T::AccountId },
+		/// Both parties agreed and the funds have been released to the provider.
+		Released { escrow_id: EscrowIndex, amount: BalanceOf<T, I> },
+		/// The deadline passed and the funds have been refunded to the buyer.
+		Refunded { escrow_id: EscrowIndex, amount: BalanceOf<T, I> },
+		/// An escrow has been disputed in the court.
+		Disputed { escrow_id: EscrowIndex, lawsuit_id: ProposalIndex },
+		/// The funds of a disputed escrow have been split by the ruling of the court.
+		Settled { escrow_id: EscrowIndex, to_buyer: BalanceOf<T, I>, to_provider: BalanceOf<T, I> },
+		/// The ruling was reversed and `amount` of the claim has been returned by the claimant.
+		ClaimClawedBack { escrow_id: EscrowIndex, amount: BalanceOf<T, I> },
+		/// The claimant withdrew the dispute from the court, the escrow is no longer disputed.
+		DisputeWithdrawn { escrow_id: EscrowIndex, lawsuit_id: ProposalIndex },
+	}
+
+	#[pallet::error]
+	pub enum Error<T, I = ()> {
+		/// No escrow at that index.
+		EscrowNotFound,
+		/// The amount escrowed cannot be zero.
+		ZeroAmount,
+		/// The deadline must be in the future.
+		InvalidDeadline,
+		/// The caller is neither the buyer nor the provider.
+		NotParty,
+		/// The caller already agreed to release the funds.
+		AlreadyAgreed,
+		/// The escrow is in dispute, it is settled by the court until the dispute times out.
+		Disputed,
+		/// The deadline has not passed yet.
+		DeadlineNotPassed,
+		/// The deadline has passed.
+		DeadlinePassed,
+		/// The claim is higher than the amount escrowed.
+		ClaimTooHigh,
+	}
+
+	#[pallet::call]
+	impl<T: Config<I>, I: 'static> Pallet<T, I> {
+		/// Open an escrow paying `amount` to `provider`, the amount is reserved from the caller.
+		///
+		/// - `terms`: The hash of the terms of the service.
+		/// - `deadline`: The block after which the caller can be refunded.
+		#[pallet::call_index(0)]
+		#[pallet::weight(Weight::zero())]
+		pub fn open(
+			origin: OriginFor<T>,
+			provider: AccountIdLookupOf<T>,
+			#[pallet::compact] amount: BalanceOf<T, I>,
+			terms: H256,
+			deadline: T::BlockNumber,
+		) -> DispatchResult {
+			let buyer = ensure_signed(origin)?;
+			let provider = T::Lookup::lookup(provider)?;
+			ensure!(!amount.is_zero(), Error::<T, I>::ZeroAmount);
+			ensure!(
+				deadline > frame_system::Pallet::<T>::block_number(),
+				Error::<T, I>::InvalidDeadline
+			);
+
+			T::Currency::reserve(&buyer, amount)?;
+			let escrow_id = Self::escrow_count();
+			<EscrowCount<T, I>>::put(escrow_id + 1);
+			Escrows::<T, I>::insert(
+				escrow_id,
+				Escrow {
+					buyer: buyer.clone(),
+					provider: provider.clone(),
+					amount,
+					terms,
+					deadline,
+					released_by: vec![],
+					dispute: None,
+				},
+			);
+
+			Self::deposit_event(Event::Opened { escrow_id, buyer, provider });
+			Ok(())
+		}
+
+		/// Agree to release the funds to the provider, they are released once both parties
+		/// agree.
+		#[pallet::call_index(1)]
+		#[pallet::weight(Weight::zero())]
+		pub fn agree_release(
+			origin: OriginFor<T>,
+			#[pallet::compact] escrow_id: EscrowIndex,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let mut escrow = Self::escrows(escrow_id).ok_or(Error::<T, I>::EscrowNotFound)?;
+			ensure!(who == escrow.buyer || who == escrow.provider, Error::<T, I>::NotParty);
+			ensure!(escrow.dispute.is_none(), Error::<T, I>::Disputed);
+			ensure!(!escrow.released_by.contains(&who), Error::<T, I>::AlreadyAgreed);
+
+			escrow.released_by.push(who.clone());
+			Self::deposit_event(Event::ReleaseAgreed { escrow_id, who });
+			if escrow.released_by.len() < 2 {
+				Escrows::<T, I>::insert(escrow_id, escrow);
+				return Ok(())
+			}
+
+			Escrows::<T, I>::remove(escrow_id);
+			let missing = T::Currency::repatriate_reserved(
+				&escrow.buyer,
+				&escrow.provider,
+				escrow.amount,
+				BalanceStatus::Free,
+			)?;
+			Self::deposit_event(Event::Released {
+				escrow_id,
+				amount: escrow.amount.saturating_sub(missing),
+			});
+			Ok(())
+		}
+
+		/// Refund the buyer of an undisputed escrow after its deadline, or of a disputed escrow
+		/// `DisputeTimeout` blocks after its deadline if the ruling has not been executed by then.
+		/// A later ruling on the dispute is ignored.
+		///
+		/// May be called by any signed origin.
+		#[pallet::call_index(2)]
+		#[pallet::weight(Weight::zero())]
+		pub fn refund(
+			origin: OriginFor<T>,
+			#[pallet::compact] escrow_id: EscrowIndex,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+			let escrow = Self::escrows(escrow_id).ok_or(Error::<T, I>::EscrowNotFound)?;
+			let now = frame_system::Pallet::<T>::block_number();
+			ensure!(now > escrow.deadline, Error::<T, I>::DeadlineNotPassed);
+			if let Some(dispute) = &escrow.dispute {
+				ensure!(
+					now > escrow.deadline.saturating_add(T::DisputeTimeout::get()),
+					Error::<T, I>::Disputed
+				);
+				Disputes::<T, I>::remove(dispute.lawsuit_id);
+			}
+
+			Escrows::<T, I>::remove(escrow_id);
+			let missing = T::Currency::unreserve(&escrow.buyer, escrow.amount);
+			Self::deposit_event(Event::Refunded {
+				escrow_id,
+				amount: escrow.amount.saturating_sub(missing),
+			});
+			Ok(())
+		}
+
+		/// Dispute an escrow before its deadline by filing a lawsuit against the other party.
+		///
+		/// The caller pays the fees and deposit of the court. If the lawsuit is approved the
+		/// caller is awarded `claim` and the other party the rest of the funds, otherwise the
+		/// other party gets all the funds.
+		///
+		/// - `claim`: The part of the funds claimed, at most the amount escrowed.
+		/// - `statement`: The hash of the statement, noted as the court requires.
+		#[pallet::call_index(3)]
+		#[pallet::weight(T::Arbitrator::file_weight())]
+		pub fn dispute(
+			origin: OriginFor<T>,
+			#[pallet::compact] escrow_id: EscrowIndex,
+			#[pallet::compact] claim: BalanceOf<T, I>,
+			statement: H256,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let mut escrow = Self::escrows(escrow_id).ok_or(Error::<T, I>::EscrowNotFound)?;
+			let other = if who == escrow.buyer {
+				escrow.provider.clone()
+			} else if who == escrow.provider {
+				escrow.buyer.clone()
+			} else {
+				return Err(Error::<T, I>::NotParty.into())
+			};
+			ensure!(escrow.dispute.is_none(), Error::<T, I>::Disputed);
+			ensure!(
+				frame_system::Pallet::<T>::block_number() <= escrow.deadline,
+				Error::<T, I>::DeadlinePassed
+			);
+			ensure!(claim <= escrow.amount, Error::<T, I>::ClaimTooHigh);
+
+			let lawsuit_id = T::Arbitrator::file_dispute(&who, &other, statement)?;
+			escrow.dispute = Some(Dispute { lawsuit_id, claimant: who, claim });
+			Escrows::<T, I>::insert(escrow_id, escrow);
+			Disputes::<T, I>::insert(lawsuit_id, escrow_id);
+
+			Self::deposit_event(Event::Disputed { escrow_id, lawsuit_id });
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config<I>, I: 'static> Pallet<T, I> {
+	/// Split the funds of the escrow disputed by `lawsuit_id` according to the ruling.
+	fn settle(lawsuit_id: ProposalIndex, approved: bool) {
+		let escrow_id = match Disputes::<T, I>::take(lawsuit_id) {
+			Some(escrow_id) => escrow_id,
+			None => return,
+		};
+		let escrow = match Escrows::<T, I>::take(escrow_id) {
+			Some(escrow) => escrow,
+			None => return,
+		};
+		let dispute = match escrow.dispute {
+			Some(dispute) => dispute,
+			None => return,
+		};
+
+		let awarded = if approved { dispute.claim } else { Zero::zero() };
+		let (to_buyer, to_provider) = if dispute.claimant == escrow.buyer {
+			(awarded, escrow.amount.saturating_sub(awarded))
+		} else {
+			(escrow.amount.saturating_sub(awarded), awarded)
+		};
+		let missing = T::Currency::repatriate_reserved(
+			&escrow.buyer,
+			&escrow.provider,
+			to_provider,
+			BalanceStatus::Free,
+		)
+		.unwrap_or(to_provider);
+		T::Currency::unreserve(&escrow.buyer, to_buyer.saturating_add(missing));
+		if !awarded.is_zero() {
+			Awards::<T, I>::insert(lawsuit_id, (escrow_id, awarded));
+		}
+
+		Self::deposit_event(Event::Settled { escrow_id, to_buyer, to_provider });
+	}
+
+	/// Drop the dispute withdrawn as `lawsuit_id`, the escrow is left as it was before.
+	fn withdraw_dispute(lawsuit_id: ProposalIndex) {
+		let escrow_id = match Disputes::<T, I>::take(lawsuit_id) {
+			Some(escrow_id) => escrow_id,
+			None => return,
+		};
+		Escrows::<T, I>::mutate(escrow_id, |escrow| {
+			if let Some(escrow) = escrow {
+				escrow.dispute = None;
+			}
+		});
+		Self::deposit_event(Event::DisputeWithdrawn { escrow_id, lawsuit_id });
+	}
+
+	/// Return the claim paid by the reversed ruling on `lawsuit_id` from the claimant to the
+	/// other party, as much as the claimant can pay.
+	fn claw_back(lawsuit_id: ProposalIndex, claimant: &T::AccountId, other: &T::AccountId) {
+		let (escrow_id, awarded) = match Awards::<T, I>::take(lawsuit_id) {
+			Some(award) => award,
+			None => return,
+		};
+		let amount = awarded.min(T::Currency::free_balance(claimant));
+		let amount = T::Currency::transfer(claimant, other, amount, AllowDeath)
+			.map_or_else(|_| Zero::zero(), |_| amount);
+		Self::deposit_event(Event::ClaimClawedBack { escrow_id, amount });
+	}
+}
+
+impl<T: Config<I>, I: 'static> OnRulingExecuted<T::AccountId> for Pallet<T, I> {
+	fn on_ruling_executed(
+		lawsuit_id: ProposalIndex,
+		_plaintiff: &T::AccountId,
+		_defendent: &T::AccountId,
+		approved: bool,
+	) -> Weight {
+		Self::settle(lawsuit_id, approved);
+		Self::max_weight()
+	}
+
+	fn on_ruling_reversed(
+		lawsuit_id: ProposalIndex,
+		plaintiff: &T::AccountId,
+		defendent: &T::AccountId,
+	) -> Weight {
+		Self::claw_back(lawsuit_id, plaintiff, defendent);
+		Self::max_weight()
+	}
+
+	fn on_lawsuit_archived(lawsuit_id: ProposalIndex) -> Weight {
+		Awards::<T, I>::remove(lawsuit_id);
+		T::DbWeight::get().writes(1)
+	}
+
+	fn on_lawsuit_withdrawn(
+		lawsuit_id: ProposalIndex,
+		_plaintiff: &T::AccountId,
+		_defendent: &T::AccountId,
+	) -> Weight {
+		Self::withdraw_dispute(lawsuit_id);
+		Self::max_weight()
+	}
+
+	fn max_weight() -> Weight {
+		// The dispute, the escrow and the award, then the balances of both parties.
+		T::DbWeight::get().reads_writes(5, 5)
+	}
+}
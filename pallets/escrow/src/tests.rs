@@ -0,0 +1,438 @@
+// Copyright (C) SaaS3.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Escrow pallet tests.
+
+#![cfg(test)]
+
+use sp_core::H256;
+use sp_runtime::{
+	testing::Header,
+	traits::{BlakeTwo256, Hash, IdentityLookup},
+	DispatchError, Perbill,
+};
+
+use frame_support::{
+	assert_noop, assert_ok,
+	instances::Instance1,
+	parameter_types,
+	traits::{AsEnsureOriginWithArg, ConstU32, ConstU64, Nothing, Randomness},
+};
+
+use super::*;
+use crate as escrow;
+use pallet_court as court;
+
+use frame_system::{EnsureRoot, EnsureSigned};
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
+type Block = frame_system::mocking::MockBlock<Test>;
+
+frame_support::construct_runtime!(
+	pub enum Test where
+		Block = Block,
+		NodeBlock = Block,
+		UncheckedExtrinsic = UncheckedExtrinsic,
+	{
+		System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
+		Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
+		Escrow: escrow::{Pallet, Call, Storage, Event<T>},
+		CourtEscrow: escrow::<Instance1>::{Pallet, Call, Storage, Event<T>},
+		Court: court::{Pallet, Call, Storage, Config, Event<T>, Origin<T>},
+		Preimage: pallet_preimage,
+		Assets: pallet_assets,
+	}
+);
+
+impl frame_system::Config for Test {
+	type BaseCallFilter = frame_support::traits::Everything;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type DbWeight = ();
+	type RuntimeOrigin = RuntimeOrigin;
+	type Index = u64;
+	type BlockNumber = u64;
+	type RuntimeCall = RuntimeCall;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = u128;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Header = Header;
+	type RuntimeEvent = RuntimeEvent;
+	type BlockHashCount = ConstU64<250>;
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = pallet_balances::AccountData<u64>;
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = ();
+	type OnSetCode = ();
+	type MaxConsumers = ConstU32<16>;
+}
+
+impl pallet_balances::Config for Test {
+	type MaxLocks = ();
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 8];
+	type Balance = u64;
+	type RuntimeEvent = RuntimeEvent;
+	type DustRemoval = ();
+	type ExistentialDeposit = ConstU64<1>;
+	type AccountStore = System;
+	type WeightInfo = ();
+}
+
+parameter_types! {
+	pub static Filed: Vec<(u128, u128, H256)> = vec![];
+}
+
+/// Records the disputes filed, the lawsuits are numbered in filing order.
+pub struct TestCourt;
+impl Arbitrator<u128> for TestCourt {
+	fn file_dispute(
+		plaintiff: &u128,
+		defendent: &u128,
+		statement: H256,
+	) -> Result<ProposalIndex, DispatchError> {
+		let lawsuit_id = Filed::get().len() as ProposalIndex;
+		Filed::mutate(|f| f.push((*plaintiff, *defendent, statement)));
+		Ok(lawsuit_id)
+	}
+}
+
+impl Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Balances;
+	type Arbitrator = TestCourt;
+	type DisputeTimeout = ConstU64<10>;
+}
+
+/// Escrows disputed in the court below.
+impl Config<Instance1> for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Balances;
+	type Arbitrator = Court;
+	type DisputeTimeout = ConstU64<10>;
+}
+
+impl pallet_preimage::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type WeightInfo = ();
+	type Currency = Balances;
+	type ManagerOrigin = EnsureRoot<u128>;
+	type BaseDeposit = ConstU64<2>;
+	type ByteDeposit = ConstU64<1>;
+}
+
+impl pallet_assets::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Balance = u64;
+	type AssetId = u32;
+	type AssetIdParameter = codec::Compact<u32>;
+	type Currency = Balances;
+	type CreateOrigin = AsEnsureOriginWithArg<EnsureSigned<u128>>;
+	type ForceOrigin = EnsureRoot<u128>;
+	type AssetDeposit = ConstU64<1>;
+	type AssetAccountDeposit = ConstU64<1>;
+	type MetadataDepositBase = ConstU64<1>;
+	type MetadataDepositPerByte = ConstU64<1>;
+	type ApprovalDeposit = ConstU64<1>;
+	type StringLimit = ConstU32<50>;
+	type Freezer = ();
+	type Extra = ();
+	type CallbackHandle = ();
+	type WeightInfo = ();
+	type RemoveItemsLimit = ConstU32<5>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = ();
+}
+
+pub struct TestRandomness;
+impl Randomness<H256, u64> for TestRandomness {
+	fn random(subject: &[u8]) -> (H256, u64) {
+		(H256::from(sp_io::hashing::blake2_256(subject)), System::block_number())
+	}
+}
+
+pub struct TestTreasury;
+impl court::CourtTreasury<u128, u64> for TestTreasury {
+	fn account_id() -> u128 {
+		999
+	}
+
+	fn on_court_income(_: &u128, _: u64) {}
+
+	fn insurance_account() -> u128 {
+		998
+	}
+}
+
+parameter_types! {
+	pub const IncoherenceSlash: Perbill = Perbill::from_percent(10);
+	pub const ReputationDecay: Perbill = Perbill::from_percent(10);
+	pub const NoShowSlash: Perbill = Perbill::from_percent(10);
+	pub const AwardHoldback: Perbill = Perbill::zero();
+	pub const MisconductSlash: Perbill = Perbill::from_percent(50);
+	pub const MaxRemedyWeight: Weight = Weight::from_parts(1_000_000_000_000, 0);
+	pub const TreasuryShare: Perbill = Perbill::zero();
+}
+
+impl pallet_court::Config for Test {
+	type Currency = Balances;
+	type RuntimeEvent = RuntimeEvent;
+	type MaxApprovals = ConstU32<100>;
+	type MaxDeferredApprovals = ConstU32<100>;
+	type MaxPayoutAttempts = ConstU32<3>;
+	type Randomness = TestRandomness;
+	type JurySize = ConstU32<0>;
+	type MaxJurors = ConstU32<10>;
+	type MaxDrawAttempts = ConstU32<2>;
+	type MinJurorBond = ConstU64<10>;
+	type UnbondingPeriod = ConstU64<10>;
+	type IncoherenceSlash = IncoherenceSlash;
+	type Preimages = Preimage;
+	type CourtAdminOrigin = EnsureRoot<u128>;
+	type InitialReputation = ConstU32<50>;
+	type MaxReputation = ConstU32<100>;
+	type ReputationGain = ConstU32<10>;
+	type ReputationLoss = ConstU32<20>;
+	type ReputationDecay = ReputationDecay;
+	type ReputationDecayPeriod = ConstU64<10>;
+	type AssetId = u32;
+	type Assets = Assets;
+	type Treasury = TestTreasury;
+	type FilingFee = ConstU64<0>;
+	type AppealFee = ConstU64<10>;
+	type AppealPeriod = ConstU64<0>;
+	type MaxAppeals = ConstU32<2>;
+	type TreasuryShare = TreasuryShare;
+	type CaseDeposit = ConstU64<0>;
+	type RetentionPeriod = ConstU64<10>;
+	type MaxOpenSuits = ConstU32<100>;
+	type MaxOpenSuitsPerDefendant = ConstU32<100>;
+	type RejectionCooldown = ConstU64<0>;
+	type MaxTags = ConstU32<4>;
+	type MaxTagLength = ConstU32<16>;
+	type MaxAmendments = ConstU32<3>;
+	type MediationFee = ConstU64<10>;
+	type MediationPeriod = ConstU64<5>;
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeCall = RuntimeCall;
+	type RemedyFilter = Nothing;
+	type MaxRemedyLength = ConstU32<64>;
+	type MaxRemedyWeight = MaxRemedyWeight;
+	type VotingPeriod = ConstU64<5>;
+	type NoShowSlash = NoShowSlash;
+	type MaxRedraws = ConstU32<1>;
+	type MaxAbsences = ConstU32<2>;
+	type SuspensionPeriod = ConstU64<20>;
+	type Prices = ();
+	type OnLawsuitFiled = ();
+	type OnVoteCast = ();
+	type OnRuled = ();
+	type OnRulingExecuted = CourtEscrow;
+	type OnAppealed = ();
+	type AwardHoldback = AwardHoldback;
+	type InsuranceCap = ConstU64<0>;
+	type MisconductCourt = Court;
+	type MisconductBond = ConstU64<20>;
+	type MisconductTimeout = ConstU64<10>;
+	type MisconductSlash = MisconductSlash;
+	type HigherCourt = ();
+	type EscalationTimeout = ConstU64<10>;
+	type MaxSummaryLength = ConstU32<16>;
+}
+
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	let mut t = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
+	pallet_balances::GenesisConfig::<Test> { balances: vec![(1, 100), (2, 10)] }
+		.assimilate_storage(&mut t)
+		.unwrap();
+	t.into()
+}
+
+fn rule(lawsuit_id: ProposalIndex, approved: bool) {
+	<Escrow as OnRulingExecuted<u128>>::on_ruling_executed(lawsuit_id, &0, &0, approved);
+}
+
+/// Note `statement` without a deposit, returning its hash.
+fn note(statement: &[u8]) -> H256 {
+	assert_ok!(Preimage::note_preimage(RuntimeOrigin::root(), statement.to_vec()));
+	BlakeTwo256::hash(statement)
+}
+
+#[test]
+fn escrow_is_released_or_refunded() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let terms = H256::repeat_byte(1);
+		assert_noop!(
+			Escrow::open(RuntimeOrigin::signed(1), 2, 50, terms, 1),
+			Error::<Test, _>::InvalidDeadline
+		);
+		assert_ok!(Escrow::open(RuntimeOrigin::signed(1), 2, 50, terms, 10));
+		assert_ok!(Escrow::open(RuntimeOrigin::signed(1), 2, 30, terms, 10));
+		assert_eq!(Balances::reserved_balance(1), 80);
+
+		assert_noop!(
+			Escrow::agree_release(RuntimeOrigin::signed(3), 0),
+			Error::<Test, _>::NotParty
+		);
+		assert_ok!(Escrow::agree_release(RuntimeOrigin::signed(2), 0));
+		assert_noop!(
+			Escrow::agree_release(RuntimeOrigin::signed(2), 0),
+			Error::<Test, _>::AlreadyAgreed
+		);
+		assert_eq!(Balances::free_balance(2), 10);
+		assert_ok!(Escrow::agree_release(RuntimeOrigin::signed(1), 0));
+		System::assert_last_event(Event::Released { escrow_id: 0, amount: 50 }.into());
+		assert_eq!(Balances::free_balance(2), 60);
+		assert!(Escrow::escrows(0).is_none());
+
+		assert_noop!(
+			Escrow::refund(RuntimeOrigin::signed(3), 1),
+			Error::<Test, _>::DeadlineNotPassed
+		);
+		System::set_block_number(11);
+		assert_ok!(Escrow::refund(RuntimeOrigin::signed(3), 1));
+		System::assert_last_event(Event::Refunded { escrow_id: 1, amount: 30 }.into());
+		assert_eq!(Balances::free_balance(1), 50);
+		assert_eq!(Balances::reserved_balance(1), 0);
+	});
+}
+
+#[test]
+fn ruling_splits_disputed_funds() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let statement = H256::repeat_byte(2);
+		assert_ok!(Escrow::open(RuntimeOrigin::signed(1), 2, 50, H256::zero(), 10));
+		assert_ok!(Escrow::open(RuntimeOrigin::signed(1), 2, 40, H256::zero(), 10));
+		assert_noop!(
+			Escrow::dispute(RuntimeOrigin::signed(1), 0, 51, statement),
+			Error::<Test, _>::ClaimTooHigh
+		);
+
+		// The buyer claims a partial refund, the provider claims payment.
+		assert_ok!(Escrow::dispute(RuntimeOrigin::signed(1), 0, 20, statement));
+		System::assert_last_event(Event::Disputed { escrow_id: 0, lawsuit_id: 0 }.into());
+		assert_ok!(Escrow::dispute(RuntimeOrigin::signed(2), 1, 40, statement));
+		assert_eq!(Filed::get(), vec![(1, 2, statement), (2, 1, statement)]);
+		assert_noop!(
+			Escrow::agree_release(RuntimeOrigin::signed(1), 0),
+			Error::<Test, _>::Disputed
+		);
+		System::set_block_number(11);
+		assert_noop!(Escrow::refund(RuntimeOrigin::signed(1), 0), Error::<Test, _>::Disputed);
+
+		rule(0, true);
+		System::assert_last_event(
+			Event::Settled { escrow_id: 0, to_buyer: 20, to_provider: 30 }.into(),
+		);
+		assert_eq!(Balances::free_balance(1), 30);
+		assert_eq!(Balances::free_balance(2), 40);
+
+		rule(1, false);
+		System::assert_last_event(
+			Event::Settled { escrow_id: 1, to_buyer: 40, to_provider: 0 }.into(),
+		);
+		assert_eq!(Balances::free_balance(1), 70);
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert!(Escrow::escrows(1).is_none() && Escrow::disputes(1).is_none());
+	});
+}
+
+#[test]
+fn court_rulings_settle_and_reversals_claw_back() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let statement = note(b"late delivery");
+		assert_ok!(CourtEscrow::open(RuntimeOrigin::signed(1), 2, 50, H256::zero(), 10));
+		assert_ok!(CourtEscrow::dispute(RuntimeOrigin::signed(1), 0, 20, statement));
+		for voter in 3..7 {
+			assert_ok!(Court::vote_sue(RuntimeOrigin::signed(voter), 0, true));
+		}
+		assert_ok!(Court::process_sue(RuntimeOrigin::signed(3), 0));
+		System::assert_has_event(
+			Event::<Test, Instance1>::Settled { escrow_id: 0, to_buyer: 20, to_provider: 30 }
+				.into(),
+		);
+		assert_eq!(CourtEscrow::awards(0), Some((0, 20)));
+		assert_eq!(Balances::free_balance(1), 70);
+		assert_eq!(Balances::free_balance(2), 40);
+
+		// The buyer returns the claim to the provider.
+		assert_ok!(Court::reverse_ruling(RuntimeOrigin::root(), 0, H256::zero()));
+		System::assert_has_event(
+			Event::<Test, Instance1>::ClaimClawedBack { escrow_id: 0, amount: 20 }.into(),
+		);
+		assert_eq!(Balances::free_balance(1), 50);
+		assert_eq!(Balances::free_balance(2), 60);
+		assert!(CourtEscrow::awards(0).is_none());
+	});
+}
+
+#[test]
+fn stalled_disputes_are_refunded() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let statement = note(b"not delivered");
+		assert_ok!(CourtEscrow::open(RuntimeOrigin::signed(1), 2, 30, H256::zero(), 10));
+		assert_ok!(CourtEscrow::dispute(RuntimeOrigin::signed(2), 0, 30, statement));
+
+		System::set_block_number(20);
+		assert_noop!(
+			CourtEscrow::refund(RuntimeOrigin::signed(3), 0),
+			Error::<Test, Instance1>::Disputed
+		);
+		System::set_block_number(21);
+		assert_ok!(CourtEscrow::refund(RuntimeOrigin::signed(3), 0));
+		assert_eq!(Balances::free_balance(1), 100);
+		assert!(CourtEscrow::disputes(0).is_none());
+
+		// A late ruling is ignored.
+		for voter in 3..7 {
+			assert_ok!(Court::vote_sue(RuntimeOrigin::signed(voter), 0, true));
+		}
+		assert_ok!(Court::process_sue(RuntimeOrigin::signed(3), 0));
+		assert_eq!(Balances::free_balance(1), 100);
+		assert_eq!(Balances::free_balance(2), 10);
+	});
+}
+
+#[test]
+fn withdrawn_disputes_leave_the_escrow_undisputed() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let statement = note(b"not delivered");
+		assert_ok!(CourtEscrow::open(RuntimeOrigin::signed(1), 2, 50, H256::zero(), 10));
+		assert_ok!(CourtEscrow::dispute(RuntimeOrigin::signed(1), 0, 50, statement));
+
+		// The funds are neither refunded nor paid to the provider.
+		assert_ok!(Court::withdraw_sue(RuntimeOrigin::signed(1), 0));
+		System::assert_has_event(
+			Event::<Test, Instance1>::DisputeWithdrawn { escrow_id: 0, lawsuit_id: 0 }.into(),
+		);
+		assert_eq!(CourtEscrow::escrows(0).unwrap().dispute, None);
+		assert!(CourtEscrow::disputes(0).is_none());
+		assert_eq!(Balances::reserved_balance(1), 50);
+		assert_eq!(Balances::free_balance(2), 10);
+
+		assert_ok!(CourtEscrow::agree_release(RuntimeOrigin::signed(1), 0));
+		assert_ok!(CourtEscrow::agree_release(RuntimeOrigin::signed(2), 0));
+		assert_eq!(Balances::free_balance(2), 60);
+	});
+}
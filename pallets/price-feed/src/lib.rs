@@ -0,0 +1,197 @@
+// Copyright (C) SaaS3.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Price Feed Pallet
+//!
+//! The Price Feed pallet holds the prices of assets in a reference currency, fed by SaaS3 oracle
+//! accounts approved by governance.
+//!
+//! - [`Config`]
+//! - [`Call`]
+//!
+//! ## Overview
+//!
+//! Each price is stored with the block it was fed at. Prices older than `MaxPriceAge` are not
+//! served to other pallets. The court uses the prices to fix the value of claims denominated in
+//! the reference currency.
+//!
+//! ## Interface
+//!
+//! ### Dispatchable Functions
+//!
+//! - `add_feeder` - Allow an account to feed prices.
+//! - `remove_feeder` - Stop an account from feeding prices.
+//! - `feed_price` - Feed the price of an asset.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(test)]
+mod tests;
+
+use codec::{Decode, Encode};
+use scale_info::TypeInfo;
+
+use sp_runtime::{
+	traits::{Saturating, StaticLookup, Zero},
+	FixedU128, RuntimeDebug,
+};
+
+use frame_support::{traits::Get, weights::Weight};
+
+pub use pallet::*;
+
+type AccountIdLookupOf<T> = <<T as frame_system::Config>::Lookup as StaticLookup>::Source;
+
+/// A price and the block it was fed at.
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct PricePoint<BlockNumber> {
+	/// The price of one unit of the asset in the reference currency.
+	pub price: FixedU128,
+	/// The block the price was fed at.
+	pub updated_at: BlockNumber,
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+
+	#[pallet::pallet]
+	#[pallet::without_storage_info]
+	pub struct Pallet<T, I = ()>(PhantomData<(T, I)>);
+
+	#[pallet::config]
+	pub trait Config<I: 'static = ()>: frame_system::Config {
+		/// The overarching event type.
+		type RuntimeEvent: From<Event<Self, I>>
+			+ IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Identifier of the assets priced, `None` is the native currency.
+		type AssetId: Member + Parameter + Copy + MaxEncodedLen;
+
+		/// The origin that manages feeders.
+		type AdminOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// The number of blocks after which a price is stale and no longer served.
+		#[pallet::constant]
+		type MaxPriceAge: Get<Self::BlockNumber>;
+	}
+
+	/// Accounts allowed to feed prices.
+	#[pallet::storage]
+	#[pallet::getter(fn feeders)]
+	pub type Feeders<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Twox64Concat, T::AccountId, bool, ValueQuery>;
+
+	/// The last price fed for each asset.
+	#[pallet::storage]
+	#[pallet::getter(fn prices)]
+	pub type Prices<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Twox64Concat, Option<T::AssetId>, PricePoint<T::BlockNumber>, OptionQuery>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config<I>, I: 'static = ()> {
+		/// An account can feed prices.
+		FeederAdded { feeder: T::AccountId },
+		/// An account can no longer feed prices.
+		FeederRemoved { feeder: T::AccountId },
+		/// A price has been fed.
+		PriceFed { asset: Option<T::AssetId>, price: FixedU128 },
+	}
+
+	#[pallet::error]
+	pub enum Error<T, I = ()> {
+		/// The account is already a feeder.
+		AlreadyFeeder,
+		/// The account is not a feeder.
+		NotFeeder,
+		/// A price cannot be zero.
+		ZeroPrice,
+	}
+
+	#[pallet::call]
+	impl<T: Config<I>, I: 'static> Pallet<T, I> {
+		/// Allow an account to feed prices.
+		///
+		/// May only be called from `T::AdminOrigin`.
+		#[pallet::call_index(0)]
+		#[pallet::weight(Weight::zero())]
+		pub fn add_feeder(origin: OriginFor<T>, feeder: AccountIdLookupOf<T>) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+			let feeder = T::Lookup::lookup(feeder)?;
+			ensure!(!Self::feeders(&feeder), Error::<T, I>::AlreadyFeeder);
+
+			Feeders::<T, I>::insert(&feeder, true);
+
+			Self::deposit_event(Event::FeederAdded { feeder });
+			Ok(())
+		}
+
+		/// Stop an account from feeding prices. The prices it fed are kept.
+		///
+		/// May only be called from `T::AdminOrigin`.
+		#[pallet::call_index(1)]
+		#[pallet::weight(Weight::zero())]
+		pub fn remove_feeder(origin: OriginFor<T>, feeder: AccountIdLookupOf<T>) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+			let feeder = T::Lookup::lookup(feeder)?;
+			ensure!(Self::feeders(&feeder), Error::<T, I>::NotFeeder);
+
+			Feeders::<T, I>::remove(&feeder);
+
+			Self::deposit_event(Event::FeederRemoved { feeder });
+			Ok(())
+		}
+
+		/// Feed the price of one unit of `asset` in the reference currency.
+		///
+		/// - `asset`: The asset priced, `None` for the native currency.
+		#[pallet::call_index(2)]
+		#[pallet::weight(Weight::zero())]
+		pub fn feed_price(
+			origin: OriginFor<T>,
+			asset: Option<T::AssetId>,
+			price: FixedU128,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(Self::feeders(&who), Error::<T, I>::NotFeeder);
+			ensure!(!price.is_zero(), Error::<T, I>::ZeroPrice);
+
+			let updated_at = frame_system::Pallet::<T>::block_number();
+			Prices::<T, I>::insert(asset, PricePoint { price, updated_at });
+
+			Self::deposit_event(Event::PriceFed { asset, price });
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config<I>, I: 'static> Pallet<T, I> {
+	/// The price of `asset` if it is not older than `MaxPriceAge`.
+	pub fn price(asset: Option<T::AssetId>) -> Option<FixedU128> {
+		let point = Self::prices(asset)?;
+		let now = frame_system::Pallet::<T>::block_number();
+		(now.saturating_sub(point.updated_at) <= T::MaxPriceAge::get()).then_some(point.price)
+	}
+}
+
+impl<T: Config<I>, I: 'static> pallet_court::PriceProvider<T::AssetId> for Pallet<T, I> {
+	fn price(asset: Option<T::AssetId>) -> Option<FixedU128> {
+		Self::price(asset)
+	}
+}
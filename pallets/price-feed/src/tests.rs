@@ -0,0 +1,141 @@
+// Copyright (C) SaaS3.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Price feed pallet tests.
+
+#![cfg(test)]
+
+use sp_core::H256;
+use sp_runtime::{
+	testing::Header,
+	traits::{BlakeTwo256, IdentityLookup},
+	FixedPointNumber,
+};
+
+use frame_support::{
+	assert_noop, assert_ok,
+	traits::{ConstU32, ConstU64},
+};
+
+use super::*;
+use crate as price_feed;
+use pallet_court::PriceProvider;
+
+use frame_system::EnsureRoot;
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
+type Block = frame_system::mocking::MockBlock<Test>;
+
+frame_support::construct_runtime!(
+	pub enum Test where
+		Block = Block,
+		NodeBlock = Block,
+		UncheckedExtrinsic = UncheckedExtrinsic,
+	{
+		System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
+		PriceFeed: price_feed::{Pallet, Call, Storage, Event<T>},
+	}
+);
+
+impl frame_system::Config for Test {
+	type BaseCallFilter = frame_support::traits::Everything;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type DbWeight = ();
+	type RuntimeOrigin = RuntimeOrigin;
+	type Index = u64;
+	type BlockNumber = u64;
+	type RuntimeCall = RuntimeCall;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = u128;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Header = Header;
+	type RuntimeEvent = RuntimeEvent;
+	type BlockHashCount = ConstU64<250>;
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = ();
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = ();
+	type OnSetCode = ();
+	type MaxConsumers = ConstU32<16>;
+}
+
+impl Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type AssetId = u32;
+	type AdminOrigin = EnsureRoot<u128>;
+	type MaxPriceAge = ConstU64<10>;
+}
+
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	let t = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
+	t.into()
+}
+
+#[test]
+fn only_feeders_feed_prices() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let price = FixedU128::saturating_from_rational(3, 2);
+		assert_noop!(
+			PriceFeed::feed_price(RuntimeOrigin::signed(1), None, price),
+			Error::<Test, _>::NotFeeder
+		);
+		assert_noop!(
+			PriceFeed::add_feeder(RuntimeOrigin::signed(1), 1),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert_ok!(PriceFeed::add_feeder(RuntimeOrigin::root(), 1));
+		assert_noop!(
+			PriceFeed::add_feeder(RuntimeOrigin::root(), 1),
+			Error::<Test, _>::AlreadyFeeder
+		);
+
+		assert_noop!(
+			PriceFeed::feed_price(RuntimeOrigin::signed(1), None, FixedU128::zero()),
+			Error::<Test, _>::ZeroPrice
+		);
+		assert_ok!(PriceFeed::feed_price(RuntimeOrigin::signed(1), None, price));
+		System::assert_last_event(Event::PriceFed { asset: None, price }.into());
+		assert_eq!(<PriceFeed as PriceProvider<u32>>::price(None), Some(price));
+		assert_eq!(<PriceFeed as PriceProvider<u32>>::price(Some(1)), None);
+
+		assert_ok!(PriceFeed::remove_feeder(RuntimeOrigin::root(), 1));
+		assert_noop!(
+			PriceFeed::feed_price(RuntimeOrigin::signed(1), Some(1), price),
+			Error::<Test, _>::NotFeeder
+		);
+	});
+}
+
+#[test]
+fn stale_prices_are_not_served() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		assert_ok!(PriceFeed::add_feeder(RuntimeOrigin::root(), 1));
+		let price = FixedU128::saturating_from_integer(2);
+		assert_ok!(PriceFeed::feed_price(RuntimeOrigin::signed(1), Some(1), price));
+
+		System::set_block_number(11);
+		assert_eq!(PriceFeed::price(Some(1)), Some(price));
+		System::set_block_number(12);
+		assert_eq!(PriceFeed::price(Some(1)), None);
+		assert_eq!(PriceFeed::prices(Some(1)).unwrap().updated_at, 1);
+	});
+}
@@ -40,6 +40,9 @@
 //! - `receive` - Receiving funds from outside
 //! - `claim_rewards` - Jury claim their rewards
 //!
+//! Fees and slashed stake received from the court are recorded under `COURT_CATEGORY`, and
+//! `InsuranceShare` of them tops up the insurance pool covering awards defendants cannot pay.
+//!
 //! ## GenesisConfig
 //!
 //! The Treasury pallet depends on the [`GenesisConfig`].
@@ -53,8 +56,8 @@ use codec::{Decode, Encode};
 use scale_info::TypeInfo;
 
 use sp_runtime::{
-	traits::{AccountIdConversion, Saturating},
-	RuntimeDebug,
+	traits::{AccountIdConversion, CheckedSub, Saturating, Zero},
+	Perbill, RuntimeDebug,
 };
 use sp_std::prelude::*;
 
@@ -72,6 +75,9 @@ pub type BalanceOf<T, I = ()> =
 /// An index of a record. Just a `u32`.
 pub type RecordIndex = u32;
 
+/// The `category_type` reserved for fees and slashed stake received from the court.
+pub const COURT_CATEGORY: u32 = u32::MAX;
+
 #[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
 pub struct Record<AccountId, Balance> {
@@ -104,6 +110,10 @@ pub mod pallet {
 		/// The treasury's pallet id, used for deriving its sovereign account ID.
 		#[pallet::constant]
 		type PalletId: Get<PalletId>;
+
+		/// The share of the income from the court that tops up the insurance pool.
+		#[pallet::constant]
+		type InsuranceShare: Get<Perbill>;
 	}
 
 	/// Number of proposals that have been made.
@@ -184,6 +194,10 @@ pub mod pallet {
 			award: BalanceOf<T, I>,
 			account: T::AccountId,
 		},
+		/// The insurance pool has been topped up from the pot.
+		InsuranceFunded {
+			amount: BalanceOf<T, I>,
+		},
 	}
 
 	/// Error for the treasury pallet.
@@ -197,6 +211,8 @@ pub mod pallet {
 		/// Rewards claim has not been approved.
 		ClaimNotApproved,
 		ExceedClaim,
+		/// The category is reserved for income from the court.
+		ReservedCategory,
 	}
 
 	#[pallet::hooks]
@@ -212,7 +228,7 @@ pub mod pallet {
 		///
 		/// - `origin`:
 		/// - `amount`: The amount to be transferred from origin to the treasury pot.
-		/// - `category_type`: The source type of funds
+		/// - `category_type`: The source type of funds, `COURT_CATEGORY` is reserved
 		///
 		#[pallet::call_index(0)]
 		#[pallet::weight(Weight::zero())]
@@ -222,6 +238,7 @@ pub mod pallet {
 			category_type: u32,
 		) -> DispatchResult {
 			let sender = ensure_signed(origin.clone())?;
+			ensure!(category_type != COURT_CATEGORY, Error::<T, I>::ReservedCategory);
 			ensure!(
 				<T as pallet::Config<I>>::Currency::free_balance(&sender) >= amount,
 				Error::<T, I>::InsufficientFund
@@ -229,13 +246,7 @@ pub mod pallet {
 			let to = Self::account_id();
 			<T as pallet::Config<I>>::Currency::transfer(&sender, &to, amount, KeepAlive)?;
 
-			// record funds
-			let c = Self::record_count();
-			<RecordCount<T, I>>::put(c + 1);
-			let record = Record { funder: sender, value: amount, category_type };
-			<Records<T, I>>::insert(c, record);
-
-			Self::deposit_event(Event::Deposit { value: (amount) });
+			Self::record(sender, amount, category_type);
 			Ok(())
 		}
 
@@ -268,8 +279,9 @@ pub mod pallet {
 			// calculate rewards - claimed rewards
 			let c = Claims::<T, I>::get(&beneficiary);
 			if let Some(c) = c {
-				// there are claims
-				ensure!(bc - c >= amount, Error::<T, I>::ExceedClaim);
+				// there are claims, a reversed ruling can lower the contribution below them
+				let unclaimed = bc.checked_sub(&c).ok_or(Error::<T, I>::ExceedClaim)?;
+				ensure!(unclaimed >= amount, Error::<T, I>::ExceedClaim);
 				Claims::<T, I>::mutate(&beneficiary, |v| *v = Some(c + amount));
 			} else {
 				ensure!(bc >= amount, Error::<T, I>::ExceedClaim);
@@ -300,6 +312,11 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		T::PalletId::get().into_account_truncating()
 	}
 
+	/// The account ID of the insurance pool, a sub-account of the treasury.
+	pub fn insurance_account() -> T::AccountId {
+		T::PalletId::get().into_sub_account_truncating(b"insurance")
+	}
+
 	/// Return the amount of money in the pot.
 	// The existential deposit is not part of the pot so treasury account never gets deleted.
 	pub fn pot() -> BalanceOf<T, I> {
@@ -307,4 +324,42 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			// Must never be less than 0 but better be safe.
 			.saturating_sub(<T as pallet::Config<I>>::Currency::minimum_balance())
 	}
+
+	/// Record funds received by the pot.
+	fn record(funder: T::AccountId, value: BalanceOf<T, I>, category_type: u32) {
+		let c = Self::record_count();
+		<RecordCount<T, I>>::put(c + 1);
+		<Records<T, I>>::insert(c, Record { funder, value, category_type });
+
+		Self::deposit_event(Event::Deposit { value });
+	}
+}
+
+impl<T: Config<I>, I: 'static> pallet_court::CourtTreasury<T::AccountId, BalanceOf<T, I>>
+	for Pallet<T, I>
+{
+	fn account_id() -> T::AccountId {
+		Self::account_id()
+	}
+
+	fn on_court_income(source: &T::AccountId, amount: BalanceOf<T, I>) {
+		Self::record(source.clone(), amount, COURT_CATEGORY);
+
+		let share = T::InsuranceShare::get() * amount;
+		if !share.is_zero() &&
+			<T as pallet::Config<I>>::Currency::transfer(
+				&Self::account_id(),
+				&Self::insurance_account(),
+				share,
+				KeepAlive,
+			)
+			.is_ok()
+		{
+			Self::deposit_event(Event::InsuranceFunded { amount: share });
+		}
+	}
+
+	fn insurance_account() -> T::AccountId {
+		Self::insurance_account()
+	}
 }
@@ -20,14 +20,16 @@
 use sp_core::H256;
 use sp_runtime::{
 	testing::Header,
-	traits::{BlakeTwo256, IdentityLookup},
+	traits::{BlakeTwo256, Hash, IdentityLookup},
+	Perbill,
 };
 
 use frame_support::{
 	assert_noop, assert_ok,
 	pallet_prelude::GenesisBuild,
 	parameter_types,
-	traits::{ConstU32, ConstU64, OnInitialize},
+	traits::{AsEnsureOriginWithArg, ConstU32, ConstU64, Nothing, OnInitialize, Randomness},
+	weights::Weight,
 	PalletId,
 };
 
@@ -35,6 +37,8 @@ use super::*;
 use crate as treasury;
 use pallet_court as court;
 
+use frame_system::{EnsureRoot, EnsureSigned};
+
 type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
 type Block = frame_system::mocking::MockBlock<Test>;
 
@@ -47,8 +51,10 @@ frame_support::construct_runtime!(
 		System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
 		Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
 		Treasury: treasury::{Pallet, Call, Storage, Config, Event<T>},
-		Court: court::{Pallet, Call, Storage, Config, Event<T>},
+		Court: court::{Pallet, Call, Storage, Config, Event<T>, Origin<T>},
 		Utility: pallet_utility,
+		Preimage: pallet_preimage,
+		Assets: pallet_assets,
 	}
 );
 
@@ -98,20 +104,129 @@ impl pallet_utility::Config for Test {
 	type WeightInfo = ();
 }
 
+impl pallet_preimage::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type WeightInfo = ();
+	type Currency = Balances;
+	type ManagerOrigin = EnsureRoot<u128>;
+	type BaseDeposit = ConstU64<2>;
+	type ByteDeposit = ConstU64<1>;
+}
+
+impl pallet_assets::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Balance = u64;
+	type AssetId = u32;
+	type AssetIdParameter = codec::Compact<u32>;
+	type Currency = Balances;
+	type CreateOrigin = AsEnsureOriginWithArg<EnsureSigned<u128>>;
+	type ForceOrigin = EnsureRoot<u128>;
+	type AssetDeposit = ConstU64<1>;
+	type AssetAccountDeposit = ConstU64<1>;
+	type MetadataDepositBase = ConstU64<1>;
+	type MetadataDepositPerByte = ConstU64<1>;
+	type ApprovalDeposit = ConstU64<1>;
+	type StringLimit = ConstU32<50>;
+	type Freezer = ();
+	type Extra = ();
+	type CallbackHandle = ();
+	type WeightInfo = ();
+	type RemoveItemsLimit = ConstU32<5>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = ();
+}
+
+pub struct TestRandomness;
+impl Randomness<H256, u64> for TestRandomness {
+	fn random(subject: &[u8]) -> (H256, u64) {
+		(H256::from(sp_io::hashing::blake2_256(subject)), System::block_number())
+	}
+}
+
 parameter_types! {
 	pub const TreasuryPalletId: PalletId = PalletId(*b"py/trsry");
+	pub const IncoherenceSlash: Perbill = Perbill::from_percent(10);
+	pub const ReputationDecay: Perbill = Perbill::from_percent(10);
+	pub const NoShowSlash: Perbill = Perbill::from_percent(10);
+	pub const AwardHoldback: Perbill = Perbill::zero();
+	pub const MisconductSlash: Perbill = Perbill::from_percent(50);
+	pub const MaxRemedyWeight: Weight = Weight::from_parts(1_000_000_000_000, 0);
+	pub const TreasuryShare: Perbill = Perbill::from_percent(50);
+	pub static FilingFee: u64 = 0;
+	pub static InsuranceShare: Perbill = Perbill::zero();
 }
 
 impl pallet_court::Config for Test {
 	type Currency = pallet_balances::Pallet<Test>;
 	type RuntimeEvent = RuntimeEvent;
 	type MaxApprovals = ConstU32<100>;
+	type MaxDeferredApprovals = ConstU32<100>;
+	type MaxPayoutAttempts = ConstU32<3>;
+	type Randomness = TestRandomness;
+	type JurySize = ConstU32<0>;
+	type MaxJurors = ConstU32<10>;
+	type MaxDrawAttempts = ConstU32<2>;
+	type MinJurorBond = ConstU64<10>;
+	type UnbondingPeriod = ConstU64<10>;
+	type IncoherenceSlash = IncoherenceSlash;
+	type Preimages = Preimage;
+	type CourtAdminOrigin = EnsureRoot<u128>;
+	type InitialReputation = ConstU32<50>;
+	type MaxReputation = ConstU32<100>;
+	type ReputationGain = ConstU32<10>;
+	type ReputationLoss = ConstU32<20>;
+	type ReputationDecay = ReputationDecay;
+	type ReputationDecayPeriod = ConstU64<10>;
+	type AssetId = u32;
+	type Assets = Assets;
+	type Treasury = Treasury;
+	type FilingFee = FilingFee;
+	type AppealFee = ConstU64<10>;
+	type AppealPeriod = ConstU64<0>;
+	type MaxAppeals = ConstU32<2>;
+	type TreasuryShare = TreasuryShare;
+	type CaseDeposit = ConstU64<0>;
+	type RetentionPeriod = ConstU64<10>;
+	type MaxOpenSuits = ConstU32<100>;
+	type MaxOpenSuitsPerDefendant = ConstU32<100>;
+	type RejectionCooldown = ConstU64<0>;
+	type MaxTags = ConstU32<4>;
+	type MaxTagLength = ConstU32<16>;
+	type MaxAmendments = ConstU32<3>;
+	type MediationFee = ConstU64<10>;
+	type MediationPeriod = ConstU64<5>;
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeCall = RuntimeCall;
+	type RemedyFilter = Nothing;
+	type MaxRemedyLength = ConstU32<64>;
+	type MaxRemedyWeight = MaxRemedyWeight;
+	type VotingPeriod = ConstU64<5>;
+	type NoShowSlash = NoShowSlash;
+	type MaxRedraws = ConstU32<1>;
+	type MaxAbsences = ConstU32<2>;
+	type SuspensionPeriod = ConstU64<20>;
+	type Prices = ();
+	type OnLawsuitFiled = ();
+	type OnVoteCast = ();
+	type OnRuled = ();
+	type OnRulingExecuted = ();
+	type OnAppealed = ();
+	type AwardHoldback = AwardHoldback;
+	type InsuranceCap = ConstU64<0>;
+	type MisconductCourt = Court;
+	type MisconductBond = ConstU64<20>;
+	type MisconductTimeout = ConstU64<10>;
+	type MisconductSlash = MisconductSlash;
+	type HigherCourt = ();
+	type EscalationTimeout = ConstU64<10>;
+	type MaxSummaryLength = ConstU32<16>;
 }
 
 impl Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type PalletId = TreasuryPalletId;
 	type Currency = pallet_balances::Pallet<Test>;
+	type InsuranceShare = InsuranceShare;
 }
 
 pub fn new_test_ext() -> sp_io::TestExternalities {
@@ -126,6 +241,12 @@ pub fn new_test_ext() -> sp_io::TestExternalities {
 	t.into()
 }
 
+/// Note `statement` without a deposit, returning its hash.
+fn note(statement: &[u8]) -> H256 {
+	assert_ok!(Preimage::note_preimage(RuntimeOrigin::root(), statement.to_vec()));
+	BlakeTwo256::hash(statement)
+}
+
 #[test]
 fn genesis_config_works() {
 	new_test_ext().execute_with(|| {
@@ -172,7 +293,17 @@ fn claim_rewards_ok() {
 		// funding by user 1
 		assert_ok!(Treasury::receive(RuntimeOrigin::signed(1), 10, 1));
 		// user 11 submit sue
-		assert_ok!(Court::submit_sue(RuntimeOrigin::signed(11), 20, 0, vec![]));
+		assert_ok!(Court::submit_sue(
+			RuntimeOrigin::signed(11),
+			20,
+			0,
+			note(b""),
+			0,
+			None,
+			None,
+			false,
+			false
+		));
 		// jury 2, 3, 4, 5 vote
 		assert_ok!(Court::vote_sue(RuntimeOrigin::signed(2), 0, true));
 		assert_ok!(Court::vote_sue(RuntimeOrigin::signed(3), 0, false));
@@ -191,5 +322,66 @@ fn claim_rewards_ok() {
 			Treasury::claim_rewards(RuntimeOrigin::signed(2), 1),
 			Error::<Test, _>::ExceedClaim
 		);
+
+		// the reversal leaves less contribution than was claimed
+		assert_ok!(Court::reverse_ruling(RuntimeOrigin::root(), 0, H256::zero()));
+		assert_eq!(Court::contribution(2), 0);
+		assert_noop!(
+			Treasury::claim_rewards(RuntimeOrigin::signed(2), 0),
+			Error::<Test, _>::ExceedClaim
+		);
+	});
+}
+
+#[test]
+fn court_income_is_recorded_under_court_category() {
+	new_test_ext().execute_with(|| {
+		FilingFee::set(&5);
+		assert_ok!(Court::submit_sue(
+			RuntimeOrigin::signed(1),
+			20,
+			0,
+			note(b""),
+			0,
+			None,
+			None,
+			false,
+			false
+		));
+		assert_eq!(Treasury::pot(), 5);
+		assert_eq!(
+			Treasury::records(0),
+			Some(Record { funder: 1, value: 5, category_type: COURT_CATEGORY })
+		);
+
+		// Donations cannot be disguised as court income.
+		assert_noop!(
+			Treasury::receive(RuntimeOrigin::signed(1), 10, COURT_CATEGORY),
+			Error::<Test, _>::ReservedCategory
+		);
+	});
+}
+
+#[test]
+fn court_income_tops_up_insurance() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		FilingFee::set(&10);
+		InsuranceShare::set(&Perbill::from_percent(50));
+		assert_ok!(Court::submit_sue(
+			RuntimeOrigin::signed(1),
+			20,
+			0,
+			note(b""),
+			0,
+			None,
+			None,
+			false,
+			false
+		));
+		assert_eq!(Treasury::pot(), 5);
+		assert_eq!(Balances::free_balance(Treasury::insurance_account()), 5);
+		System::assert_has_event(Event::InsuranceFunded { amount: 5 }.into());
+		assert_eq!(Court::insurance_status().balance, 4);
 	});
 }
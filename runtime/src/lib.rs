@@ -29,7 +29,7 @@ use frame_support::{
 	construct_runtime,
 	dispatch::DispatchClass,
 	parameter_types,
-	traits::{ConstU32, ConstU64, ConstU8, Everything},
+	traits::{AsEnsureOriginWithArg, ConstU32, ConstU64, ConstU8, Everything, Nothing},
 	weights::{
 		constants::WEIGHT_REF_TIME_PER_SECOND, ConstantMultiplier, Weight, WeightToFeeCoefficient,
 		WeightToFeeCoefficients, WeightToFeePolynomial,
@@ -38,7 +38,7 @@ use frame_support::{
 };
 use frame_system::{
 	limits::{BlockLength, BlockWeights},
-	EnsureRoot,
+	EnsureRoot, EnsureSigned,
 };
 pub use sp_consensus_aura::sr25519::AuthorityId as AuraId;
 pub use sp_runtime::{MultiAddress, Perbill, Permill};
@@ -119,8 +119,15 @@ pub type Executive = frame_executive::Executive<
 	frame_system::ChainContext<Runtime>,
 	Runtime,
 	AllPalletsWithSystem,
+	Migrations,
 >;
 
+/// Migrations to apply on runtime upgrade.
+pub type Migrations = (
+	pallet_court::migrations::MigrateToV1<Runtime>,
+	pallet_court::migrations::MigrateToV1<Runtime, pallet_court::Instance1>,
+);
+
 /// Handles converting a weight scalar to a fee value, based on the scale and granularity of the
 /// node's balance type.
 ///
@@ -176,7 +183,7 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
 	spec_name: create_runtime_str!("saas3-dao-local"),
 	impl_name: create_runtime_str!("saaas3-dao-local"),
 	authoring_version: 1,
-	spec_version: 1,
+	spec_version: 2,
 	impl_version: 0,
 	apis: RUNTIME_API_VERSIONS,
 	transaction_version: 1,
@@ -259,8 +266,62 @@ parameter_types! {
 	// pallet treasury
 	pub const TreasuryPalletId: PalletId = PalletId(*b"py/trsry");
 	pub const MaxApprovals: u32 = 100;
+	pub const MaxDeferredApprovals: u32 = 1000;
+	pub const MaxPayoutAttempts: u32 = 10;
 	pub const MaxBalance: Balance = Balance::max_value();
 	// pallet court
+	pub const JurySize: u32 = 5;
+	pub const MinJurorBond: Balance = 10 * UNIT;
+	pub const JurorUnbondingPeriod: BlockNumber = 7 * DAYS;
+	pub const IncoherenceSlash: Perbill = Perbill::from_percent(10);
+	pub const InitialReputation: u32 = 100;
+	pub const MaxReputation: u32 = 1000;
+	pub const ReputationGain: u32 = 10;
+	pub const ReputationLoss: u32 = 30;
+	pub const ReputationDecay: Perbill = Perbill::from_percent(5);
+	pub const ReputationDecayPeriod: BlockNumber = 30 * DAYS;
+	pub const FilingFee: Balance = UNIT;
+	pub const AppealFee: Balance = 10 * UNIT;
+	pub const AppealPeriod: BlockNumber = 2 * DAYS;
+	pub const MaxAppeals: u32 = 3;
+	pub const CourtTreasuryShare: Perbill = Perbill::from_percent(20);
+	pub const CaseDeposit: Balance = UNIT;
+	pub const CaseRetentionPeriod: BlockNumber = 30 * DAYS;
+	pub const MaxOpenSuits: u32 = 20;
+	pub const MaxOpenSuitsPerDefendant: u32 = 3;
+	pub const RejectionCooldown: BlockNumber = DAYS;
+	pub const MediationFee: Balance = 5 * UNIT;
+	pub const MediationPeriod: BlockNumber = 3 * DAYS;
+	pub const JuryVotingPeriod: BlockNumber = 3 * DAYS;
+	pub const NoShowSlash: Perbill = Perbill::from_percent(5);
+	pub const AwardHoldback: Perbill = Perbill::from_percent(10);
+	pub const InsuranceCap: Balance = 1_000 * UNIT;
+	pub const InsuranceShare: Perbill = Perbill::from_percent(25);
+	pub const MisconductBond: Balance = 50 * UNIT;
+	pub const MisconductTimeout: BlockNumber = 60 * DAYS;
+	pub const EscalationTimeout: BlockNumber = 60 * DAYS;
+	pub const MisconductSlash: Perbill = Perbill::from_percent(50);
+	pub const JurorSuspensionPeriod: BlockNumber = 30 * DAYS;
+	pub MaxRemedyWeight: Weight = Perbill::from_percent(10) * MAXIMUM_BLOCK_WEIGHT;
+	// pallet court, higher court instance. Until it has `HigherJurySize` jurors, appeals are
+	// ruled on by the court itself and misconduct cannot be reported.
+	pub const HigherJurySize: u32 = 11;
+	pub const HigherMinJurorBond: Balance = 100 * UNIT;
+	pub const HigherFilingFee: Balance = 10 * UNIT;
+	pub const HigherAppealFee: Balance = 100 * UNIT;
+	// pallet preimage
+	pub const PreimageBaseDeposit: Balance = UNIT;
+	pub const PreimageByteDeposit: Balance = 10 * MICROUNIT;
+	// pallet assets
+	pub const AssetDeposit: Balance = 10 * UNIT;
+	pub const AssetAccountDeposit: Balance = MILLIUNIT;
+	pub const MetadataDepositBase: Balance = UNIT;
+	pub const MetadataDepositPerByte: Balance = 10 * MICROUNIT;
+	pub const ApprovalDeposit: Balance = MILLIUNIT;
+	// pallet price feed
+	pub const MaxPriceAge: BlockNumber = HOURS;
+	// pallet escrow
+	pub const EscrowDisputeTimeout: BlockNumber = 90 * DAYS;
 }
 
 // Configure FRAME pallets to include in runtime.
@@ -315,6 +376,8 @@ impl frame_system::Config for Runtime {
 	type MaxConsumers = frame_support::traits::ConstU32<16>;
 }
 
+impl pallet_insecure_randomness_collective_flip::Config for Runtime {}
+
 impl pallet_timestamp::Config for Runtime {
 	/// A timestamp: milliseconds since the unix epoch.
 	type Moment = u64;
@@ -462,16 +525,193 @@ impl pallet_template::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 }
 
+impl pallet_preimage::Config for Runtime {
+	type WeightInfo = pallet_preimage::weights::SubstrateWeight<Runtime>;
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Balances;
+	type ManagerOrigin = EnsureRoot<AccountId>;
+	type BaseDeposit = PreimageBaseDeposit;
+	type ByteDeposit = PreimageByteDeposit;
+}
+
+impl pallet_assets::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type Balance = Balance;
+	type AssetId = u32;
+	type AssetIdParameter = codec::Compact<u32>;
+	type Currency = Balances;
+	type CreateOrigin = AsEnsureOriginWithArg<EnsureSigned<AccountId>>;
+	type ForceOrigin = EnsureRoot<AccountId>;
+	type AssetDeposit = AssetDeposit;
+	type AssetAccountDeposit = AssetAccountDeposit;
+	type MetadataDepositBase = MetadataDepositBase;
+	type MetadataDepositPerByte = MetadataDepositPerByte;
+	type ApprovalDeposit = ApprovalDeposit;
+	type StringLimit = ConstU32<50>;
+	type Freezer = ();
+	type Extra = ();
+	type CallbackHandle = ();
+	type WeightInfo = pallet_assets::weights::SubstrateWeight<Runtime>;
+	type RemoveItemsLimit = ConstU32<1000>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = ();
+}
+
 impl pallet_treasury::Config for Runtime {
 	type Currency = Balances;
 	type RuntimeEvent = RuntimeEvent;
 	type PalletId = TreasuryPalletId;
+	type InsuranceShare = InsuranceShare;
+}
+
+impl pallet_price_feed::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type AssetId = u32;
+	type AdminOrigin = EnsureRoot<AccountId>;
+	type MaxPriceAge = MaxPriceAge;
+}
+
+impl pallet_escrow::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Balances;
+	type Arbitrator = Court;
+	type DisputeTimeout = EscrowDisputeTimeout;
 }
 
 impl pallet_court::Config for Runtime {
 	type Currency = Balances;
 	type RuntimeEvent = RuntimeEvent;
 	type MaxApprovals = MaxApprovals;
+	type MaxDeferredApprovals = MaxDeferredApprovals;
+	type MaxPayoutAttempts = MaxPayoutAttempts;
+	type Randomness = RandomnessCollectiveFlip;
+	type JurySize = JurySize;
+	type MaxJurors = ConstU32<1000>;
+	type MaxDrawAttempts = ConstU32<100>;
+	type MinJurorBond = MinJurorBond;
+	type UnbondingPeriod = JurorUnbondingPeriod;
+	type IncoherenceSlash = IncoherenceSlash;
+	type Preimages = Preimage;
+	type CourtAdminOrigin = EnsureRoot<AccountId>;
+	type InitialReputation = InitialReputation;
+	type MaxReputation = MaxReputation;
+	type ReputationGain = ReputationGain;
+	type ReputationLoss = ReputationLoss;
+	type ReputationDecay = ReputationDecay;
+	type ReputationDecayPeriod = ReputationDecayPeriod;
+	type AssetId = u32;
+	type Assets = Assets;
+	type Treasury = Treasury;
+	type FilingFee = FilingFee;
+	type AppealFee = AppealFee;
+	type AppealPeriod = AppealPeriod;
+	type MaxAppeals = MaxAppeals;
+	type TreasuryShare = CourtTreasuryShare;
+	type CaseDeposit = CaseDeposit;
+	type RetentionPeriod = CaseRetentionPeriod;
+	type MaxOpenSuits = MaxOpenSuits;
+	type MaxOpenSuitsPerDefendant = MaxOpenSuitsPerDefendant;
+	type RejectionCooldown = RejectionCooldown;
+	type MaxTags = ConstU32<8>;
+	type MaxTagLength = ConstU32<32>;
+	type MaxAmendments = ConstU32<5>;
+	type MediationFee = MediationFee;
+	type MediationPeriod = MediationPeriod;
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeCall = RuntimeCall;
+	// No pallet accepts the court origin yet.
+	type RemedyFilter = Nothing;
+	type MaxRemedyLength = ConstU32<1024>;
+	type MaxRemedyWeight = MaxRemedyWeight;
+	type VotingPeriod = JuryVotingPeriod;
+	type NoShowSlash = NoShowSlash;
+	type MaxRedraws = ConstU32<2>;
+	type MaxAbsences = ConstU32<3>;
+	type SuspensionPeriod = JurorSuspensionPeriod;
+	type Prices = PriceFeed;
+	type OnLawsuitFiled = ();
+	type OnVoteCast = ();
+	type OnRuled = ();
+	type OnRulingExecuted = Escrow;
+	type OnAppealed = ();
+	type AwardHoldback = AwardHoldback;
+	type InsuranceCap = InsuranceCap;
+	type MisconductCourt = HigherCourt;
+	type MisconductBond = MisconductBond;
+	type MisconductTimeout = MisconductTimeout;
+	type MisconductSlash = MisconductSlash;
+	type HigherCourt = HigherCourt;
+	type EscalationTimeout = EscalationTimeout;
+	type MaxSummaryLength = ConstU32<256>;
+}
+
+/// The court of appeal, also ruling on high-value cases filed with it directly.
+impl pallet_court::Config<pallet_court::Instance1> for Runtime {
+	type Currency = Balances;
+	type RuntimeEvent = RuntimeEvent;
+	type MaxApprovals = MaxApprovals;
+	type MaxDeferredApprovals = MaxDeferredApprovals;
+	type MaxPayoutAttempts = MaxPayoutAttempts;
+	type Randomness = RandomnessCollectiveFlip;
+	type JurySize = HigherJurySize;
+	type MaxJurors = ConstU32<1000>;
+	type MaxDrawAttempts = ConstU32<100>;
+	type MinJurorBond = HigherMinJurorBond;
+	type UnbondingPeriod = JurorUnbondingPeriod;
+	type IncoherenceSlash = IncoherenceSlash;
+	type Preimages = Preimage;
+	type CourtAdminOrigin = EnsureRoot<AccountId>;
+	type InitialReputation = InitialReputation;
+	type MaxReputation = MaxReputation;
+	type ReputationGain = ReputationGain;
+	type ReputationLoss = ReputationLoss;
+	type ReputationDecay = ReputationDecay;
+	type ReputationDecayPeriod = ReputationDecayPeriod;
+	type AssetId = u32;
+	type Assets = Assets;
+	type Treasury = Treasury;
+	type FilingFee = HigherFilingFee;
+	type AppealFee = HigherAppealFee;
+	type AppealPeriod = AppealPeriod;
+	type MaxAppeals = MaxAppeals;
+	type TreasuryShare = CourtTreasuryShare;
+	type CaseDeposit = CaseDeposit;
+	type RetentionPeriod = CaseRetentionPeriod;
+	type MaxOpenSuits = MaxOpenSuits;
+	type MaxOpenSuitsPerDefendant = MaxOpenSuitsPerDefendant;
+	type RejectionCooldown = RejectionCooldown;
+	type MaxTags = ConstU32<8>;
+	type MaxTagLength = ConstU32<32>;
+	type MaxAmendments = ConstU32<5>;
+	type MediationFee = MediationFee;
+	type MediationPeriod = MediationPeriod;
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeCall = RuntimeCall;
+	type RemedyFilter = Nothing;
+	type MaxRemedyLength = ConstU32<1024>;
+	type MaxRemedyWeight = MaxRemedyWeight;
+	type VotingPeriod = JuryVotingPeriod;
+	type NoShowSlash = NoShowSlash;
+	type MaxRedraws = ConstU32<2>;
+	type MaxAbsences = ConstU32<3>;
+	type SuspensionPeriod = JurorSuspensionPeriod;
+	type Prices = PriceFeed;
+	type OnLawsuitFiled = ();
+	type OnVoteCast = ();
+	type OnRuled = ();
+	// The higher court rules on the appeals and the misconduct of the jurors of both courts.
+	type OnRulingExecuted = (Court, HigherCourt);
+	type OnAppealed = ();
+	type AwardHoldback = AwardHoldback;
+	type InsuranceCap = InsuranceCap;
+	type MisconductCourt = HigherCourt;
+	type MisconductBond = MisconductBond;
+	type MisconductTimeout = MisconductTimeout;
+	type MisconductSlash = MisconductSlash;
+	// Appeals of the higher court are ruled on by a new jury of the higher court.
+	type HigherCourt = ();
+	type EscalationTimeout = EscalationTimeout;
+	type MaxSummaryLength = ConstU32<256>;
 }
 
 // Create the runtime by composing the FRAME pallets that were previously configured.
@@ -486,6 +726,7 @@ construct_runtime!(
 		ParachainSystem: cumulus_pallet_parachain_system = 1,
 		Timestamp: pallet_timestamp = 2,
 		ParachainInfo: parachain_info = 3,
+		RandomnessCollectiveFlip: pallet_insecure_randomness_collective_flip = 4,
 
 		// Monetary stuff.
 		Balances: pallet_balances = 10,
@@ -508,9 +749,15 @@ construct_runtime!(
 		// Template
 		TemplatePallet: pallet_template = 40,
 
+		Preimage: pallet_preimage = 45,
+		Assets: pallet_assets = 46,
+		PriceFeed: pallet_price_feed = 47,
+		Escrow: pallet_escrow = 48,
+
 		Treasury: pallet_treasury = 50,
 
 		Court: pallet_court = 60,
+		HigherCourt: pallet_court::<Instance1> = 61,
 
 		// Sudo.
 		Sudo: pallet_sudo::{Pallet, Call, Storage, Event<T>, Config<T>} = 71,
@@ -525,6 +772,8 @@ mod benches {
 		[pallet_session, SessionBench::<Runtime>]
 		[pallet_timestamp, Timestamp]
 		[pallet_collator_selection, CollatorSelection]
+		[pallet_preimage, Preimage]
+		[pallet_assets, Assets]
 		[cumulus_pallet_xcmp_queue, XcmpQueue]
 	);
 }
@@ -659,6 +908,70 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_court_runtime_api::CourtApi<Block, AccountId, BlockNumber, Balance> for Runtime {
+		fn reputation(who: AccountId) -> u32 {
+			Court::reputation(&who)
+		}
+
+		fn lawsuits(filter: pallet_court::LawsuitFilter<BlockNumber>) -> Vec<pallet_court::ProposalIndex> {
+			Court::lawsuits(filter)
+		}
+
+		fn insurance_status() -> pallet_court::InsuranceStatus<Balance> {
+			Court::insurance_status()
+		}
+
+		fn juror_stats(who: AccountId) -> pallet_court::JurorStats<Balance> {
+			Court::juror_stats(&who)
+		}
+
+		fn leaderboard(
+			sub_court: pallet_court::SubCourtIndex,
+			start: u32,
+			limit: u32,
+		) -> Vec<(AccountId, pallet_court::JurorStats<Balance>)> {
+			Court::leaderboard(sub_court, start, limit)
+		}
+
+		fn reasons(
+			lawsuit_id: pallet_court::ProposalIndex,
+		) -> Vec<pallet_court::Justification<AccountId, Vec<u8>>> {
+			Court::ruling_reasons(lawsuit_id)
+		}
+	}
+
+	impl pallet_court_runtime_api::HigherCourtApi<Block, AccountId, BlockNumber, Balance> for Runtime {
+		fn reputation(who: AccountId) -> u32 {
+			HigherCourt::reputation(&who)
+		}
+
+		fn lawsuits(filter: pallet_court::LawsuitFilter<BlockNumber>) -> Vec<pallet_court::ProposalIndex> {
+			HigherCourt::lawsuits(filter)
+		}
+
+		fn insurance_status() -> pallet_court::InsuranceStatus<Balance> {
+			HigherCourt::insurance_status()
+		}
+
+		fn juror_stats(who: AccountId) -> pallet_court::JurorStats<Balance> {
+			HigherCourt::juror_stats(&who)
+		}
+
+		fn leaderboard(
+			sub_court: pallet_court::SubCourtIndex,
+			start: u32,
+			limit: u32,
+		) -> Vec<(AccountId, pallet_court::JurorStats<Balance>)> {
+			HigherCourt::leaderboard(sub_court, start, limit)
+		}
+
+		fn reasons(
+			lawsuit_id: pallet_court::ProposalIndex,
+		) -> Vec<pallet_court::Justification<AccountId, Vec<u8>>> {
+			HigherCourt::ruling_reasons(lawsuit_id)
+		}
+	}
+
 	impl cumulus_primitives_core::CollectCollationInfo<Block> for Runtime {
 		fn collect_collation_info(header: &<Block as BlockT>::Header) -> cumulus_primitives_core::CollationInfo {
 			ParachainSystem::collect_collation_info(header)